@@ -1,4 +1,4 @@
-use std::{fmt, num::ParseIntError, str::FromStr};
+use std::{cmp::Ordering, fmt, num::ParseIntError, str::FromStr};
 
 use regex::Regex;
 use schemars::JsonSchema;
@@ -46,16 +46,19 @@ impl TryFrom<cosmrs::proto::cosmos::base::v1beta1::Coin> for Coin {
     fn try_from(coin: cosmrs::proto::cosmos::base::v1beta1::Coin) -> Result<Self, Self::Error> {
         Ok(Self {
             denom: coin.denom.parse()?,
-            amount: coin
-                .amount
-                .parse()
-                .map_err(|e: ParseIntError| ChainError::ProtoDecoding {
-                    message: e.to_string(),
-                })?,
+            amount: parse_int(&coin.amount)?,
         })
     }
 }
 
+/// Parses a cosmos sdk `Int`, which is encoded as a string in protos
+pub(crate) fn parse_int(s: &str) -> Result<u128, ChainError> {
+    s.parse()
+        .map_err(|e: ParseIntError| ChainError::ProtoDecoding {
+            message: e.to_string(),
+        })
+}
+
 impl From<Coin> for cosmrs::proto::cosmos::base::v1beta1::Coin {
     fn from(coin: Coin) -> Self {
         Self {
@@ -65,6 +68,197 @@ impl From<Coin> for cosmrs::proto::cosmos::base::v1beta1::Coin {
     }
 }
 
+/// Cosmos sdk `DecCoin`, a coin with a decimal amount (ie. staking rewards)
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct DecCoin {
+    pub denom: Denom,
+    pub amount: Dec,
+}
+
+impl fmt::Display for DecCoin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}{}", self.amount, self.denom)
+    }
+}
+
+impl TryFrom<cosmrs::proto::cosmos::base::v1beta1::DecCoin> for DecCoin {
+    type Error = ChainError;
+
+    fn try_from(coin: cosmrs::proto::cosmos::base::v1beta1::DecCoin) -> Result<Self, Self::Error> {
+        Ok(Self {
+            denom: coin.denom.parse()?,
+            amount: Dec::from_atomics(&coin.amount)?,
+        })
+    }
+}
+
+impl From<DecCoin> for cosmrs::proto::cosmos::base::v1beta1::DecCoin {
+    fn from(coin: DecCoin) -> Self {
+        Self {
+            denom: coin.denom.into(),
+            amount: coin.amount.atomics(),
+        }
+    }
+}
+
+/// Cosmos sdk `Dec`, a fixed point decimal with 18 fractional digits.
+///
+/// Protos encode a `Dec` as its underlying integer (ie. `1.5` is `"1500000000000000000"`),
+/// `Dec` parses and displays the human readable form instead.
+/// The decimal is kept as its canonical string, so that no precision is lost for values above `u128::MAX` atomics.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+#[serde(try_from = "String")]
+pub struct Dec(String);
+
+impl Dec {
+    pub const PRECISION: usize = 18;
+
+    /// Parses the proto encoding of a `Dec`
+    pub fn from_atomics(atomics: &str) -> Result<Self, ChainError> {
+        let err = || ChainError::Dec {
+            value: atomics.to_string(),
+        };
+
+        let (sign, digits) = match atomics.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", atomics),
+        };
+
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(err());
+        }
+
+        // no negative zero, so that equal values have the same string
+        let sign = if digits.bytes().all(|b| b == b'0') {
+            ""
+        } else {
+            sign
+        };
+
+        let digits = format!("{digits:0>width$}", width = Self::PRECISION + 1);
+        let (int, frac) = digits.split_at(digits.len() - Self::PRECISION);
+        let int = int.trim_start_matches('0');
+        let int = if int.is_empty() { "0" } else { int };
+
+        Ok(Dec(format!("{sign}{int}.{frac}")))
+    }
+
+    /// Parses the proto encoding of a `Dec` stored in a `bytes` field
+    pub(crate) fn from_atomics_bytes(bytes: Vec<u8>) -> Result<Self, ChainError> {
+        let atomics = String::from_utf8(bytes).map_err(|e| ChainError::ProtoDecoding {
+            message: e.to_string(),
+        })?;
+
+        Dec::from_atomics(&atomics)
+    }
+
+    /// Returns the proto encoding of this `Dec`
+    pub fn atomics(&self) -> String {
+        let (sign, digits) = match self.0.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", self.0.as_str()),
+        };
+
+        let digits = digits.replace('.', "");
+        let digits = digits.trim_start_matches('0');
+
+        if digits.is_empty() {
+            "0".to_string()
+        } else {
+            format!("{sign}{digits}")
+        }
+    }
+
+    /// Returns the proto encoding of this `Dec` as an integer,
+    /// fails if it is negative or does not fit in a `u128`
+    pub fn atomics_u128(&self) -> Result<u128, ChainError> {
+        self.atomics().parse().map_err(|_| ChainError::Dec {
+            value: self.0.clone(),
+        })
+    }
+
+    /// Returns the `f64` nearest to this `Dec`
+    pub fn to_f64(&self) -> f64 {
+        // the canonical form is always a valid float
+        self.0.parse().unwrap_or_default()
+    }
+
+    fn split_sign(&self) -> (bool, &str) {
+        match self.0.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, &self.0),
+        }
+    }
+
+    /// Returns the integer part of this `Dec`, dropping any fractional amount
+    pub fn truncate(&self) -> Result<u128, ChainError> {
+        let int = self.0.split('.').next().unwrap_or_default();
+
+        int.parse().map_err(|_| ChainError::Dec {
+            value: self.0.clone(),
+        })
+    }
+}
+
+impl fmt::Display for Dec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Ord for Dec {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // every canonical form has 18 fractional digits and no leading zeros,
+        // so a longer absolute value is always bigger
+        let cmp_abs = |a: &str, b: &str| a.len().cmp(&b.len()).then_with(|| a.cmp(b));
+
+        match (self.split_sign(), other.split_sign()) {
+            ((false, a), (false, b)) => cmp_abs(a, b),
+            ((true, a), (true, b)) => cmp_abs(b, a),
+            ((true, _), (false, _)) => Ordering::Less,
+            ((false, _), (true, _)) => Ordering::Greater,
+        }
+    }
+}
+
+impl PartialOrd for Dec {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl TryFrom<String> for Dec {
+    type Error = ChainError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl FromStr for Dec {
+    type Err = ChainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ChainError::Dec {
+            value: s.to_string(),
+        };
+
+        let (int, frac) = s.split_once('.').unwrap_or((s, ""));
+
+        if frac.len() > Self::PRECISION || !frac.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(err());
+        }
+
+        let digits = int.strip_prefix('-').unwrap_or(int);
+        if digits.is_empty() {
+            return Err(err());
+        }
+
+        Dec::from_atomics(&format!("{int}{frac:0<width$}", width = Self::PRECISION))
+            .map_err(|_| err())
+    }
+}
+
 #[derive(
     Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, PartialOrd, Ord, Hash,
 )]
@@ -119,3 +313,67 @@ impl From<Denom> for String {
         d.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dec() {
+        let dec = Dec::from_atomics("1500000000000000000").unwrap();
+        assert_eq!(dec.to_string(), "1.500000000000000000");
+        assert_eq!(dec.atomics(), "1500000000000000000");
+        assert_eq!(dec.truncate().unwrap(), 1);
+        assert_eq!("1.5".parse::<Dec>().unwrap(), dec);
+
+        let dec = Dec::from_atomics("25").unwrap();
+        assert_eq!(dec.to_string(), "0.000000000000000025");
+        assert_eq!(dec.atomics(), "25");
+        assert_eq!(dec.truncate().unwrap(), 0);
+
+        assert_eq!(Dec::from_atomics("0").unwrap().atomics(), "0");
+        assert!(Dec::from_atomics("1.5").is_err());
+        assert!("1.0000000000000000001".parse::<Dec>().is_err());
+        assert!(".5".parse::<Dec>().is_err());
+    }
+
+    #[test]
+    fn test_dec_numeric() {
+        let dec = |s: &str| s.parse::<Dec>().unwrap();
+
+        assert!(dec("0.05") < dec("0.5"));
+        assert!(dec("9.99") < dec("10"));
+        assert!(dec("-10") < dec("-9.99"));
+        assert!(dec("-0.01") < dec("0"));
+        assert_eq!(dec("-0"), dec("0"));
+        assert_eq!(
+            [dec("1.5"), dec("-2"), dec("0.13")].iter().max(),
+            Some(&dec("1.5"))
+        );
+
+        assert_eq!(dec("0.13").atomics_u128().unwrap(), 130_000_000_000_000_000);
+        assert_eq!(dec("0").atomics_u128().unwrap(), 0);
+        assert!(dec("-1").atomics_u128().is_err());
+        assert!(dec("1000000000000000000000").atomics_u128().is_err());
+
+        assert_eq!(dec("0.13").to_f64(), 0.13);
+        assert_eq!(dec("-2.5").to_f64(), -2.5);
+    }
+
+    #[test]
+    fn test_dec_serde() {
+        let dec: Dec = serde_json::from_str(r#""0.050000000000000000""#).unwrap();
+        assert_eq!(dec, "0.05".parse().unwrap());
+        assert_eq!(
+            serde_json::to_string(&dec).unwrap(),
+            r#""0.050000000000000000""#
+        );
+
+        // not canonical, but still parsed like `FromStr`
+        let dec: Dec = serde_json::from_str(r#""0.05""#).unwrap();
+        assert_eq!(dec.to_string(), "0.050000000000000000");
+
+        assert!(serde_json::from_str::<Dec>(r#""not a dec""#).is_err());
+        assert!(serde_json::from_str::<Dec>(r#""1.0000000000000000001""#).is_err());
+    }
+}
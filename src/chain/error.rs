@@ -7,15 +7,19 @@ pub use keyring::Error as KeyringError;
 
 pub use cosmrs::rpc::Error as TendermintRPCError;
 pub use cosmrs::tendermint::Error as TendermintError;
+pub use reqwest::Error as CosmosRESTError;
 pub use tonic::transport::Error as CosmosGRPCError;
 
-use super::response::ChainResponse;
+use super::response::{ChainResponse, Code};
 
 #[derive(Error, Debug)]
 pub enum ChainError {
     #[error("invalid denomination: {name:?}")]
     Denom { name: String },
 
+    #[error("invalid decimal: {value:?}")]
+    Dec { value: String },
+
     #[error("invalid chainId: {chain_id:?}")]
     ChainId { chain_id: String },
 
@@ -43,13 +47,53 @@ pub enum ChainError {
     #[error("invalid cosmos msg sent to simulate endpoint")]
     Simulation,
 
+    /// Signing for this public key type is not supported, ie. Ethermint `eth_secp256k1` keys,
+    /// which are signed over a Keccak-256 digest instead of SHA-256.
+    #[error("signing with {type_url:?} public keys is not supported")]
+    UnsupportedPubKey { type_url: String },
+
     #[cfg(feature = "os_keyring")]
     #[error(transparent)]
     Keyring(#[from] KeyringError),
 
+    /// The broadcasted tx was not found in a block before the poll timeout.
+    /// `last_error` is the last error returned while querying the tx, if the query itself was failing.
+    #[error(
+        "timed out waiting for tx {tx_hash:?} to be committed (last query error: {last_error:?})"
+    )]
+    TxTimeout {
+        tx_hash: String,
+        last_error: Option<Box<ChainError>>,
+    },
+
     #[error("CosmosSDK error: {res:?}")]
     CosmosSdk { res: ChainResponse },
 
+    /// The tx was committed in block `height`, but failed to execute.
+    /// Unlike a tx rejected by the broadcast with a `CosmosSdk` error, it used up its account sequence.
+    #[error("tx {tx_hash:?} failed in block {height}: {res:?}")]
+    TxFailed {
+        tx_hash: String,
+        height: u64,
+        res: ChainResponse,
+    },
+
+    /// Non 2xx response from the Cosmos SDK REST api.
+    /// `code` is the gRPC status code reported by the gateway, which is unrelated to sdk error codes.
+    /// It is `None` if the body is not a gateway error, ie. an html error page from a proxy,
+    /// in which case `message` holds the raw body.
+    #[error("Cosmos REST error (http status {status}, grpc code {code:?}): {message:?}")]
+    CosmosRest {
+        status: u16,
+        code: Option<u32>,
+        message: String,
+    },
+
+    /// The REST endpoint does not serve `route`, ie. the `abci_query` gateway route used for every
+    /// `CosmosRest` query, which only exists since cosmos-sdk v0.47.
+    #[error("REST endpoint does not support {route:?} (requires cosmos-sdk v0.47+), use the gRPC or Tendermint RPC client instead")]
+    RestRouteUnsupported { route: String },
+
     #[error("Tendermint error")]
     Tendermint(#[from] TendermintError),
 
@@ -60,6 +104,10 @@ pub enum ChainError {
     /// Cosmos gRPC client errors
     #[error(transparent)]
     GRPC(#[from] CosmosGRPCError),
+
+    /// Cosmos REST client errors
+    #[error(transparent)]
+    REST(#[from] CosmosRESTError),
 }
 
 impl ChainError {
@@ -75,6 +123,12 @@ impl ChainError {
         }
     }
 
+    pub(crate) fn proto_decoding(e: ErrorReport) -> ChainError {
+        ChainError::ProtoDecoding {
+            message: e.to_string(),
+        }
+    }
+
     pub(crate) fn prost_proto_encoding(e: EncodeError) -> ChainError {
         ChainError::ProtoEncoding {
             message: e.to_string(),
@@ -90,6 +144,184 @@ impl ChainError {
     pub(crate) fn tonic_status(e: tonic::Status) -> ChainError {
         ChainError::CosmosSdk { res: e.into() }
     }
+
+    /// Classifies a `CosmosSdk` or `TxFailed` error by its codespace and code.
+    /// Returns `None` for any other kind of error.
+    pub fn sdk_error(&self) -> Option<SdkError> {
+        match self {
+            ChainError::CosmosSdk { res } | ChainError::TxFailed { res, .. } => res.sdk_error(),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the chain does not serve the queried path, ie. because it runs an older version of the module.
+    /// Queries over tendermint rpc fail with sdk code 6, while gRPC and REST report `Unimplemented`.
+    pub fn is_unknown_query(&self) -> bool {
+        let unimplemented = tonic::Code::Unimplemented as u32;
+
+        match self {
+            ChainError::CosmosSdk { res } => {
+                res.sdk_error() == Some(SdkError::UnknownRequest)
+                    || (res.codespace.is_empty() && res.code == Code::Err(unimplemented))
+            }
+            ChainError::CosmosRest { code, .. } => *code == Some(unimplemented),
+            _ => false,
+        }
+    }
+
+    /// Returns true if the queried state does not exist, ie. a packet that was not acknowledged yet.
+    /// Queries over tendermint rpc fail with sdk code 22 (or 38), while gRPC and REST report `NotFound`.
+    pub fn is_not_found(&self) -> bool {
+        let not_found = tonic::Code::NotFound as u32;
+
+        match self {
+            ChainError::CosmosSdk { res } => {
+                matches!(
+                    res.sdk_error(),
+                    Some(SdkError::KeyNotFound | SdkError::NotFound)
+                ) || (res.codespace.is_empty() && res.code == Code::Err(not_found))
+            }
+            ChainError::CosmosRest { code, .. } => *code == Some(not_found),
+            _ => false,
+        }
+    }
+}
+
+pub const SDK_CODESPACE: &str = "sdk";
+pub const WASM_CODESPACE: &str = "wasm";
+
+/// Well known errors from the `sdk` and `wasm` codespaces.
+///
+/// Codes from any other codespace, or codes that are not listed here, are returned as `Other`.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum SdkError {
+    TxDecode,
+    InvalidSequence,
+    Unauthorized,
+    InsufficientFunds,
+    UnknownRequest,
+    InvalidAddress,
+    InvalidPubKey,
+    UnknownAddress,
+    InvalidCoins,
+    OutOfGas,
+    MemoTooLarge,
+    InsufficientFee,
+    TooManySignatures,
+    NoSignatures,
+    InvalidRequest,
+    TxInMempoolCache,
+    MempoolIsFull,
+    TxTooLarge,
+    KeyNotFound,
+    InvalidSigner,
+    InvalidGasAdjustment,
+    InvalidHeight,
+    InvalidChainId,
+    InvalidType,
+    TxTimeoutHeight,
+    SequenceMismatch,
+    NotFound,
+    InvalidGasLimit,
+
+    WasmCreateFailed,
+    WasmAccountExists,
+    WasmInstantiateFailed,
+    WasmExecuteFailed,
+    WasmGasLimit,
+    WasmNotFound,
+    WasmQueryFailed,
+    WasmInvalidMsg,
+    WasmMigrationFailed,
+    WasmEmpty,
+    WasmLimit,
+    WasmInvalid,
+    WasmDuplicate,
+    WasmUnknownMsg,
+    WasmInvalidEvent,
+
+    Other { codespace: String, code: u32 },
+}
+
+impl SdkError {
+    pub fn new(codespace: &str, code: u32) -> Self {
+        match (codespace, code) {
+            (SDK_CODESPACE, 2) => SdkError::TxDecode,
+            (SDK_CODESPACE, 3) => SdkError::InvalidSequence,
+            (SDK_CODESPACE, 4) => SdkError::Unauthorized,
+            (SDK_CODESPACE, 5) => SdkError::InsufficientFunds,
+            (SDK_CODESPACE, 6) => SdkError::UnknownRequest,
+            (SDK_CODESPACE, 7) => SdkError::InvalidAddress,
+            (SDK_CODESPACE, 8) => SdkError::InvalidPubKey,
+            (SDK_CODESPACE, 9) => SdkError::UnknownAddress,
+            (SDK_CODESPACE, 10) => SdkError::InvalidCoins,
+            (SDK_CODESPACE, 11) => SdkError::OutOfGas,
+            (SDK_CODESPACE, 12) => SdkError::MemoTooLarge,
+            (SDK_CODESPACE, 13) => SdkError::InsufficientFee,
+            (SDK_CODESPACE, 14) => SdkError::TooManySignatures,
+            (SDK_CODESPACE, 15) => SdkError::NoSignatures,
+            (SDK_CODESPACE, 18) => SdkError::InvalidRequest,
+            (SDK_CODESPACE, 19) => SdkError::TxInMempoolCache,
+            (SDK_CODESPACE, 20) => SdkError::MempoolIsFull,
+            (SDK_CODESPACE, 21) => SdkError::TxTooLarge,
+            (SDK_CODESPACE, 22) => SdkError::KeyNotFound,
+            (SDK_CODESPACE, 24) => SdkError::InvalidSigner,
+            (SDK_CODESPACE, 25) => SdkError::InvalidGasAdjustment,
+            (SDK_CODESPACE, 26) => SdkError::InvalidHeight,
+            (SDK_CODESPACE, 28) => SdkError::InvalidChainId,
+            (SDK_CODESPACE, 29) => SdkError::InvalidType,
+            (SDK_CODESPACE, 30) => SdkError::TxTimeoutHeight,
+            (SDK_CODESPACE, 32) => SdkError::SequenceMismatch,
+            (SDK_CODESPACE, 38) => SdkError::NotFound,
+            (SDK_CODESPACE, 41) => SdkError::InvalidGasLimit,
+
+            (WASM_CODESPACE, 2) => SdkError::WasmCreateFailed,
+            (WASM_CODESPACE, 3) => SdkError::WasmAccountExists,
+            (WASM_CODESPACE, 4) => SdkError::WasmInstantiateFailed,
+            (WASM_CODESPACE, 5) => SdkError::WasmExecuteFailed,
+            (WASM_CODESPACE, 6) => SdkError::WasmGasLimit,
+            (WASM_CODESPACE, 8) => SdkError::WasmNotFound,
+            (WASM_CODESPACE, 9) => SdkError::WasmQueryFailed,
+            (WASM_CODESPACE, 10) => SdkError::WasmInvalidMsg,
+            (WASM_CODESPACE, 11) => SdkError::WasmMigrationFailed,
+            (WASM_CODESPACE, 12) => SdkError::WasmEmpty,
+            (WASM_CODESPACE, 13) => SdkError::WasmLimit,
+            (WASM_CODESPACE, 14) => SdkError::WasmInvalid,
+            (WASM_CODESPACE, 15) => SdkError::WasmDuplicate,
+            (WASM_CODESPACE, 20) => SdkError::WasmUnknownMsg,
+            (WASM_CODESPACE, 21) => SdkError::WasmInvalidEvent,
+
+            (codespace, code) => SdkError::Other {
+                codespace: codespace.to_string(),
+                code,
+            },
+        }
+    }
+
+    /// Returns true for errors that came from a wasm contract or the wasm module
+    pub fn is_wasm(&self) -> bool {
+        match self {
+            SdkError::Other { codespace, .. } => codespace == WASM_CODESPACE,
+            e => matches!(
+                e,
+                SdkError::WasmCreateFailed
+                    | SdkError::WasmAccountExists
+                    | SdkError::WasmInstantiateFailed
+                    | SdkError::WasmExecuteFailed
+                    | SdkError::WasmGasLimit
+                    | SdkError::WasmNotFound
+                    | SdkError::WasmQueryFailed
+                    | SdkError::WasmInvalidMsg
+                    | SdkError::WasmMigrationFailed
+                    | SdkError::WasmEmpty
+                    | SdkError::WasmLimit
+                    | SdkError::WasmInvalid
+                    | SdkError::WasmDuplicate
+                    | SdkError::WasmUnknownMsg
+                    | SdkError::WasmInvalidEvent
+            ),
+        }
+    }
 }
 
 #[derive(Error, Debug)]
@@ -100,3 +332,57 @@ pub enum DeserializeError {
     #[error(transparent)]
     Serde(#[from] serde_json::error::Error),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::chain::response::{ChainResponse, Code};
+
+    #[test]
+    fn test_sdk_error_codespace() {
+        let err = |codespace: &str, code: u32| ChainError::CosmosSdk {
+            res: ChainResponse {
+                code: Code::Err(code),
+                codespace: codespace.to_string(),
+                data: None,
+                log: String::new(),
+            },
+        };
+
+        assert_eq!(err("sdk", 5).sdk_error(), Some(SdkError::InsufficientFunds));
+        assert_eq!(
+            err("wasm", 5).sdk_error(),
+            Some(SdkError::WasmExecuteFailed)
+        );
+        assert!(err("wasm", 5).sdk_error().unwrap().is_wasm());
+        assert_eq!(
+            err("staking", 5).sdk_error(),
+            Some(SdkError::Other {
+                codespace: "staking".to_string(),
+                code: 5
+            })
+        );
+        assert_eq!(ChainError::Simulation.sdk_error(), None);
+    }
+
+    #[test]
+    fn test_is_not_found() {
+        let err = |codespace: &str, code: u32| ChainError::CosmosSdk {
+            res: ChainResponse {
+                code: Code::Err(code),
+                codespace: codespace.to_string(),
+                data: None,
+                log: String::new(),
+            },
+        };
+
+        assert!(err("sdk", 22).is_not_found());
+        assert!(err("sdk", 38).is_not_found());
+        assert!(ChainError::tonic_status(tonic::Status::not_found("ack")).is_not_found());
+
+        // sdk code 5 is InsufficientFunds, only the grpc code 5 is NotFound
+        assert!(!err("sdk", 5).is_not_found());
+        assert!(!err("wasm", 22).is_not_found());
+        assert!(!ChainError::tonic_status(tonic::Status::unavailable("down")).is_not_found());
+    }
+}
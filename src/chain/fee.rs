@@ -6,7 +6,7 @@ use std::fmt;
 use super::{coin::Coin, error::ChainError};
 use crate::modules::auth::model::Address;
 
-#[derive(Clone, Debug, Default, Serialize, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
 pub struct Fee {
     pub amount: Vec<Coin>,
 
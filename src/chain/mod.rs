@@ -10,5 +10,7 @@ pub mod error;
 
 pub mod msg;
 
+pub mod time;
+
 pub use cosmrs::proto::traits::Message;
 pub use cosmrs::{proto::traits::TypeUrl, tx::MessageExt, Any};
@@ -1,5 +1,9 @@
 use core::fmt::Debug;
-use cosmrs::{proto::traits::TypeUrl, tx::MessageExt, Any};
+use cosmrs::{
+    proto::traits::{Message, TypeUrl},
+    tx::MessageExt,
+    Any,
+};
 use std::fmt::Display;
 
 use super::error::ChainError;
@@ -33,3 +37,12 @@ pub trait Msg:
             .map_err(ChainError::prost_proto_encoding)?)
     }
 }
+
+/// Encodes a proto message that doesn't implement `TypeUrl` as [`Any`].
+/// Needed for sdk messages whose type urls are missing from the protos we depend on.
+pub(crate) fn encode_any(type_url: &str, msg: &impl Message) -> Any {
+    Any {
+        type_url: type_url.to_string(),
+        value: msg.encode_to_vec(),
+    }
+}
@@ -4,6 +4,7 @@ use serde::{Deserialize, Serialize};
 use cosmrs::proto::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
 
 use super::fee::Fee;
+use crate::modules::auth::model::Address;
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
 pub struct PaginationRequest {
@@ -97,6 +98,10 @@ pub struct TxOptions {
     /// If set will use this fee, instead of the simulated gas price
     pub fee: Option<Fee>,
 
+    /// Account paying the simulated fee through a feegrant allowance, instead of the signer.
+    /// Ignored if `fee` is set, use `Fee::granter` instead.
+    pub fee_granter: Option<Address>,
+
     /// An arbitrary memo to be added to the transaction
     pub memo: String,
 }
@@ -105,6 +110,7 @@ impl Default for TxOptions {
     fn default() -> Self {
         Self {
             fee: None,
+            fee_granter: None,
             timeout_height: Some(0),
             memo: "Made with cosm-tome client".to_string(),
         }
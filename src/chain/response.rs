@@ -11,16 +11,20 @@ use cosmrs::rpc::endpoint::{
     broadcast::tx_async::Response as AsyncTendermintResponse,
     broadcast::tx_commit::{Response as BlockingTendermintResponse, TxResult},
     broadcast::tx_sync::Response as SyncTendermintResponse,
+    tx::Response as TxTendermintResponse,
 };
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
-use super::error::{ChainError, DeserializeError};
+use super::error::{ChainError, DeserializeError, SdkError};
 
 #[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Default)]
 pub struct ChainResponse {
     pub code: Code,
+    /// Namespace of `code`, ie. `sdk` or `wasm`. Empty for successful responses
+    /// and for errors that did not come from a cosmos module (ie. gRPC status codes).
+    pub codespace: String,
     pub data: Option<Vec<u8>>,
     pub log: String,
 }
@@ -35,12 +39,21 @@ impl ChainResponse {
         )?;
         Ok(r)
     }
+
+    /// Classifies the error code by its codespace. Returns `None` if the response is not an error.
+    pub fn sdk_error(&self) -> Option<SdkError> {
+        match self.code {
+            Code::Ok => None,
+            Code::Err(code) => Some(SdkError::new(&self.codespace, code)),
+        }
+    }
 }
 
 impl From<AbciQuery> for ChainResponse {
     fn from(res: AbciQuery) -> ChainResponse {
         ChainResponse {
             code: res.code.into(),
+            codespace: res.codespace,
             data: Some(res.value),
             log: res.log.to_string(),
         }
@@ -51,6 +64,7 @@ impl From<TxResult> for ChainResponse {
     fn from(res: TxResult) -> ChainResponse {
         ChainResponse {
             code: res.code.into(),
+            codespace: res.codespace.to_string(),
             data: res.data.map(|d| d.into()),
             log: res.log.to_string(),
         }
@@ -61,6 +75,7 @@ impl From<tonic::Status> for ChainResponse {
     fn from(res: tonic::Status) -> ChainResponse {
         ChainResponse {
             code: res.code().into(),
+            codespace: String::new(),
             data: Some(res.details().into()),
             log: res.message().into(),
         }
@@ -85,6 +100,7 @@ impl From<CosmosResponse> for AsyncChainTxResponse {
         Self {
             res: ChainResponse {
                 code: res.code.into(),
+                codespace: res.codespace,
                 data: Some(res.data.into()), // TODO
                 log: res.raw_log,
             },
@@ -98,6 +114,7 @@ impl From<AsyncTendermintResponse> for AsyncChainTxResponse {
         Self {
             res: ChainResponse {
                 code: res.code.into(),
+                codespace: String::new(),
                 data: Some(res.data.into()),
                 log: res.log.to_string(),
             },
@@ -111,6 +128,7 @@ impl From<SyncTendermintResponse> for AsyncChainTxResponse {
         Self {
             res: ChainResponse {
                 code: res.code.into(),
+                codespace: String::new(),
                 data: Some(res.data.into()),
                 log: res.log.to_string(),
             },
@@ -158,6 +176,7 @@ impl From<BlockingTendermintResponse> for ChainTxResponse {
         ChainTxResponse {
             res: ChainResponse {
                 code: res.deliver_tx.code.into(),
+                codespace: res.deliver_tx.codespace.to_string(),
                 data: res.deliver_tx.data.map(|d| d.into()),
                 log: res.deliver_tx.log.to_string(),
             },
@@ -170,6 +189,24 @@ impl From<BlockingTendermintResponse> for ChainTxResponse {
     }
 }
 
+impl From<TxTendermintResponse> for ChainTxResponse {
+    fn from(res: TxTendermintResponse) -> Self {
+        ChainTxResponse {
+            res: ChainResponse {
+                code: res.tx_result.code.into(),
+                codespace: res.tx_result.codespace.to_string(),
+                data: Some(res.tx_result.data.into()),
+                log: res.tx_result.log.to_string(),
+            },
+            events: res.tx_result.events.into_iter().map(Into::into).collect(),
+            gas_used: res.tx_result.gas_used.into(),
+            gas_wanted: res.tx_result.gas_wanted.into(),
+            tx_hash: res.hash.to_string(),
+            height: res.height.into(),
+        }
+    }
+}
+
 impl TryFrom<CosmosResponse> for ChainTxResponse {
     type Error = ChainError;
 
@@ -177,6 +214,7 @@ impl TryFrom<CosmosResponse> for ChainTxResponse {
         Ok(ChainTxResponse {
             res: ChainResponse {
                 code: res.code.into(),
+                codespace: res.codespace,
                 data: Some(res.data.into()), // TODO
                 log: res.raw_log,
             },
@@ -0,0 +1,55 @@
+use std::time::Duration;
+
+use cosmrs::proto::tendermint::google::protobuf::Timestamp as TendermintTimestamp;
+use prost_types::{Duration as ProtoDuration, Timestamp};
+
+use super::error::ChainError;
+
+pub use cosmrs::tendermint::Time;
+
+pub(crate) fn time_from_proto(ts: Timestamp) -> Result<Time, ChainError> {
+    let nanos = ts.nanos.try_into().map_err(|_| ChainError::ProtoDecoding {
+        message: format!("invalid timestamp nanos: {}", ts.nanos),
+    })?;
+
+    Ok(Time::from_unix_timestamp(ts.seconds, nanos)?)
+}
+
+pub(crate) fn time_to_proto(t: Time) -> Timestamp {
+    let ts: TendermintTimestamp = t.into();
+
+    Timestamp {
+        seconds: ts.seconds,
+        nanos: ts.nanos,
+    }
+}
+
+pub(crate) fn time_from_unix_seconds(secs: i64) -> Result<Time, ChainError> {
+    Ok(Time::from_unix_timestamp(secs, 0)?)
+}
+
+/// Unix timestamp of `time` in whole seconds, as used by the vesting accounts and msgs
+pub(crate) fn unix_seconds(time: Time) -> i64 {
+    TendermintTimestamp::from(time).seconds
+}
+
+pub(crate) fn duration_from_proto(d: ProtoDuration) -> Result<Duration, ChainError> {
+    let secs = d
+        .seconds
+        .try_into()
+        .map_err(|_| ChainError::ProtoDecoding {
+            message: format!("invalid duration: {}s", d.seconds),
+        })?;
+    let nanos = d.nanos.try_into().map_err(|_| ChainError::ProtoDecoding {
+        message: format!("invalid duration nanos: {}", d.nanos),
+    })?;
+
+    Ok(Duration::new(secs, nanos))
+}
+
+pub(crate) fn duration_to_proto(d: Duration) -> ProtoDuration {
+    ProtoDuration {
+        seconds: d.as_secs() as i64,
+        nanos: d.subsec_nanos() as i32,
+    }
+}
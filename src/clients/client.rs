@@ -1,13 +1,23 @@
+use std::future::Future;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
 use async_trait::async_trait;
 use cosmrs::proto::traits::Message;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tokio::sync::OnceCell;
 
 use crate::chain::error::ChainError;
 use crate::chain::fee::GasInfo;
 use crate::chain::response::{AsyncChainTxResponse, ChainTxResponse};
 use crate::config::cfg::ChainConfig;
+use crate::modules::gov::model::GovVersion;
 use crate::modules::tx::model::{BroadcastMode, RawTx};
+use crate::modules::tx::sequence::SequenceManager;
 
-use super::cosmos_grpc::CosmosgRPC;
+use super::cosmos_grpc::{CosmosgRPC, CosmosgRPCOptions};
+use super::cosmos_rest::CosmosRest;
 use super::tendermint_rpc::TendermintRPC;
 
 #[cfg(feature = "mocks")]
@@ -32,22 +42,103 @@ pub trait CosmosClient {
         mode: BroadcastMode,
     ) -> Result<AsyncChainTxResponse, ChainError>;
 
-    /// TODO: Block BroadcastMode support is being dropped from future Cosmos-Sdk versions.
-    /// Cosm-tome will continue to support it by broadcasting with the Sync mode
-    /// and then polling the GetTransaction endpoint until it has been committed in the block.
+    /// Block BroadcastMode support is being dropped from future Cosmos-Sdk versions.
+    /// Cosm-tome continues to support it by broadcasting with the Sync mode
+    /// and then polling the GetTx endpoint until it has been committed in a block.
+    /// Txs rejected by `CheckTx` fail with `ChainError::CosmosSdk`, committed txs that failed to execute
+    /// with `ChainError::TxFailed`.
     async fn broadcast_tx_block(&self, tx: &RawTx) -> Result<ChainTxResponse, ChainError>;
 }
 
+/// Controls how `broadcast_tx_block()` polls the chain for a committed tx
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct BroadcastPollOptions {
+    /// Time to wait between GetTx requests
+    pub interval: Duration,
+
+    /// Total time to wait for the tx to be committed before returning `ChainError::TxTimeout`
+    pub timeout: Duration,
+}
+
+impl Default for BroadcastPollOptions {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(1),
+            timeout: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Polls `find_tx` until it returns the committed tx, or the configured timeout has elapsed.
+/// `find_tx` should return `Ok(None)` while the tx is not yet included in a block.
+/// Query errors are treated as pending, since the tx was already broadcasted and can still be committed,
+/// the last one is returned along with the tx hash in `ChainError::TxTimeout`.
+/// A committed tx that failed to execute is returned as `ChainError::TxFailed`.
+pub(crate) async fn poll_for_tx<F, Fut>(
+    tx_hash: String,
+    options: &BroadcastPollOptions,
+    mut find_tx: F,
+) -> Result<ChainTxResponse, ChainError>
+where
+    F: FnMut(String) -> Fut,
+    Fut: Future<Output = Result<Option<ChainTxResponse>, ChainError>>,
+{
+    let start = Instant::now();
+
+    loop {
+        let last_error = match find_tx(tx_hash.clone()).await {
+            Ok(Some(res)) => {
+                if res.res.code.is_err() {
+                    return Err(ChainError::TxFailed {
+                        tx_hash: res.tx_hash,
+                        height: res.height,
+                        res: res.res,
+                    });
+                }
+
+                return Ok(res);
+            }
+            Ok(None) => None,
+            Err(e) => Some(Box::new(e)),
+        };
+
+        if start.elapsed() >= options.timeout {
+            return Err(ChainError::TxTimeout {
+                tx_hash,
+                last_error,
+            });
+        }
+
+        tokio::time::sleep(options.interval).await;
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct CosmTome<T: CosmosClient> {
     pub(crate) cfg: ChainConfig,
     pub(crate) client: T,
+    pub(crate) sequences: SequenceManager,
+    pub(crate) gov_version: Arc<OnceCell<GovVersion>>,
 }
 
 impl<T: CosmosClient> CosmTome<T> {
     /// General usage CosmClient constructor accepting any client that impls `CosmosClient` trait
     pub fn new(cfg: ChainConfig, client: T) -> Self {
-        Self { cfg, client }
+        Self {
+            cfg,
+            client,
+            sequences: SequenceManager::default(),
+            gov_version: Arc::default(),
+        }
+    }
+
+    /// Skips detecting the gov module version from the chain.
+    /// Required for chains that have gov v1 disabled, or when using a client that cannot query it.
+    pub fn with_gov_version(self, version: GovVersion) -> Self {
+        Self {
+            gov_version: Arc::new(OnceCell::new_with(Some(version))),
+            ..self
+        }
     }
 }
 
@@ -60,15 +151,31 @@ impl CosmTome<TendermintRPC> {
                 api_type: "tendermint_rpc".to_string(),
             })?;
 
+        Ok(CosmTome::new(cfg, TendermintRPC::new(&rpc_endpoint)?))
+    }
+
+    pub fn with_tendermint_rpc_options(
+        cfg: ChainConfig,
+        poll_options: BroadcastPollOptions,
+    ) -> Result<CosmTome<TendermintRPC>, ChainError> {
+        let tome = Self::with_tendermint_rpc(cfg)?;
+
         Ok(CosmTome {
-            client: TendermintRPC::new(&rpc_endpoint)?,
-            cfg,
+            client: tome.client.with_broadcast_poll_options(poll_options),
+            ..tome
         })
     }
 }
 
 impl CosmTome<CosmosgRPC> {
     pub fn with_cosmos_grpc(cfg: ChainConfig) -> Result<CosmTome<CosmosgRPC>, ChainError> {
+        Self::with_cosmos_grpc_options(cfg, CosmosgRPCOptions::default())
+    }
+
+    pub fn with_cosmos_grpc_options(
+        cfg: ChainConfig,
+        options: CosmosgRPCOptions,
+    ) -> Result<CosmTome<CosmosgRPC>, ChainError> {
         let grpc_endpoint = cfg
             .grpc_endpoint
             .clone()
@@ -76,16 +183,173 @@ impl CosmTome<CosmosgRPC> {
                 api_type: "cosmos_grpc".to_string(),
             })?;
 
-        Ok(CosmTome {
-            client: CosmosgRPC::new(grpc_endpoint),
+        Ok(CosmTome::new(
             cfg,
+            CosmosgRPC::with_options(grpc_endpoint, options)?,
+        ))
+    }
+}
+
+impl CosmTome<CosmosRest> {
+    /// Queries require a cosmos-sdk v0.47+ chain, see `CosmosRest`.
+    pub fn with_cosmos_rest(cfg: ChainConfig) -> Result<CosmTome<CosmosRest>, ChainError> {
+        let rest_endpoint = cfg
+            .rest_endpoint
+            .clone()
+            .ok_or(ChainError::MissingApiEndpoint {
+                api_type: "cosmos_rest".to_string(),
+            })?;
+
+        Ok(CosmTome::new(cfg, CosmosRest::new(rest_endpoint)))
+    }
+
+    pub fn with_cosmos_rest_options(
+        cfg: ChainConfig,
+        poll_options: BroadcastPollOptions,
+    ) -> Result<CosmTome<CosmosRest>, ChainError> {
+        let tome = Self::with_cosmos_rest(cfg)?;
+
+        Ok(CosmTome {
+            client: tome.client.with_broadcast_poll_options(poll_options),
+            ..tome
         })
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::CosmosClient;
+    use std::time::Duration;
+
+    use crate::chain::error::{ChainError, SdkError};
+    use crate::chain::response::{ChainResponse, ChainTxResponse, Code};
+
+    use super::{poll_for_tx, BroadcastPollOptions, CosmosClient};
 
     const _MESSAGE_IS_OBJECT_SAFE: Option<&dyn CosmosClient> = None;
+
+    #[tokio::test]
+    async fn test_poll_for_tx() {
+        let options = BroadcastPollOptions {
+            interval: Duration::from_millis(1),
+            timeout: Duration::from_secs(5),
+        };
+
+        let mut polls = 0;
+        let res = poll_for_tx("TX_HASH_0".to_string(), &options, |tx_hash| {
+            polls += 1;
+            let found = polls == 3;
+            async move {
+                Ok(found.then(|| ChainTxResponse {
+                    tx_hash,
+                    ..Default::default()
+                }))
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(polls, 3);
+        assert_eq!(res.tx_hash, "TX_HASH_0");
+    }
+
+    #[tokio::test]
+    async fn test_poll_for_tx_failed() {
+        let options = BroadcastPollOptions {
+            interval: Duration::from_millis(1),
+            timeout: Duration::from_secs(5),
+        };
+
+        let res = poll_for_tx("TX_HASH_0".to_string(), &options, |tx_hash| async move {
+            Ok(Some(ChainTxResponse {
+                res: ChainResponse {
+                    code: Code::Err(5),
+                    codespace: "sdk".to_string(),
+                    ..Default::default()
+                },
+                tx_hash,
+                height: 1337,
+                ..Default::default()
+            }))
+        })
+        .await
+        .err()
+        .unwrap();
+
+        assert!(matches!(
+            &res,
+            ChainError::TxFailed { tx_hash, height: 1337, .. } if tx_hash == "TX_HASH_0"
+        ));
+        assert_eq!(res.sdk_error(), Some(SdkError::InsufficientFunds));
+    }
+
+    #[tokio::test]
+    async fn test_poll_for_tx_timeout() {
+        let options = BroadcastPollOptions {
+            interval: Duration::from_millis(1),
+            timeout: Duration::from_millis(10),
+        };
+
+        let res = poll_for_tx("TX_HASH_0".to_string(), &options, |_| async { Ok(None) })
+            .await
+            .err()
+            .unwrap();
+
+        assert!(matches!(
+            res,
+            ChainError::TxTimeout { tx_hash, last_error: None } if tx_hash == "TX_HASH_0"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_poll_for_tx_query_errors() {
+        let options = BroadcastPollOptions {
+            interval: Duration::from_millis(1),
+            timeout: Duration::from_secs(5),
+        };
+
+        // a failing query does not abort the poll, since the tx can still be committed
+        let mut polls = 0;
+        let res = poll_for_tx("TX_HASH_0".to_string(), &options, |tx_hash| {
+            polls += 1;
+            let polled = polls;
+            async move {
+                match polled {
+                    1 => Err(ChainError::Simulation),
+                    _ => Ok(Some(ChainTxResponse {
+                        tx_hash,
+                        ..Default::default()
+                    })),
+                }
+            }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(polls, 2);
+        assert_eq!(res.tx_hash, "TX_HASH_0");
+
+        // the hash is kept along with the last query error, if the chain could not be queried until the timeout
+        let options = BroadcastPollOptions {
+            interval: Duration::from_millis(1),
+            timeout: Duration::from_millis(10),
+        };
+
+        let res = poll_for_tx("TX_HASH_0".to_string(), &options, |_| async {
+            Err(ChainError::Simulation)
+        })
+        .await
+        .err()
+        .unwrap();
+
+        match res {
+            ChainError::TxTimeout {
+                tx_hash,
+                last_error: Some(e),
+            } => {
+                assert_eq!(tx_hash, "TX_HASH_0");
+                assert!(matches!(*e, ChainError::Simulation));
+            }
+            e => panic!("unexpected error: {e:?}"),
+        }
+    }
 }
@@ -1,9 +1,13 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
 use async_trait::async_trait;
 use cosmrs::proto::cosmos::tx::v1beta1::service_client::ServiceClient;
-use cosmrs::proto::cosmos::tx::v1beta1::{
-    BroadcastMode as ProtoBroadcastMode, BroadcastTxRequest, SimulateRequest,
-};
+use cosmrs::proto::cosmos::tx::v1beta1::{BroadcastTxRequest, GetTxRequest, SimulateRequest};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
 use tonic::codec::ProstCodec;
+use tonic::transport::{Channel, Endpoint};
 
 use cosmrs::proto::traits::Message;
 
@@ -12,16 +16,122 @@ use crate::chain::response::{AsyncChainTxResponse, ChainResponse, Code};
 use crate::chain::{error::ChainError, response::ChainTxResponse};
 use crate::modules::tx::model::{BroadcastMode, RawTx};
 
-use super::client::CosmosClient;
+use super::client::{poll_for_tx, BroadcastPollOptions, CosmosClient};
+
+/// Connection settings for the underlying gRPC channel
+#[derive(Clone, Debug, Default, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct CosmosgRPCOptions {
+    /// Timeout for establishing a connection to the gRPC endpoint
+    pub connect_timeout: Option<Duration>,
+
+    /// Timeout applied to every gRPC request
+    pub request_timeout: Option<Duration>,
+
+    /// Interval between HTTP2 keep-alive pings
+    pub keep_alive_interval: Option<Duration>,
+
+    /// How long to wait for a keep-alive ping to be acknowledged before closing the connection
+    pub keep_alive_timeout: Option<Duration>,
 
+    /// Send keep-alive pings even when there are no in-flight requests
+    pub keep_alive_while_idle: bool,
+
+    /// How `broadcast_tx_block()` polls for the committed tx
+    pub broadcast_poll: BroadcastPollOptions,
+}
+
+/// gRPC client that shares a single lazily connected `Channel` across all calls.
+/// The channel transparently reconnects if the connection is dropped.
 #[derive(Clone, Debug)]
 pub struct CosmosgRPC {
     grpc_endpoint: String,
+    options: CosmosgRPCOptions,
+    channel: Arc<Mutex<Option<Channel>>>,
 }
 
 impl CosmosgRPC {
+    /// An invalid `grpc_endpoint` is only reported by the first call, use `try_new()` to validate it upfront.
     pub fn new(grpc_endpoint: String) -> Self {
-        Self { grpc_endpoint }
+        Self {
+            grpc_endpoint,
+            options: CosmosgRPCOptions::default(),
+            channel: Arc::new(Mutex::new(None)),
+        }
+    }
+
+    /// Same as `new()`, but fails if `grpc_endpoint` is not a valid uri
+    pub fn try_new(grpc_endpoint: String) -> Result<Self, ChainError> {
+        Self::with_options(grpc_endpoint, CosmosgRPCOptions::default())
+    }
+
+    /// Validates `grpc_endpoint` and applies `options` to the shared channel
+    pub fn with_options(
+        grpc_endpoint: String,
+        options: CosmosgRPCOptions,
+    ) -> Result<Self, ChainError> {
+        let client = Self {
+            options,
+            ..Self::new(grpc_endpoint)
+        };
+        client.endpoint()?;
+
+        Ok(client)
+    }
+
+    pub fn with_broadcast_poll_options(mut self, poll_options: BroadcastPollOptions) -> Self {
+        self.options.broadcast_poll = poll_options;
+        self
+    }
+
+    fn endpoint(&self) -> Result<Endpoint, ChainError> {
+        let options = &self.options;
+
+        let mut endpoint = Endpoint::new(self.grpc_endpoint.clone())?
+            .keep_alive_while_idle(options.keep_alive_while_idle);
+
+        if let Some(timeout) = options.connect_timeout {
+            endpoint = endpoint.connect_timeout(timeout);
+        }
+        if let Some(timeout) = options.request_timeout {
+            endpoint = endpoint.timeout(timeout);
+        }
+        if let Some(interval) = options.keep_alive_interval {
+            endpoint = endpoint.http2_keep_alive_interval(interval);
+        }
+        if let Some(timeout) = options.keep_alive_timeout {
+            endpoint = endpoint.keep_alive_timeout(timeout);
+        }
+
+        Ok(endpoint)
+    }
+
+    // NOTE: The channel is created on first use instead of in the constructor,
+    // because tonic needs to be inside of a tokio runtime to spawn the connection worker.
+    fn channel(&self) -> Result<Channel, ChainError> {
+        let mut channel = self.channel.lock().unwrap_or_else(|e| e.into_inner());
+
+        if let Some(channel) = channel.as_ref() {
+            return Ok(channel.clone());
+        }
+
+        Ok(channel.insert(self.endpoint()?.connect_lazy()).clone())
+    }
+
+    // Drops the shared channel after a connection failure,
+    // so that the next call connects from scratch instead of reusing a broken channel.
+    fn reset_channel(&self) {
+        self.channel
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .take();
+    }
+
+    fn status_error(&self, e: tonic::Status) -> ChainError {
+        if e.code() == tonic::Code::Unavailable {
+            self.reset_channel();
+        }
+
+        ChainError::tonic_status(e)
     }
 
     // Uses underlying grpc client to make calls to any gRPC service
@@ -35,13 +145,12 @@ impl CosmosgRPC {
         I: Message + 'static,
         O: Message + Default + 'static,
     {
-        let conn = tonic::transport::Endpoint::new(self.grpc_endpoint.clone())?
-            .connect()
-            .await?;
-
-        let mut client = tonic::client::Grpc::new(conn);
+        let mut client = tonic::client::Grpc::new(self.channel()?);
 
-        client.ready().await?;
+        if let Err(e) = client.ready().await {
+            self.reset_channel();
+            return Err(e.into());
+        }
 
         // NOTE: `I` and `O` in ProstCodec have static lifetime bounds:
         let codec: ProstCodec<I, O> = tonic::codec::ProstCodec::default();
@@ -54,10 +163,25 @@ impl CosmosgRPC {
                 codec,
             )
             .await
-            .map_err(ChainError::tonic_status)?;
+            .map_err(|e| self.status_error(e))?;
 
         Ok(res.into_inner())
     }
+
+    // Returns `None` if the tx has not been committed in a block yet
+    async fn find_tx(&self, tx_hash: String) -> Result<Option<ChainTxResponse>, ChainError> {
+        let mut client = ServiceClient::new(self.channel()?);
+
+        match client.get_tx(GetTxRequest { hash: tx_hash }).await {
+            Ok(res) => res
+                .into_inner()
+                .tx_response
+                .map(TryInto::try_into)
+                .transpose(),
+            Err(e) if e.code() == tonic::Code::NotFound => Ok(None),
+            Err(e) => Err(self.status_error(e)),
+        }
+    }
 }
 
 #[async_trait]
@@ -74,7 +198,7 @@ impl CosmosClient for CosmosgRPC {
 
     #[allow(deprecated)]
     async fn simulate_tx(&self, tx: &RawTx) -> Result<GasInfo, ChainError> {
-        let mut client = ServiceClient::connect(self.grpc_endpoint.clone()).await?;
+        let mut client = ServiceClient::new(self.channel()?);
 
         let req = SimulateRequest {
             tx: None,
@@ -84,12 +208,18 @@ impl CosmosClient for CosmosgRPC {
         let gas_info = client
             .simulate(req)
             .await
-            .map_err(|e| ChainError::CosmosSdk {
-                res: ChainResponse {
-                    code: Code::Err(e.code() as u32),
-                    log: e.message().to_string(),
-                    ..Default::default()
-                },
+            .map_err(|e| {
+                if e.code() == tonic::Code::Unavailable {
+                    self.reset_channel();
+                }
+
+                ChainError::CosmosSdk {
+                    res: ChainResponse {
+                        code: Code::Err(e.code() as u32),
+                        log: e.message().to_string(),
+                        ..Default::default()
+                    },
+                }
             })?
             .into_inner()
             .gas_info
@@ -103,7 +233,7 @@ impl CosmosClient for CosmosgRPC {
         tx: &RawTx,
         mode: BroadcastMode,
     ) -> Result<AsyncChainTxResponse, ChainError> {
-        let mut client = ServiceClient::connect(self.grpc_endpoint.clone()).await?;
+        let mut client = ServiceClient::new(self.channel()?);
 
         let req = BroadcastTxRequest {
             tx_bytes: tx.to_bytes()?,
@@ -113,10 +243,15 @@ impl CosmosClient for CosmosgRPC {
         let res = client
             .broadcast_tx(req)
             .await
-            .map_err(ChainError::tonic_status)?
+            .map_err(|e| self.status_error(e))?
             .into_inner();
 
-        let res: AsyncChainTxResponse = res.tx_response.unwrap().into();
+        let res: AsyncChainTxResponse = res
+            .tx_response
+            .ok_or(ChainError::ProtoDecoding {
+                message: "BroadcastTxResponse is missing its tx_response".to_string(),
+            })?
+            .into();
 
         if res.res.code.is_err() {
             return Err(ChainError::CosmosSdk { res: res.res });
@@ -126,25 +261,78 @@ impl CosmosClient for CosmosgRPC {
     }
 
     async fn broadcast_tx_block(&self, tx: &RawTx) -> Result<ChainTxResponse, ChainError> {
-        let mut client = ServiceClient::connect(self.grpc_endpoint.clone()).await?;
+        let res = self.broadcast_tx(tx, BroadcastMode::Sync).await?;
 
-        let req = BroadcastTxRequest {
-            tx_bytes: tx.to_bytes()?,
-            mode: ProtoBroadcastMode::Block.into(),
-        };
+        poll_for_tx(res.tx_hash, &self.options.broadcast_poll, |hash| {
+            self.find_tx(hash)
+        })
+        .await
+    }
+}
 
-        let res = client
-            .broadcast_tx(req)
-            .await
-            .map_err(ChainError::tonic_status)?
-            .into_inner();
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
 
-        let res: ChainTxResponse = res.tx_response.unwrap().try_into()?;
+    use cosmrs::proto::cosmos::auth::v1beta1::{QueryAccountRequest, QueryAccountResponse};
 
-        if res.res.code.is_err() {
-            return Err(ChainError::CosmosSdk { res: res.res });
-        }
+    use crate::clients::client::CosmosClient;
 
-        Ok(res)
+    use super::{CosmosgRPC, CosmosgRPCOptions};
+
+    fn has_channel(client: &CosmosgRPC) -> bool {
+        client.channel.lock().unwrap().is_some()
+    }
+
+    #[tokio::test]
+    async fn test_shared_channel() {
+        // nothing listens on port 1, so every call fails to connect
+        let client = CosmosgRPC::with_options(
+            "http://127.0.0.1:1".to_string(),
+            CosmosgRPCOptions {
+                connect_timeout: Some(Duration::from_secs(1)),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let cloned = client.clone();
+
+        // connected lazily on first use, and shared with every clone
+        assert!(!has_channel(&client));
+        cloned.channel().unwrap();
+        assert!(has_channel(&client));
+
+        let err = client
+            .query::<_, QueryAccountResponse>(
+                QueryAccountRequest {
+                    address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                },
+                "/cosmos.auth.v1beta1.Query/Account",
+            )
+            .await;
+        assert!(err.is_err());
+
+        // the failed channel is dropped for every clone, and recreated on the next call
+        assert!(!has_channel(&client));
+        assert!(!has_channel(&cloned));
+        cloned.channel().unwrap();
+        assert!(has_channel(&client));
+    }
+
+    #[tokio::test]
+    async fn test_invalid_endpoint() {
+        assert!(CosmosgRPC::try_new("not a uri".to_string()).is_err());
+
+        // only reported once the client is used
+        let client = CosmosgRPC::new("not a uri".to_string());
+
+        let err = client
+            .query::<_, QueryAccountResponse>(
+                QueryAccountRequest::default(),
+                "/cosmos.auth.v1beta1.Query/Account",
+            )
+            .await;
+        assert!(err.is_err());
+        assert!(!has_channel(&client));
     }
 }
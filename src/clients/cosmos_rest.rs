@@ -0,0 +1,721 @@
+use std::sync::Arc;
+use std::{fmt::Display, str::FromStr};
+
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse as CosmosResponse;
+use cosmrs::proto::tendermint::abci::{Event as ProtoEvent, EventAttribute};
+use cosmrs::proto::traits::Message;
+use reqwest::RequestBuilder;
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
+use tokio::sync::OnceCell;
+
+use crate::chain::error::ChainError;
+use crate::chain::fee::GasInfo;
+use crate::chain::response::{AsyncChainTxResponse, ChainResponse, ChainTxResponse};
+use crate::modules::tx::model::{BroadcastMode, RawTx};
+
+use super::client::{poll_for_tx, BroadcastPollOptions, CosmosClient};
+
+/// Client for the Cosmos SDK REST API (LCD / gRPC-gateway).
+///
+/// Queries are tunneled through the gateway's `abci_query` endpoint, so that the same gRPC
+/// paths and protobuf types used by the other clients work here as well.
+/// That endpoint was added in cosmos-sdk v0.47, older nodes return `ChainError::RestRouteUnsupported`
+/// for every query. Simulating and broadcasting txs works with any cosmos-sdk version.
+#[derive(Clone, Debug)]
+pub struct CosmosRest {
+    rest_endpoint: String,
+    client: reqwest::Client,
+    poll_options: BroadcastPollOptions,
+    event_encoding: Arc<OnceCell<EventEncoding>>,
+}
+
+impl CosmosRest {
+    pub fn new(rest_endpoint: String) -> Self {
+        Self {
+            rest_endpoint: rest_endpoint.trim_end_matches('/').to_string(),
+            client: reqwest::Client::new(),
+            poll_options: BroadcastPollOptions::default(),
+            event_encoding: Arc::default(),
+        }
+    }
+
+    pub fn with_broadcast_poll_options(mut self, poll_options: BroadcastPollOptions) -> Self {
+        self.poll_options = poll_options;
+        self
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.rest_endpoint, path)
+    }
+
+    // Sends the request and decodes the json body, mapping non 2xx responses into `ChainError::CosmosRest`
+    async fn send<O: DeserializeOwned>(&self, req: RequestBuilder) -> Result<O, ChainError> {
+        let res = req.send().await?;
+
+        let status = res.status();
+        if !status.is_success() {
+            let body = res.text().await?;
+
+            return Err(match serde_json::from_str::<RestError>(&body) {
+                Ok(err) => ChainError::CosmosRest {
+                    status: status.as_u16(),
+                    code: Some(err.code),
+                    message: err.message,
+                },
+                Err(_) => ChainError::CosmosRest {
+                    status: status.as_u16(),
+                    code: None,
+                    message: body,
+                },
+            });
+        }
+
+        Ok(res.json().await?)
+    }
+
+    // Returns `None` if the tx has not been committed in a block yet
+    async fn find_tx(&self, tx_hash: String) -> Result<Option<ChainTxResponse>, ChainError> {
+        let res = self
+            .send::<GetTxResponse>(
+                self.client
+                    .get(self.url(&format!("/cosmos/tx/v1beta1/txs/{tx_hash}"))),
+            )
+            .await;
+
+        match res {
+            Ok(res) => Ok(Some(
+                self.cosmos_response(res.tx_response).await?.try_into()?,
+            )),
+            // grpc-gateway reports the `NotFound` grpc status returned by GetTx as code 5
+            Err(ChainError::CosmosRest { code, .. })
+                if code == Some(tonic::Code::NotFound as u32) =>
+            {
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    async fn cosmos_response(&self, res: RestTxResponse) -> Result<CosmosResponse, ChainError> {
+        // only ask the node for its encoding if there is anything to decode
+        let encoding = if res.events.is_empty() {
+            EventEncoding::Plain
+        } else {
+            self.event_encoding().await?
+        };
+
+        Ok(res.into_cosmos_response(encoding))
+    }
+
+    // Tendermint 0.34 based chains return base64 encoded event attributes, while CometBFT 0.37+
+    // returns them as plain strings, so the node version is queried once to know which one it uses.
+    async fn event_encoding(&self) -> Result<EventEncoding, ChainError> {
+        self.event_encoding
+            .get_or_try_init(|| async {
+                let res: NodeInfoResponse = self
+                    .send(
+                        self.client
+                            .get(self.url("/cosmos/base/tendermint/v1beta1/node_info")),
+                    )
+                    .await?;
+
+                Ok(EventEncoding::from_tendermint_version(
+                    &res.default_node_info.version,
+                ))
+            })
+            .await
+            .copied()
+    }
+}
+
+#[async_trait]
+impl CosmosClient for CosmosRest {
+    async fn query<I, O>(&self, msg: I, path: &str) -> Result<O, ChainError>
+    where
+        I: Message + Default + tonic::IntoRequest<I> + 'static,
+        O: Message + Default + 'static,
+    {
+        let data = BASE64.encode(msg.encode_to_vec());
+
+        let res: AbciQueryResponse = self
+            .send(
+                self.client
+                    .get(self.url(ABCI_QUERY_ROUTE))
+                    .query(&[("path", path), ("data", data.as_str())]),
+            )
+            .await
+            .map_err(|e| match e {
+                // query errors are reported in the body, so these mean the route itself is missing
+                ChainError::CosmosRest {
+                    status: 404 | 501, ..
+                } => ChainError::RestRouteUnsupported {
+                    route: ABCI_QUERY_ROUTE.to_string(),
+                },
+                e => e,
+            })?;
+
+        if res.code != 0 {
+            return Err(ChainError::CosmosSdk {
+                res: ChainResponse {
+                    code: res.code.into(),
+                    codespace: res.codespace,
+                    data: None,
+                    log: res.log,
+                },
+            });
+        }
+
+        let value = decode_base64(&res.value.unwrap_or_default())?;
+
+        O::decode(value.as_slice()).map_err(ChainError::prost_proto_decoding)
+    }
+
+    async fn simulate_tx(&self, tx: &RawTx) -> Result<GasInfo, ChainError> {
+        let req = SimulateRequest {
+            tx_bytes: BASE64.encode(tx.to_bytes()?),
+        };
+
+        let res: SimulateResponse = self
+            .send(
+                self.client
+                    .post(self.url("/cosmos/tx/v1beta1/simulate"))
+                    .json(&req),
+            )
+            .await?;
+
+        let gas_info = res.gas_info.ok_or(ChainError::Simulation)?;
+
+        Ok(GasInfo::new(gas_info.gas_wanted, gas_info.gas_used))
+    }
+
+    async fn broadcast_tx(
+        &self,
+        tx: &RawTx,
+        mode: BroadcastMode,
+    ) -> Result<AsyncChainTxResponse, ChainError> {
+        let req = BroadcastTxRequest {
+            tx_bytes: BASE64.encode(tx.to_bytes()?),
+            mode: mode.as_ref(),
+        };
+
+        let res: BroadcastTxResponse = self
+            .send(
+                self.client
+                    .post(self.url("/cosmos/tx/v1beta1/txs"))
+                    .json(&req),
+            )
+            .await?;
+
+        let res: AsyncChainTxResponse = self.cosmos_response(res.tx_response).await?.into();
+
+        if res.res.code.is_err() {
+            return Err(ChainError::CosmosSdk { res: res.res });
+        }
+
+        Ok(res)
+    }
+
+    async fn broadcast_tx_block(&self, tx: &RawTx) -> Result<ChainTxResponse, ChainError> {
+        let res = self.broadcast_tx(tx, BroadcastMode::Sync).await?;
+
+        poll_for_tx(res.tx_hash, &self.poll_options, |hash| self.find_tx(hash)).await
+    }
+}
+
+/// Gateway route for raw abci queries, only available since cosmos-sdk v0.47
+const ABCI_QUERY_ROUTE: &str = "/cosmos/base/tendermint/v1beta1/abci_query";
+
+fn decode_base64(s: &str) -> Result<Vec<u8>, ChainError> {
+    BASE64.decode(s).map_err(|e| ChainError::ProtoDecoding {
+        message: e.to_string(),
+    })
+}
+
+// The gateway encodes 64 bit integers as json strings
+fn from_str<'de, D, T>(d: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    String::deserialize(d)?
+        .parse()
+        .map_err(serde::de::Error::custom)
+}
+
+#[derive(Deserialize)]
+struct RestError {
+    code: u32,
+    #[serde(default)]
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct AbciQueryResponse {
+    #[serde(default)]
+    code: u32,
+    #[serde(default)]
+    log: String,
+    #[serde(default)]
+    codespace: String,
+    value: Option<String>,
+}
+
+#[derive(Serialize)]
+struct SimulateRequest {
+    tx_bytes: String,
+}
+
+#[derive(Deserialize)]
+struct SimulateResponse {
+    gas_info: Option<RestGasInfo>,
+}
+
+#[derive(Deserialize)]
+struct RestGasInfo {
+    #[serde(deserialize_with = "from_str")]
+    gas_wanted: u64,
+    #[serde(deserialize_with = "from_str")]
+    gas_used: u64,
+}
+
+#[derive(Serialize)]
+struct BroadcastTxRequest<'a> {
+    tx_bytes: String,
+    mode: &'a str,
+}
+
+#[derive(Deserialize)]
+struct BroadcastTxResponse {
+    tx_response: RestTxResponse,
+}
+
+#[derive(Deserialize)]
+struct GetTxResponse {
+    tx_response: RestTxResponse,
+}
+
+#[derive(Deserialize)]
+struct RestTxResponse {
+    #[serde(deserialize_with = "from_str")]
+    height: i64,
+    txhash: String,
+    #[serde(default)]
+    codespace: String,
+    #[serde(default)]
+    code: u32,
+    #[serde(default)]
+    data: String,
+    #[serde(default)]
+    raw_log: String,
+    #[serde(default)]
+    info: String,
+    #[serde(deserialize_with = "from_str")]
+    gas_wanted: i64,
+    #[serde(deserialize_with = "from_str")]
+    gas_used: i64,
+    #[serde(default)]
+    timestamp: String,
+    #[serde(default)]
+    events: Vec<RestEvent>,
+}
+
+impl RestTxResponse {
+    fn into_cosmos_response(self, encoding: EventEncoding) -> CosmosResponse {
+        CosmosResponse {
+            height: self.height,
+            txhash: self.txhash,
+            codespace: self.codespace,
+            code: self.code,
+            data: self.data,
+            raw_log: self.raw_log,
+            logs: vec![],
+            info: self.info,
+            gas_wanted: self.gas_wanted,
+            gas_used: self.gas_used,
+            tx: None,
+            timestamp: self.timestamp,
+            events: self
+                .events
+                .into_iter()
+                .map(|e| e.into_proto(encoding))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct RestEvent {
+    r#type: String,
+    #[serde(default)]
+    attributes: Vec<RestEventAttribute>,
+}
+
+impl RestEvent {
+    fn into_proto(self, encoding: EventEncoding) -> ProtoEvent {
+        ProtoEvent {
+            r#type: self.r#type,
+            attributes: self
+                .attributes
+                .into_iter()
+                .map(|attr| EventAttribute {
+                    key: encoding.decode(attr.key).into(),
+                    value: encoding.decode(attr.value).into(),
+                    index: attr.index,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct RestEventAttribute {
+    #[serde(default)]
+    key: String,
+    #[serde(default)]
+    value: String,
+    #[serde(default)]
+    index: bool,
+}
+
+/// Encoding of tx event attributes returned by the node
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+enum EventEncoding {
+    /// Tendermint 0.34
+    Base64,
+    /// CometBFT 0.37+
+    Plain,
+}
+
+impl EventEncoding {
+    fn from_tendermint_version(version: &str) -> Self {
+        let mut parts = version.trim_start_matches('v').split('.');
+
+        match (parts.next(), parts.next().map(str::parse::<u32>)) {
+            (Some("0"), Some(Ok(minor))) if minor < 37 => EventEncoding::Base64,
+            _ => EventEncoding::Plain,
+        }
+    }
+
+    // Attributes that fail to decode are kept as is, since they can't have been base64 encoded by the node
+    fn decode(&self, s: String) -> Vec<u8> {
+        match self {
+            EventEncoding::Base64 => BASE64.decode(&s).unwrap_or_else(|_| s.into_bytes()),
+            EventEncoding::Plain => s.into_bytes(),
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct NodeInfoResponse {
+    default_node_info: DefaultNodeInfo,
+}
+
+#[derive(Deserialize)]
+struct DefaultNodeInfo {
+    version: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmrs::proto::cosmos::bank::v1beta1::{QueryBalanceRequest, QueryBalanceResponse};
+    use cosmrs::proto::cosmos::base::v1beta1::Coin;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    use crate::chain::error::ChainError;
+    use crate::clients::client::{CosmTome, CosmosClient};
+    use crate::config::cfg::ChainConfig;
+    use crate::modules::bank::error::BankError;
+    use crate::modules::tx::model::{BroadcastMode, RawTx};
+    use crate::test_utils::test_cfg;
+
+    use super::{CosmosRest, EventEncoding, ABCI_QUERY_ROUTE, BASE64};
+    use base64::Engine;
+
+    // Serves a single canned http response and returns the endpoint url
+    async fn stub_server(status: &'static str, body: String) -> String {
+        stub_server_seq(vec![(status, body)]).await
+    }
+
+    // Serves the canned http responses in order, one per connection, and returns the endpoint url
+    async fn stub_server_seq(responses: Vec<(&'static str, String)>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        tokio::spawn(async move {
+            for (status, body) in responses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut buf = vec![0u8; 8192];
+                let _ = socket.read(&mut buf).await.unwrap();
+
+                let res = format!(
+                    "HTTP/1.1 {status}\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                socket.write_all(res.as_bytes()).await.unwrap();
+            }
+        });
+
+        format!("http://{addr}")
+    }
+
+    fn get_tx_body(attr_value: &str) -> String {
+        format!(
+            r#"{{"tx":null,"tx_response":{{"height":"10","txhash":"TX_HASH_0","codespace":"","code":0,"data":"","raw_log":"","logs":[],"info":"","gas_wanted":"100","gas_used":"90","tx":null,"timestamp":"","events":[{{"type":"transfer","attributes":[{{"key":"YW1vdW50","value":"{attr_value}","index":true}}]}}]}}}}"#
+        )
+    }
+
+    fn node_info_body(version: &str) -> String {
+        format!(r#"{{"default_node_info":{{"version":"{version}"}},"application_version":{{}}}}"#)
+    }
+
+    #[tokio::test]
+    async fn test_query() {
+        let proto_res = QueryBalanceResponse {
+            balance: Some(Coin {
+                denom: "utest".to_string(),
+                amount: "1337".to_string(),
+            }),
+        };
+
+        let body = format!(
+            r#"{{"code":0,"log":"","info":"","index":"0","key":null,"value":"{}","proof_ops":null,"height":"10","codespace":""}}"#,
+            BASE64.encode(cosmrs::proto::traits::Message::encode_to_vec(&proto_res))
+        );
+
+        let client = CosmosRest::new(stub_server("200 OK", body).await);
+
+        let res = client
+            .query::<_, QueryBalanceResponse>(
+                QueryBalanceRequest {
+                    address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                    denom: "utest".to_string(),
+                },
+                "/cosmos.bank.v1beta1.Query/Balance",
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res, proto_res);
+    }
+
+    #[tokio::test]
+    async fn test_query_gateway_err() {
+        let body = r#"{"code":3,"message":"invalid address","details":[]}"#.to_string();
+
+        let client = CosmosRest::new(stub_server("400 Bad Request", body).await);
+
+        let res = client
+            .query::<_, QueryBalanceResponse>(
+                QueryBalanceRequest::default(),
+                "/cosmos.bank.v1beta1.Query/Balance",
+            )
+            .await
+            .err()
+            .unwrap();
+
+        assert!(matches!(
+            res,
+            ChainError::CosmosRest { status: 400, code: Some(3), message } if message == "invalid address"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_query_proxy_err() {
+        let body = "<html><body><h1>502 Bad Gateway</h1></body></html>".to_string();
+
+        let client = CosmosRest::new(stub_server("502 Bad Gateway", body.clone()).await);
+
+        let res = client
+            .query::<_, QueryBalanceResponse>(
+                QueryBalanceRequest::default(),
+                "/cosmos.bank.v1beta1.Query/Balance",
+            )
+            .await
+            .err()
+            .unwrap();
+
+        assert!(matches!(
+            res,
+            ChainError::CosmosRest { status: 502, code: None, message } if message == body
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_query_unsupported_route() {
+        // cosmos-sdk v0.45 gateway, which has no abci_query route
+        let body = r#"{"code":12,"message":"Not Implemented","details":[]}"#.to_string();
+
+        let client = CosmosRest::new(stub_server("501 Not Implemented", body).await);
+
+        let res = client
+            .query::<_, QueryBalanceResponse>(
+                QueryBalanceRequest::default(),
+                "/cosmos.bank.v1beta1.Query/Balance",
+            )
+            .await
+            .err()
+            .unwrap();
+
+        assert!(matches!(
+            res,
+            ChainError::RestRouteUnsupported { route } if route == ABCI_QUERY_ROUTE
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_find_tx_base64_events() {
+        let client = CosmosRest::new(
+            stub_server_seq(vec![
+                ("200 OK", get_tx_body("c3Rha2U=")),
+                ("200 OK", node_info_body("0.34.24")),
+            ])
+            .await,
+        );
+
+        let res = client
+            .find_tx("TX_HASH_0".to_string())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(res.tx_hash, "TX_HASH_0");
+        assert_eq!(res.events[0].attributes[0].key, "amount");
+        assert_eq!(res.events[0].attributes[0].value, "stake");
+    }
+
+    #[tokio::test]
+    async fn test_find_tx_plain_events() {
+        // "ABCD" is valid base64, but has to be kept as is on CometBFT 0.37+ chains
+        let client = CosmosRest::new(
+            stub_server_seq(vec![
+                ("200 OK", get_tx_body("ABCD")),
+                ("200 OK", node_info_body("0.37.2")),
+            ])
+            .await,
+        );
+
+        let res = client
+            .find_tx("TX_HASH_0".to_string())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(res.events[0].attributes[0].key, "YW1vdW50");
+        assert_eq!(res.events[0].attributes[0].value, "ABCD");
+    }
+
+    #[tokio::test]
+    async fn test_find_tx_not_found() {
+        let body = r#"{"code":5,"message":"tx not found: TX_HASH_0","details":[]}"#.to_string();
+
+        let client = CosmosRest::new(stub_server("404 Not Found", body).await);
+
+        let res = client.find_tx("TX_HASH_0".to_string()).await.unwrap();
+        assert!(res.is_none());
+
+        // a missing route is an error, instead of a pending tx
+        let body = "404 page not found".to_string();
+
+        let client = CosmosRest::new(stub_server("404 Not Found", body).await);
+
+        let res = client.find_tx("TX_HASH_0".to_string()).await.err().unwrap();
+        assert!(matches!(
+            res,
+            ChainError::CosmosRest {
+                status: 404,
+                code: None,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_event_encoding_from_version() {
+        assert_eq!(
+            EventEncoding::from_tendermint_version("0.34.27"),
+            EventEncoding::Base64
+        );
+        assert_eq!(
+            EventEncoding::from_tendermint_version("v0.34.24"),
+            EventEncoding::Base64
+        );
+        assert_eq!(
+            EventEncoding::from_tendermint_version("0.37.2"),
+            EventEncoding::Plain
+        );
+        assert_eq!(
+            EventEncoding::from_tendermint_version("0.38.0"),
+            EventEncoding::Plain
+        );
+    }
+
+    #[tokio::test]
+    async fn test_broadcast_tx() {
+        let body = r#"{"tx_response":{"height":"0","txhash":"TX_HASH_0","codespace":"","code":0,"data":"","raw_log":"[]","logs":[],"info":"","gas_wanted":"0","gas_used":"0","tx":null,"timestamp":"","events":[]}}"#.to_string();
+
+        let client = CosmosRest::new(stub_server("200 OK", body).await);
+
+        let res = client
+            .broadcast_tx(&RawTx::from_bytes(&[]).unwrap(), BroadcastMode::Sync)
+            .await
+            .unwrap();
+
+        assert_eq!(res.tx_hash, "TX_HASH_0");
+        assert!(res.res.code.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_sdk_045_gateway() {
+        let simulate_body =
+            r#"{"gas_info":{"gas_wanted":"200","gas_used":"100"},"result":null}"#.to_string();
+        let broadcast_body = r#"{"tx_response":{"height":"0","txhash":"TX_HASH_0","codespace":"","code":0,"data":"","raw_log":"[]","logs":[],"info":"","gas_wanted":"0","gas_used":"0","tx":null,"timestamp":"","events":[]}}"#.to_string();
+
+        let endpoint = stub_server_seq(vec![
+            (
+                "501 Not Implemented",
+                r#"{"code":12,"message":"Not Implemented","details":[]}"#.to_string(),
+            ),
+            ("200 OK", simulate_body),
+            ("200 OK", broadcast_body),
+        ])
+        .await;
+
+        let cfg = ChainConfig {
+            rest_endpoint: Some(endpoint),
+            ..test_cfg()
+        };
+        let cosm_tome = CosmTome::with_cosmos_rest(cfg).unwrap();
+
+        // module queries fail on a gateway without the abci_query route
+        let err = cosm_tome
+            .bank_query_balance(
+                "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                    .parse()
+                    .unwrap(),
+                "utest".parse().unwrap(),
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            BankError::ChainError(ChainError::RestRouteUnsupported { route }) if route == ABCI_QUERY_ROUTE
+        ));
+
+        // while signed txs can still be simulated and broadcasted
+        let tx = RawTx::from_bytes(&[]).unwrap();
+
+        let gas = cosm_tome.client.simulate_tx(&tx).await.unwrap();
+        assert_eq!(gas.gas_used, 100u64.into());
+
+        let res = cosm_tome
+            .client
+            .broadcast_tx(&tx, BroadcastMode::Sync)
+            .await
+            .unwrap();
+        assert_eq!(res.tx_hash, "TX_HASH_0");
+    }
+}
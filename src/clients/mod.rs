@@ -1,4 +1,5 @@
 pub mod client;
 
 pub mod cosmos_grpc;
+pub mod cosmos_rest;
 pub mod tendermint_rpc;
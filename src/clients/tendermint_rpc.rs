@@ -8,20 +8,36 @@ use crate::chain::fee::GasInfo;
 use crate::chain::response::{AsyncChainTxResponse, ChainTxResponse};
 use crate::modules::tx::model::{BroadcastMode, RawTx};
 
-use super::client::CosmosClient;
+use super::client::{poll_for_tx, BroadcastPollOptions, CosmosClient};
 
 #[derive(Clone, Debug)]
 pub struct TendermintRPC {
     client: HttpClient,
+    poll_options: BroadcastPollOptions,
 }
 
 impl TendermintRPC {
     pub fn new(rpc_endpoint: &str) -> Result<Self, ChainError> {
         Ok(Self {
             client: HttpClient::new(rpc_endpoint)?,
+            poll_options: BroadcastPollOptions::default(),
         })
     }
 
+    pub fn with_broadcast_poll_options(mut self, poll_options: BroadcastPollOptions) -> Self {
+        self.poll_options = poll_options;
+        self
+    }
+
+    // Returns `None` if the tx has not been committed in a block yet
+    async fn find_tx(&self, tx_hash: String) -> Result<Option<ChainTxResponse>, ChainError> {
+        match self.client.tx(tx_hash.parse()?, false).await {
+            Ok(res) => Ok(Some(res.into())),
+            Err(e) if e.to_string().contains("not found") => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     fn encode_msg<T: Message>(msg: T) -> Result<Vec<u8>, ChainError> {
         let mut data = Vec::with_capacity(msg.encoded_len());
         msg.encode(&mut data)
@@ -111,22 +127,8 @@ impl CosmosClient for TendermintRPC {
     }
 
     async fn broadcast_tx_block(&self, tx: &RawTx) -> Result<ChainTxResponse, ChainError> {
-        let res = self
-            .client
-            .broadcast_tx_commit(tx.to_bytes()?.into())
-            .await?;
-
-        if res.check_tx.code.is_err() {
-            return Err(ChainError::CosmosSdk {
-                res: res.check_tx.into(),
-            });
-        }
-        if res.deliver_tx.code.is_err() {
-            return Err(ChainError::CosmosSdk {
-                res: res.deliver_tx.into(),
-            });
-        }
+        let res = self.broadcast_tx(tx, BroadcastMode::Sync).await?;
 
-        Ok(res.into())
+        poll_for_tx(res.tx_hash, &self.poll_options, |hash| self.find_tx(hash)).await
     }
 }
@@ -17,6 +17,8 @@ pub struct ChainConfig {
     pub rpc_endpoint: Option<String>,
     /// example: "https://terra-testnet-grpc.polkachu.com:11790"
     pub grpc_endpoint: Option<String>,
+    /// example: "https://terra-testnet-api.polkachu.com"
+    pub rest_endpoint: Option<String>,
     /// example: 0.025
     pub gas_price: f64,
     /// example: 1.3
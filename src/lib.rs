@@ -7,3 +7,6 @@ pub mod config;
 pub mod signing_key;
 
 pub mod chain;
+
+#[cfg(test)]
+mod test_utils;
@@ -1,12 +1,9 @@
-use crate::chain::error::ChainError;
 use crate::chain::request::PaginationRequest;
 use crate::clients::client::{CosmTome, CosmosClient};
-use crate::modules::auth::model::Account;
 use cosmrs::proto::cosmos::auth::v1beta1::{
-    BaseAccount, QueryAccountRequest, QueryAccountResponse, QueryAccountsRequest,
-    QueryAccountsResponse, QueryParamsRequest, QueryParamsResponse,
+    QueryAccountRequest, QueryAccountResponse, QueryAccountsRequest, QueryAccountsResponse,
+    QueryParamsRequest, QueryParamsResponse,
 };
-use cosmrs::proto::traits::Message;
 
 use super::error::AccountError;
 use super::model::{AccountResponse, AccountsResponse, Address, ParamsResponse};
@@ -29,12 +26,7 @@ impl<T: CosmosClient> CosmTome<T> {
             message: "Invalid account address".to_string(),
         })?;
 
-        let base_account = BaseAccount::decode(account.value.as_slice())
-            .map_err(ChainError::prost_proto_decoding)?;
-
-        Ok(AccountResponse {
-            account: base_account.try_into()?,
-        })
+        account.try_into()
     }
 
     pub async fn auth_query_accounts(
@@ -50,15 +42,11 @@ impl<T: CosmosClient> CosmTome<T> {
             .query::<_, QueryAccountsResponse>(req, "/cosmos.auth.v1beta1.Query/Accounts")
             .await?;
 
-        let accounts: Vec<Account> = res
+        let accounts = res
             .accounts
             .into_iter()
-            .map(|a| {
-                BaseAccount::decode(a.value.as_slice())
-                    .map_err(ChainError::prost_proto_decoding)?
-                    .try_into()
-            })
-            .collect::<Result<Vec<Account>, AccountError>>()?;
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<AccountResponse>, AccountError>>()?;
 
         Ok(AccountsResponse {
             accounts,
@@ -10,6 +10,12 @@ pub enum AccountError {
     #[error("cannot parse account ID from bytes: {message:?}")]
     AccountIdParse { message: String },
 
+    #[error("unsupported account type: {type_url:?}")]
+    UnsupportedAccountType { type_url: String },
+
+    #[error("account is missing its base account")]
+    MissingBaseAccount,
+
     #[error(transparent)]
     ChainError(#[from] ChainError),
 }
@@ -1,11 +1,19 @@
 use std::{fmt, str::FromStr};
 
-use cosmrs::proto::cosmos::auth::v1beta1::{BaseAccount, Params as CosmosParams};
-use cosmrs::{crypto::PublicKey, AccountId};
-use schemars::JsonSchema;
+use cosmrs::proto::cosmos::auth::v1beta1::{BaseAccount, ModuleAccount, Params as CosmosParams};
+use cosmrs::proto::cosmos::crypto::secp256k1::PubKey as Secp256k1PubKey;
+use cosmrs::proto::cosmos::vesting::v1beta1::{
+    BaseVestingAccount, ContinuousVestingAccount, DelayedVestingAccount, Period,
+    PeriodicVestingAccount, PermanentLockedAccount,
+};
+use cosmrs::proto::ibc::applications::interchain_accounts::v1::InterchainAccount;
+use cosmrs::proto::traits::Message;
+use cosmrs::{crypto::PublicKey, tx::SignerPublicKey, AccountId, Any};
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
 use serde::{Deserialize, Serialize};
 
-use crate::chain::{error::ChainError, request::PaginationResponse};
+use crate::chain::time::{time_from_unix_seconds, Time};
+use crate::chain::{coin::Coin, error::ChainError, request::PaginationResponse};
 
 use super::error::AccountError;
 
@@ -55,6 +63,17 @@ impl FromStr for Address {
     }
 }
 
+// Addresses are serialized as bech32 strings
+impl JsonSchema for Address {
+    fn schema_name() -> String {
+        "Address".to_string()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        String::json_schema(gen)
+    }
+}
+
 impl From<AccountId> for Address {
     fn from(account: AccountId) -> Address {
         Address(account)
@@ -73,12 +92,13 @@ impl From<Address> for String {
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
 pub struct Account {
     /// Bech32 address of account
     pub address: Address,
 
-    pub pubkey: Option<PublicKey>,
+    #[schemars(with = "Option<serde_json::Value>")]
+    pub pubkey: Option<AccountPubKey>,
 
     pub account_number: u64,
 
@@ -93,7 +113,7 @@ impl TryFrom<BaseAccount> for Account {
             address: proto.address.parse()?,
             pubkey: proto
                 .pub_key
-                .map(PublicKey::try_from)
+                .map(TryInto::try_into)
                 .transpose()
                 .map_err(ChainError::crypto)?,
             account_number: proto.account_number,
@@ -102,14 +122,311 @@ impl TryFrom<BaseAccount> for Account {
     }
 }
 
+/// Public key of an account, along with the key type it is stored under on chain.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub enum AccountPubKey {
+    /// Cosmos sdk ed25519 or secp256k1 key
+    Cosmos(PublicKey),
+    /// Ethermint secp256k1 key, which has the same wire format as the cosmos sdk secp256k1 key
+    /// but is encoded under its own type url.
+    /// `SigningKey` cannot sign for these keys, since ethermint verifies Keccak-256 signatures.
+    EthSecp256k1(PublicKey),
+}
+
+impl AccountPubKey {
+    pub fn public_key(&self) -> PublicKey {
+        match self {
+            AccountPubKey::Cosmos(key) | AccountPubKey::EthSecp256k1(key) => *key,
+        }
+    }
+
+    pub fn type_url(&self) -> &str {
+        match self {
+            AccountPubKey::Cosmos(key) => key.type_url(),
+            AccountPubKey::EthSecp256k1(_) => ETH_SECP256K1_PUBKEY_TYPE_URL,
+        }
+    }
+}
+
+impl From<PublicKey> for AccountPubKey {
+    fn from(key: PublicKey) -> Self {
+        AccountPubKey::Cosmos(key)
+    }
+}
+
+impl TryFrom<Any> for AccountPubKey {
+    type Error = cosmrs::ErrorReport;
+
+    fn try_from(any: Any) -> Result<Self, Self::Error> {
+        if any.type_url == ETH_SECP256K1_PUBKEY_TYPE_URL {
+            let key = Secp256k1PubKey::decode(any.value.as_slice())?;
+            return Ok(AccountPubKey::EthSecp256k1(key.try_into()?));
+        }
+
+        Ok(AccountPubKey::Cosmos(any.try_into()?))
+    }
+}
+
+impl From<AccountPubKey> for SignerPublicKey {
+    fn from(key: AccountPubKey) -> Self {
+        match key {
+            AccountPubKey::Cosmos(key) => SignerPublicKey::Single(key),
+            AccountPubKey::EthSecp256k1(key) => SignerPublicKey::Any(Any {
+                type_url: ETH_SECP256K1_PUBKEY_TYPE_URL.to_string(),
+                value: Secp256k1PubKey {
+                    key: key.to_bytes(),
+                }
+                .encode_to_vec(),
+            }),
+        }
+    }
+}
+
+pub const BASE_ACCOUNT_TYPE_URL: &str = "/cosmos.auth.v1beta1.BaseAccount";
+pub const MODULE_ACCOUNT_TYPE_URL: &str = "/cosmos.auth.v1beta1.ModuleAccount";
+pub const CONTINUOUS_VESTING_ACCOUNT_TYPE_URL: &str =
+    "/cosmos.vesting.v1beta1.ContinuousVestingAccount";
+pub const DELAYED_VESTING_ACCOUNT_TYPE_URL: &str = "/cosmos.vesting.v1beta1.DelayedVestingAccount";
+pub const PERIODIC_VESTING_ACCOUNT_TYPE_URL: &str =
+    "/cosmos.vesting.v1beta1.PeriodicVestingAccount";
+pub const PERMANENT_LOCKED_ACCOUNT_TYPE_URL: &str =
+    "/cosmos.vesting.v1beta1.PermanentLockedAccount";
+pub const ETH_ACCOUNT_TYPE_URL: &str = "/ethermint.types.v1.EthAccount";
+pub const INTERCHAIN_ACCOUNT_TYPE_URL: &str =
+    "/ibc.applications.interchain_accounts.v1.InterchainAccount";
+
+pub const ETH_SECP256K1_PUBKEY_TYPE_URL: &str = "/ethermint.crypto.v1.ethsecp256k1.PubKey";
+
+/// Ethermint `EthAccount`, which is not part of the cosmos sdk protos.
+#[derive(Clone, PartialEq, prost::Message)]
+struct EthAccount {
+    #[prost(message, optional, tag = "1")]
+    base_account: Option<BaseAccount>,
+    #[prost(string, tag = "2")]
+    code_hash: String,
+}
+
+/// Account types extend `BaseAccount` by embedding it as their first field,
+/// which lets us read the signing account of types unknown to cosm-tome.
+#[derive(Clone, PartialEq, prost::Message)]
+struct UnknownAccount {
+    #[prost(message, optional, tag = "1")]
+    base_account: Option<BaseAccount>,
+}
+
+/// The concrete account type stored on chain, along with any data specific to it.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub enum AccountKind {
+    Base,
+    Module {
+        name: String,
+        permissions: Vec<String>,
+    },
+    ContinuousVesting {
+        vesting: VestingInfo,
+        start_time: Time,
+    },
+    DelayedVesting {
+        vesting: VestingInfo,
+    },
+    PeriodicVesting {
+        vesting: VestingInfo,
+        start_time: Time,
+        periods: Vec<VestingPeriod>,
+    },
+    PermanentLocked {
+        vesting: VestingInfo,
+    },
+    Eth {
+        code_hash: String,
+    },
+    /// ICS-27 interchain account, controlled by `account_owner` on the controller chain
+    Interchain {
+        account_owner: String,
+    },
+    /// Account type unknown to cosm-tome
+    Other {
+        type_url: String,
+        value: Vec<u8>,
+    },
+}
+
+/// Fields shared by every vesting account type
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct VestingInfo {
+    pub original_vesting: Vec<Coin>,
+    pub delegated_free: Vec<Coin>,
+    pub delegated_vesting: Vec<Coin>,
+    /// Time at which all coins are vested
+    #[schemars(with = "String")]
+    pub end_time: Time,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct VestingPeriod {
+    /// Length of the period in seconds
+    pub length: i64,
+    pub amount: Vec<Coin>,
+}
+
+impl TryFrom<Period> for VestingPeriod {
+    type Error = ChainError;
+
+    fn try_from(proto: Period) -> Result<Self, Self::Error> {
+        Ok(Self {
+            length: proto.length,
+            amount: proto
+                .amount
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+impl From<VestingPeriod> for Period {
+    fn from(period: VestingPeriod) -> Self {
+        Self {
+            length: period.length,
+            amount: period.amount.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+fn decode_account<M: Message + Default>(any: &Any) -> Result<M, AccountError> {
+    Ok(M::decode(any.value.as_slice()).map_err(ChainError::prost_proto_decoding)?)
+}
+
+fn base_account(proto: Option<BaseAccount>) -> Result<Account, AccountError> {
+    proto.ok_or(AccountError::MissingBaseAccount)?.try_into()
+}
+
+fn base_vesting(proto: Option<BaseVestingAccount>) -> Result<(Account, VestingInfo), AccountError> {
+    let proto = proto.ok_or(AccountError::MissingBaseAccount)?;
+    let coins = |coins: Vec<cosmrs::proto::cosmos::base::v1beta1::Coin>| {
+        coins
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<Coin>, ChainError>>()
+    };
+
+    Ok((
+        base_account(proto.base_account)?,
+        VestingInfo {
+            original_vesting: coins(proto.original_vesting)?,
+            delegated_free: coins(proto.delegated_free)?,
+            delegated_vesting: coins(proto.delegated_vesting)?,
+            end_time: time_from_unix_seconds(proto.end_time)?,
+        },
+    ))
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
 pub struct AccountResponse {
+    /// Base account used for signing, regardless of the account kind
     pub account: Account,
+
+    pub kind: AccountKind,
+}
+
+impl TryFrom<Any> for AccountResponse {
+    type Error = AccountError;
+
+    fn try_from(any: Any) -> Result<Self, Self::Error> {
+        let (account, kind) = match any.type_url.as_str() {
+            BASE_ACCOUNT_TYPE_URL => (
+                decode_account::<BaseAccount>(&any)?.try_into()?,
+                AccountKind::Base,
+            ),
+            MODULE_ACCOUNT_TYPE_URL => {
+                let acc = decode_account::<ModuleAccount>(&any)?;
+                (
+                    base_account(acc.base_account)?,
+                    AccountKind::Module {
+                        name: acc.name,
+                        permissions: acc.permissions,
+                    },
+                )
+            }
+            CONTINUOUS_VESTING_ACCOUNT_TYPE_URL => {
+                let acc = decode_account::<ContinuousVestingAccount>(&any)?;
+                let (account, vesting) = base_vesting(acc.base_vesting_account)?;
+                (
+                    account,
+                    AccountKind::ContinuousVesting {
+                        vesting,
+                        start_time: time_from_unix_seconds(acc.start_time)?,
+                    },
+                )
+            }
+            DELAYED_VESTING_ACCOUNT_TYPE_URL => {
+                let acc = decode_account::<DelayedVestingAccount>(&any)?;
+                let (account, vesting) = base_vesting(acc.base_vesting_account)?;
+                (account, AccountKind::DelayedVesting { vesting })
+            }
+            PERIODIC_VESTING_ACCOUNT_TYPE_URL => {
+                let acc = decode_account::<PeriodicVestingAccount>(&any)?;
+                let (account, vesting) = base_vesting(acc.base_vesting_account)?;
+                (
+                    account,
+                    AccountKind::PeriodicVesting {
+                        vesting,
+                        start_time: time_from_unix_seconds(acc.start_time)?,
+                        periods: acc
+                            .vesting_periods
+                            .into_iter()
+                            .map(TryInto::try_into)
+                            .collect::<Result<Vec<_>, ChainError>>()?,
+                    },
+                )
+            }
+            PERMANENT_LOCKED_ACCOUNT_TYPE_URL => {
+                let acc = decode_account::<PermanentLockedAccount>(&any)?;
+                let (account, vesting) = base_vesting(acc.base_vesting_account)?;
+                (account, AccountKind::PermanentLocked { vesting })
+            }
+            ETH_ACCOUNT_TYPE_URL => {
+                let acc = decode_account::<EthAccount>(&any)?;
+                (
+                    base_account(acc.base_account)?,
+                    AccountKind::Eth {
+                        code_hash: acc.code_hash,
+                    },
+                )
+            }
+            INTERCHAIN_ACCOUNT_TYPE_URL => {
+                let acc = decode_account::<InterchainAccount>(&any)?;
+                (
+                    base_account(acc.base_account)?,
+                    AccountKind::Interchain {
+                        account_owner: acc.account_owner,
+                    },
+                )
+            }
+            _ => {
+                let account = decode_account::<UnknownAccount>(&any)
+                    .and_then(|acc| base_account(acc.base_account))
+                    .map_err(|_| AccountError::UnsupportedAccountType {
+                        type_url: any.type_url.clone(),
+                    })?;
+
+                (
+                    account,
+                    AccountKind::Other {
+                        type_url: any.type_url,
+                        value: any.value,
+                    },
+                )
+            }
+        };
+
+        Ok(Self { account, kind })
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
 pub struct AccountsResponse {
-    pub accounts: Vec<Account>,
+    pub accounts: Vec<AccountResponse>,
 
     pub next: Option<PaginationResponse>,
 }
@@ -151,3 +468,164 @@ impl From<Params> for CosmosParams {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use cosmrs::proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+    use cosmrs::proto::traits::MessageExt;
+
+    use super::*;
+
+    fn base() -> BaseAccount {
+        BaseAccount {
+            address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+            pub_key: None,
+            account_number: 7,
+            sequence: 3,
+        }
+    }
+
+    #[test]
+    fn test_decode_account_kinds() {
+        let periodic = Any {
+            type_url: PERIODIC_VESTING_ACCOUNT_TYPE_URL.to_string(),
+            value: PeriodicVestingAccount {
+                base_vesting_account: Some(BaseVestingAccount {
+                    base_account: Some(base()),
+                    original_vesting: vec![ProtoCoin {
+                        denom: "ujuno".to_string(),
+                        amount: "100".to_string(),
+                    }],
+                    delegated_free: vec![],
+                    delegated_vesting: vec![],
+                    end_time: 2000,
+                }),
+                start_time: 1000,
+                vesting_periods: vec![Period {
+                    length: 1000,
+                    amount: vec![ProtoCoin {
+                        denom: "ujuno".to_string(),
+                        amount: "100".to_string(),
+                    }],
+                }],
+            }
+            .to_bytes()
+            .unwrap(),
+        };
+
+        let res = AccountResponse::try_from(periodic).unwrap();
+        assert_eq!(res.account.sequence, 3);
+        assert_eq!(res.account.account_number, 7);
+        match res.kind {
+            AccountKind::PeriodicVesting {
+                vesting,
+                start_time,
+                periods,
+            } => {
+                assert_eq!(
+                    vesting.end_time,
+                    Time::from_unix_timestamp(2000, 0).unwrap()
+                );
+                assert_eq!(vesting.original_vesting[0].amount, 100);
+                assert_eq!(start_time, Time::from_unix_timestamp(1000, 0).unwrap());
+                assert_eq!(periods.len(), 1);
+            }
+            kind => panic!("unexpected account kind: {kind:?}"),
+        }
+
+        let module = Any {
+            type_url: MODULE_ACCOUNT_TYPE_URL.to_string(),
+            value: ModuleAccount {
+                base_account: Some(base()),
+                name: "distribution".to_string(),
+                permissions: vec![],
+            }
+            .to_bytes()
+            .unwrap(),
+        };
+
+        let res = AccountResponse::try_from(module).unwrap();
+        assert!(matches!(res.kind, AccountKind::Module { name, .. } if name == "distribution"));
+
+        let unknown = Any {
+            type_url: "/foo.Account".to_string(),
+            value: base().to_bytes().unwrap(),
+        };
+
+        assert!(matches!(
+            AccountResponse::try_from(unknown),
+            Err(AccountError::UnsupportedAccountType { .. })
+        ));
+    }
+
+    #[test]
+    fn test_decode_interchain_and_other_accounts() {
+        let ica = Any {
+            type_url: INTERCHAIN_ACCOUNT_TYPE_URL.to_string(),
+            value: InterchainAccount {
+                base_account: Some(base()),
+                account_owner: "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea".to_string(),
+            }
+            .to_bytes()
+            .unwrap(),
+        };
+
+        let res = AccountResponse::try_from(ica).unwrap();
+        assert_eq!(res.account.account_number, 7);
+        assert_eq!(
+            res.kind,
+            AccountKind::Interchain {
+                account_owner: "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea".to_string()
+            }
+        );
+
+        // unknown account types embedding a base account are kept as is
+        let value = EthAccount {
+            base_account: Some(base()),
+            code_hash: "0x00".to_string(),
+        }
+        .encode_to_vec();
+        let other = Any {
+            type_url: "/injective.types.v1beta1.EthAccount".to_string(),
+            value: value.clone(),
+        };
+
+        let res = AccountResponse::try_from(other).unwrap();
+        assert_eq!(res.account.sequence, 3);
+        assert_eq!(
+            res.kind,
+            AccountKind::Other {
+                type_url: "/injective.types.v1beta1.EthAccount".to_string(),
+                value,
+            }
+        );
+    }
+
+    #[test]
+    fn test_decode_eth_pubkey() {
+        let key = Secp256k1PubKey {
+            key: vec![
+                2, 133, 92, 244, 96, 150, 124, 218, 163, 197, 56, 2, 253, 64, 208, 163, 200, 72,
+                70, 63, 221, 184, 253, 197, 145, 211, 25, 200, 198, 150, 214, 213, 162,
+            ],
+        };
+
+        let pubkey = AccountPubKey::try_from(Any {
+            type_url: ETH_SECP256K1_PUBKEY_TYPE_URL.to_string(),
+            value: key.encode_to_vec(),
+        })
+        .unwrap();
+
+        assert!(matches!(pubkey, AccountPubKey::EthSecp256k1(_)));
+        assert_eq!(pubkey.type_url(), ETH_SECP256K1_PUBKEY_TYPE_URL);
+
+        // signing keeps the ethermint type url instead of the cosmos secp256k1 one
+        match SignerPublicKey::from(pubkey) {
+            SignerPublicKey::Any(any) => {
+                assert_eq!(any.type_url, ETH_SECP256K1_PUBKEY_TYPE_URL);
+                assert_eq!(any.value, key.encode_to_vec());
+            }
+            _ => panic!("ethermint key signed under a cosmos type url"),
+        }
+    }
+}
@@ -0,0 +1,292 @@
+use cosmrs::proto::cosmos::authz::v1beta1::{
+    QueryGranteeGrantsRequest, QueryGranteeGrantsResponse, QueryGranterGrantsRequest,
+    QueryGranterGrantsResponse, QueryGrantsRequest, QueryGrantsResponse,
+};
+
+use crate::{
+    chain::{
+        error::ChainError,
+        msg::Msg,
+        request::{PaginationRequest, TxOptions},
+        Any,
+    },
+    clients::client::{CosmTome, CosmosClient},
+    modules::auth::model::Address,
+    signing_key::key::SigningKey,
+};
+
+use super::{
+    error::AuthzError,
+    model::{
+        AuthzTxResponse, ExecRequest, GrantAuthorizationsResponse, GrantRequest, GrantsResponse,
+        RevokeRequest,
+    },
+};
+
+impl<T: CosmosClient> CosmTome<T> {
+    pub async fn authz_grant(
+        &self,
+        req: GrantRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<AuthzTxResponse, AuthzError> {
+        let res = self.tx_send(vec![req], key, tx_options).await?;
+
+        Ok(AuthzTxResponse { res })
+    }
+
+    pub async fn authz_revoke(
+        &self,
+        req: RevokeRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<AuthzTxResponse, AuthzError> {
+        let res = self.tx_send(vec![req], key, tx_options).await?;
+
+        Ok(AuthzTxResponse { res })
+    }
+
+    /// Execute `msgs` on behalf of their signer (the granter), using the grants given to `key`.
+    ///
+    /// The msgs must be built with the granter as their signer, ie. `SendRequest { from: granter, .. }`,
+    /// or `cosmwasm::model::ExecRequest::to_proto(granter)` for the cosmwasm requests.
+    pub async fn authz_exec(
+        &self,
+        msgs: Vec<impl Msg>,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<AuthzTxResponse, AuthzError> {
+        let msgs = msgs
+            .into_iter()
+            .map(|m| m.into_any())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| ChainError::ProtoEncoding {
+                message: e.to_string(),
+            })?;
+
+        self.authz_exec_any(msgs, key, tx_options).await
+    }
+
+    /// Same as `authz_exec()`, for already encoded messages.
+    /// Use `Msg::into_any()` to execute different message types in the same `MsgExec`.
+    pub async fn authz_exec_any(
+        &self,
+        msgs: Vec<Any>,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<AuthzTxResponse, AuthzError> {
+        let grantee = key.to_addr(&self.cfg.prefix).await?;
+
+        // encoded upfront to report `AuthzError::EmptyMsgs` as is
+        let msg = ExecRequest { grantee, msgs }.into_any()?;
+
+        let res = self.tx_send_any(vec![msg], key, tx_options).await?;
+
+        Ok(AuthzTxResponse { res })
+    }
+
+    /// Query the grants given by `granter` to `grantee`, optionally only the one for `msg_type_url`
+    pub async fn authz_query_grants(
+        &self,
+        granter: Address,
+        grantee: Address,
+        msg_type_url: Option<String>,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<GrantsResponse, AuthzError> {
+        let req = QueryGrantsRequest {
+            granter: granter.into(),
+            grantee: grantee.into(),
+            msg_type_url: msg_type_url.unwrap_or_default(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryGrantsResponse>(req, "/cosmos.authz.v1beta1.Query/Grants")
+            .await?;
+
+        Ok(GrantsResponse {
+            grants: res
+                .grants
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query all grants given by `granter`
+    ///
+    /// Since: cosmos-sdk 0.46
+    pub async fn authz_query_granter_grants(
+        &self,
+        granter: Address,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<GrantAuthorizationsResponse, AuthzError> {
+        let req = QueryGranterGrantsRequest {
+            granter: granter.into(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryGranterGrantsResponse>(
+                req,
+                "/cosmos.authz.v1beta1.Query/GranterGrants",
+            )
+            .await?;
+
+        Ok(GrantAuthorizationsResponse {
+            grants: res
+                .grants
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query all grants given to `grantee`
+    ///
+    /// Since: cosmos-sdk 0.46
+    pub async fn authz_query_grantee_grants(
+        &self,
+        grantee: Address,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<GrantAuthorizationsResponse, AuthzError> {
+        let req = QueryGranteeGrantsRequest {
+            grantee: grantee.into(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryGranteeGrantsResponse>(
+                req,
+                "/cosmos.authz.v1beta1.Query/GranteeGrants",
+            )
+            .await?;
+
+        Ok(GrantAuthorizationsResponse {
+            grants: res
+                .grants
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use cosmrs::proto::cosmos::{
+        auth::v1beta1::{BaseAccount, QueryAccountRequest, QueryAccountResponse},
+        authz::v1beta1::MsgExec,
+        bank::v1beta1::MsgSend,
+    };
+    use cosmrs::proto::traits::{Message, MessageExt};
+
+    use crate::{
+        chain::{
+            coin::Coin,
+            fee::GasInfo,
+            request::TxOptions,
+            response::{ChainResponse, ChainTxResponse, Code},
+        },
+        clients::client::{CosmTome, MockCosmosClient},
+        modules::{auth::model::BASE_ACCOUNT_TYPE_URL, bank::model::SendRequest, tx::model::RawTx},
+        signing_key::key::SigningKey,
+        test_utils::test_cfg,
+    };
+
+    #[tokio::test]
+    async fn test_authz_exec() {
+        let cfg = test_cfg();
+        let tx_options = TxOptions::default();
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
+        let grantee = key.to_addr(&cfg.prefix).await.unwrap().to_string();
+
+        let mut mock_client = MockCosmosClient::new();
+
+        let account_addr = grantee.clone();
+        mock_client
+            .expect_query::<QueryAccountRequest, QueryAccountResponse>()
+            .times(1)
+            .returning(move |_, _| {
+                Ok(QueryAccountResponse {
+                    account: Some(cosmrs::proto::Any {
+                        type_url: BASE_ACCOUNT_TYPE_URL.to_string(),
+                        value: BaseAccount {
+                            address: account_addr.clone(),
+                            pub_key: None,
+                            account_number: 1337,
+                            sequence: 1,
+                        }
+                        .to_bytes()
+                        .unwrap(),
+                    }),
+                })
+            });
+
+        mock_client.expect_simulate_tx().times(1).returning(|_| {
+            Ok(GasInfo {
+                gas_wanted: 200u16.into(),
+                gas_used: 100u16.into(),
+            })
+        });
+
+        mock_client
+            .expect_broadcast_tx_block()
+            .times(1)
+            .withf(move |tx: &RawTx| {
+                let tx = cosmrs::Tx::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+                let exec = MsgExec::decode(tx.body.messages[0].value.as_slice()).unwrap();
+                let send = MsgSend::decode(exec.msgs[0].value.as_slice()).unwrap();
+
+                tx.body.messages.len() == 1
+                    && tx.body.messages[0].type_url == "/cosmos.authz.v1beta1.MsgExec"
+                    && exec.grantee == grantee
+                    && exec.msgs[0].type_url == "/cosmos.bank.v1beta1.MsgSend"
+                    && send.from_address == "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+            })
+            .returning(|_| {
+                Ok(ChainTxResponse {
+                    res: ChainResponse {
+                        code: Code::Ok,
+                        codespace: String::new(),
+                        data: None,
+                        log: "log log log".to_string(),
+                    },
+                    events: vec![],
+                    gas_wanted: 200,
+                    gas_used: 100,
+                    tx_hash: "TX_HASH_0".to_string(),
+                    height: 1337,
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg.clone(), mock_client);
+
+        let send = SendRequest {
+            from: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                .parse()
+                .unwrap(),
+            to: "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                .parse()
+                .unwrap(),
+            amounts: vec![Coin {
+                denom: cfg.denom.parse().unwrap(),
+                amount: 10,
+            }],
+        };
+
+        let res = cosm_tome
+            .authz_exec(vec![send], &key, &tx_options)
+            .await
+            .unwrap();
+
+        assert_eq!(res.res.tx_hash, "TX_HASH_0");
+    }
+}
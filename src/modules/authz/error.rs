@@ -0,0 +1,27 @@
+use thiserror::Error;
+
+use crate::{
+    chain::error::ChainError,
+    modules::{auth::error::AccountError, tx::error::TxError},
+};
+
+#[derive(Error, Debug)]
+pub enum AuthzError {
+    #[error("Cannot execute 0 messages with MsgExec")]
+    EmptyMsgs,
+
+    #[error("Invalid stake authorization type: {i}")]
+    StakeAuthorizationType { i: i32 },
+
+    #[error("Grant is missing its authorization")]
+    MissingAuthorization,
+
+    #[error(transparent)]
+    TxError(#[from] TxError),
+
+    #[error(transparent)]
+    AccountError(#[from] AccountError),
+
+    #[error(transparent)]
+    ChainError(#[from] ChainError),
+}
@@ -0,0 +1,479 @@
+use cosmrs::proto::cosmos::authz::v1beta1::{
+    Grant as ProtoGrant, GrantAuthorization as ProtoGrantAuthorization,
+};
+use cosmrs::proto::cosmos::staking::v1beta1::{
+    stake_authorization::{Policy, Validators},
+    AuthorizationType,
+};
+use cosmrs::proto::traits::{Message, TypeUrl};
+use cosmrs::tx::MessageExt;
+use cosmrs::{tendermint::Time, Any};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::chain::coin::Coin;
+use crate::chain::error::ChainError;
+use crate::chain::msg::Msg;
+use crate::chain::request::PaginationResponse;
+use crate::chain::response::ChainTxResponse;
+use crate::chain::time::{time_from_proto, time_to_proto};
+use crate::modules::auth::model::Address;
+use crate::modules::tx::model::any_msgs;
+
+use super::error::AuthzError;
+use super::v1beta1::{
+    GenericAuthorization, MsgExec, MsgGrant, MsgRevoke, SendAuthorization, StakeAuthorization,
+};
+
+pub const GENERIC_AUTHORIZATION_TYPE_URL: &str = GenericAuthorization::TYPE_URL;
+pub const SEND_AUTHORIZATION_TYPE_URL: &str = SendAuthorization::TYPE_URL;
+pub const STAKE_AUTHORIZATION_TYPE_URL: &str = StakeAuthorization::TYPE_URL;
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct AuthzTxResponse {
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct GrantsResponse {
+    pub grants: Vec<Grant>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct GrantAuthorizationsResponse {
+    pub grants: Vec<GrantAuthorization>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+/// Permission given by a granter to a grantee to execute messages on its behalf
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub enum Authorization {
+    /// Unrestricted permission to execute messages of type `msg_type_url`, ie. `/cosmos.gov.v1beta1.MsgVote`
+    Generic { msg_type_url: String },
+
+    /// Permission to send up to `spend_limit` coins from the granter's account
+    Send { spend_limit: Vec<Coin> },
+
+    /// Permission to delegate, undelegate or redelegate the granter's tokens
+    Stake {
+        authorization_type: StakeAuthorizationType,
+
+        /// Maximum amount of tokens that can be (un/re)delegated, `None` if unlimited
+        max_tokens: Option<Coin>,
+
+        validators: Option<StakeValidators>,
+    },
+
+    /// Authorization type unknown to cosm-tome
+    Other { type_url: String, value: Vec<u8> },
+}
+
+impl Authorization {
+    /// Type url of the messages that this authorization allows the grantee to execute
+    pub fn msg_type_url(&self) -> Option<String> {
+        match self {
+            Authorization::Generic { msg_type_url } => Some(msg_type_url.clone()),
+            Authorization::Send { .. } => Some("/cosmos.bank.v1beta1.MsgSend".to_string()),
+            Authorization::Stake {
+                authorization_type, ..
+            } => Some(authorization_type.msg_type_url().to_string()),
+            Authorization::Other { .. } => None,
+        }
+    }
+}
+
+impl TryFrom<Any> for Authorization {
+    type Error = AuthzError;
+
+    fn try_from(any: Any) -> Result<Self, Self::Error> {
+        let authorization = match any.type_url.as_str() {
+            GENERIC_AUTHORIZATION_TYPE_URL => {
+                let a = GenericAuthorization::decode(any.value.as_slice())
+                    .map_err(ChainError::prost_proto_decoding)?;
+
+                Authorization::Generic {
+                    msg_type_url: a.msg,
+                }
+            }
+            SEND_AUTHORIZATION_TYPE_URL => {
+                let a = SendAuthorization::decode(any.value.as_slice())
+                    .map_err(ChainError::prost_proto_decoding)?;
+
+                Authorization::Send {
+                    spend_limit: a
+                        .spend_limit
+                        .into_iter()
+                        .map(TryInto::try_into)
+                        .collect::<Result<Vec<_>, _>>()?,
+                }
+            }
+            STAKE_AUTHORIZATION_TYPE_URL => {
+                let a = StakeAuthorization::decode(any.value.as_slice())
+                    .map_err(ChainError::prost_proto_decoding)?;
+
+                Authorization::Stake {
+                    authorization_type: a.authorization_type.try_into()?,
+                    max_tokens: a.max_tokens.map(TryInto::try_into).transpose()?,
+                    validators: a.validators.map(TryInto::try_into).transpose()?,
+                }
+            }
+            _ => Authorization::Other {
+                type_url: any.type_url,
+                value: any.value,
+            },
+        };
+
+        Ok(authorization)
+    }
+}
+
+impl TryFrom<Authorization> for Any {
+    type Error = AuthzError;
+
+    fn try_from(a: Authorization) -> Result<Self, Self::Error> {
+        let any = match a {
+            Authorization::Generic { msg_type_url } => {
+                GenericAuthorization { msg: msg_type_url }.to_any()
+            }
+            Authorization::Send { spend_limit } => SendAuthorization {
+                spend_limit: spend_limit.into_iter().map(Into::into).collect(),
+            }
+            .to_any(),
+            Authorization::Stake {
+                authorization_type,
+                max_tokens,
+                validators,
+            } => StakeAuthorization {
+                max_tokens: max_tokens.map(Into::into),
+                authorization_type: authorization_type.into(),
+                validators: validators.map(Into::into),
+            }
+            .to_any(),
+            Authorization::Other { type_url, value } => return Ok(Any { type_url, value }),
+        };
+
+        Ok(any.map_err(ChainError::prost_proto_encoding)?)
+    }
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub enum StakeAuthorizationType {
+    Delegate,
+    Undelegate,
+    Redelegate,
+}
+
+impl StakeAuthorizationType {
+    pub fn msg_type_url(&self) -> &'static str {
+        match self {
+            StakeAuthorizationType::Delegate => "/cosmos.staking.v1beta1.MsgDelegate",
+            StakeAuthorizationType::Undelegate => "/cosmos.staking.v1beta1.MsgUndelegate",
+            StakeAuthorizationType::Redelegate => "/cosmos.staking.v1beta1.MsgBeginRedelegate",
+        }
+    }
+}
+
+impl TryFrom<i32> for StakeAuthorizationType {
+    type Error = AuthzError;
+
+    fn try_from(i: i32) -> Result<Self, Self::Error> {
+        match AuthorizationType::from_i32(i) {
+            Some(AuthorizationType::Delegate) => Ok(StakeAuthorizationType::Delegate),
+            Some(AuthorizationType::Undelegate) => Ok(StakeAuthorizationType::Undelegate),
+            Some(AuthorizationType::Redelegate) => Ok(StakeAuthorizationType::Redelegate),
+            Some(AuthorizationType::Unspecified) | None => {
+                Err(AuthzError::StakeAuthorizationType { i })
+            }
+        }
+    }
+}
+
+impl From<StakeAuthorizationType> for i32 {
+    fn from(t: StakeAuthorizationType) -> Self {
+        match t {
+            StakeAuthorizationType::Delegate => AuthorizationType::Delegate as i32,
+            StakeAuthorizationType::Undelegate => AuthorizationType::Undelegate as i32,
+            StakeAuthorizationType::Redelegate => AuthorizationType::Redelegate as i32,
+        }
+    }
+}
+
+/// Validators that the grantee is allowed, or not allowed, to stake with
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub enum StakeValidators {
+    Allow(Vec<Address>),
+    Deny(Vec<Address>),
+}
+
+impl TryFrom<Policy> for StakeValidators {
+    type Error = AuthzError;
+
+    fn try_from(p: Policy) -> Result<Self, Self::Error> {
+        let parse = |v: Validators| {
+            v.address
+                .into_iter()
+                .map(|a| a.parse())
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        Ok(match p {
+            Policy::AllowList(v) => StakeValidators::Allow(parse(v)?),
+            Policy::DenyList(v) => StakeValidators::Deny(parse(v)?),
+        })
+    }
+}
+
+impl From<StakeValidators> for Policy {
+    fn from(v: StakeValidators) -> Self {
+        let list = |addrs: Vec<Address>| Validators {
+            address: addrs.into_iter().map(Into::into).collect(),
+        };
+
+        match v {
+            StakeValidators::Allow(addrs) => Policy::AllowList(list(addrs)),
+            StakeValidators::Deny(addrs) => Policy::DenyList(list(addrs)),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct Grant {
+    pub authorization: Authorization,
+
+    /// Time after which the grant can no longer be used, `None` if it never expires
+    pub expiration: Option<Time>,
+}
+
+impl TryFrom<ProtoGrant> for Grant {
+    type Error = AuthzError;
+
+    fn try_from(g: ProtoGrant) -> Result<Self, Self::Error> {
+        Ok(Self {
+            authorization: g
+                .authorization
+                .ok_or(AuthzError::MissingAuthorization)?
+                .try_into()?,
+            expiration: g.expiration.map(time_from_proto).transpose()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct GrantAuthorization {
+    pub granter: Address,
+    pub grantee: Address,
+    pub authorization: Authorization,
+    pub expiration: Option<Time>,
+}
+
+impl TryFrom<ProtoGrantAuthorization> for GrantAuthorization {
+    type Error = AuthzError;
+
+    fn try_from(g: ProtoGrantAuthorization) -> Result<Self, Self::Error> {
+        Ok(Self {
+            granter: g.granter.parse()?,
+            grantee: g.grantee.parse()?,
+            authorization: g
+                .authorization
+                .ok_or(AuthzError::MissingAuthorization)?
+                .try_into()?,
+            expiration: g.expiration.map(time_from_proto).transpose()?,
+        })
+    }
+}
+
+/// Grants `grantee` permission to execute messages on behalf of `granter`.
+/// Granting the same message type twice overwrites the previous grant.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct GrantRequest {
+    pub granter: Address,
+    pub grantee: Address,
+    pub authorization: Authorization,
+    pub expiration: Option<Time>,
+}
+
+impl Msg for GrantRequest {
+    type Proto = MsgGrant;
+    type Err = AuthzError;
+}
+
+impl TryFrom<MsgGrant> for GrantRequest {
+    type Error = AuthzError;
+
+    fn try_from(msg: MsgGrant) -> Result<Self, Self::Error> {
+        let grant = msg.grant.unwrap_or_default();
+
+        Ok(Self {
+            granter: msg.granter.parse()?,
+            grantee: msg.grantee.parse()?,
+            authorization: grant
+                .authorization
+                .ok_or(AuthzError::MissingAuthorization)?
+                .try_into()?,
+            expiration: grant.expiration.map(time_from_proto).transpose()?,
+        })
+    }
+}
+
+impl TryFrom<GrantRequest> for MsgGrant {
+    type Error = AuthzError;
+
+    fn try_from(req: GrantRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            granter: req.granter.into(),
+            grantee: req.grantee.into(),
+            grant: Some(ProtoGrant {
+                authorization: Some(req.authorization.try_into()?),
+                expiration: req.expiration.map(time_to_proto),
+            }),
+        })
+    }
+}
+
+/// Revokes the grant that allowed `grantee` to execute messages of type `msg_type_url` on behalf of `granter`
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct RevokeRequest {
+    pub granter: Address,
+    pub grantee: Address,
+    pub msg_type_url: String,
+}
+
+impl Msg for RevokeRequest {
+    type Proto = MsgRevoke;
+    type Err = AuthzError;
+}
+
+impl TryFrom<MsgRevoke> for RevokeRequest {
+    type Error = AuthzError;
+
+    fn try_from(msg: MsgRevoke) -> Result<Self, Self::Error> {
+        Ok(Self {
+            granter: msg.granter.parse()?,
+            grantee: msg.grantee.parse()?,
+            msg_type_url: msg.msg_type_url,
+        })
+    }
+}
+
+impl TryFrom<RevokeRequest> for MsgRevoke {
+    type Error = AuthzError;
+
+    fn try_from(req: RevokeRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            granter: req.granter.into(),
+            grantee: req.grantee.into(),
+            msg_type_url: req.msg_type_url,
+        })
+    }
+}
+
+/// Executes `msgs` on behalf of their signer (the granter), using the grants given to `grantee`
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ExecRequest {
+    pub grantee: Address,
+    #[serde(with = "any_msgs")]
+    pub msgs: Vec<Any>,
+}
+
+impl Msg for ExecRequest {
+    type Proto = MsgExec;
+    type Err = AuthzError;
+}
+
+impl TryFrom<MsgExec> for ExecRequest {
+    type Error = AuthzError;
+
+    fn try_from(msg: MsgExec) -> Result<Self, Self::Error> {
+        Ok(Self {
+            grantee: msg.grantee.parse()?,
+            msgs: msg.msgs,
+        })
+    }
+}
+
+impl TryFrom<ExecRequest> for MsgExec {
+    type Error = AuthzError;
+
+    fn try_from(req: ExecRequest) -> Result<Self, Self::Error> {
+        if req.msgs.is_empty() {
+            return Err(AuthzError::EmptyMsgs);
+        }
+
+        Ok(Self {
+            grantee: req.grantee.into(),
+            msgs: req.msgs,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::chain::msg::Msg;
+
+    use super::*;
+
+    #[test]
+    fn test_authz_msgs_any_roundtrip() {
+        let grant = GrantRequest {
+            granter: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                .parse()
+                .unwrap(),
+            grantee: "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                .parse()
+                .unwrap(),
+            authorization: Authorization::Send {
+                spend_limit: vec![Coin {
+                    denom: "ujuno".parse().unwrap(),
+                    amount: 100,
+                }],
+            },
+            expiration: Some(Time::from_unix_timestamp(1_700_000_000, 0).unwrap()),
+        };
+
+        let any = grant.to_any().unwrap();
+        assert_eq!(any.type_url, "/cosmos.authz.v1beta1.MsgGrant");
+        assert_eq!(GrantRequest::from_any(&any).unwrap(), grant);
+
+        let revoke = RevokeRequest {
+            granter: grant.granter,
+            grantee: grant.grantee,
+            msg_type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+        };
+
+        let any = revoke.to_any().unwrap();
+        assert_eq!(any.type_url, "/cosmos.authz.v1beta1.MsgRevoke");
+        assert_eq!(RevokeRequest::from_any(&any).unwrap(), revoke);
+
+        let exec = ExecRequest {
+            grantee: revoke.grantee,
+            msgs: vec![any],
+        };
+
+        let any = exec.to_any().unwrap();
+        assert_eq!(any.type_url, "/cosmos.authz.v1beta1.MsgExec");
+        assert_eq!(ExecRequest::from_any(&any).unwrap(), exec);
+
+        let empty = ExecRequest {
+            msgs: vec![],
+            ..exec
+        };
+        assert!(matches!(empty.into_any(), Err(AuthzError::EmptyMsgs)));
+    }
+
+    #[test]
+    fn test_grant_missing_authorization() {
+        let msg = MsgGrant {
+            granter: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+            grantee: "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea".to_string(),
+            grant: None,
+        };
+
+        assert!(matches!(
+            GrantRequest::try_from(msg),
+            Err(AuthzError::MissingAuthorization)
+        ));
+    }
+}
@@ -0,0 +1,84 @@
+//! authz msgs and authorizations implementing `TypeUrl`, which the sdk protos we currently depend on are missing.
+
+use cosmrs::proto::cosmos::authz::v1beta1::Grant;
+use cosmrs::proto::cosmos::base::v1beta1::Coin;
+use cosmrs::proto::cosmos::staking::v1beta1::{stake_authorization::Policy, AuthorizationType};
+use cosmrs::proto::traits::TypeUrl;
+use cosmrs::Any;
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgGrant {
+    #[prost(string, tag = "1")]
+    pub granter: String,
+    #[prost(string, tag = "2")]
+    pub grantee: String,
+    #[prost(message, optional, tag = "3")]
+    pub grant: Option<Grant>,
+}
+
+impl TypeUrl for MsgGrant {
+    const TYPE_URL: &'static str = "/cosmos.authz.v1beta1.MsgGrant";
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgRevoke {
+    #[prost(string, tag = "1")]
+    pub granter: String,
+    #[prost(string, tag = "2")]
+    pub grantee: String,
+    #[prost(string, tag = "3")]
+    pub msg_type_url: String,
+}
+
+impl TypeUrl for MsgRevoke {
+    const TYPE_URL: &'static str = "/cosmos.authz.v1beta1.MsgRevoke";
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgExec {
+    #[prost(string, tag = "1")]
+    pub grantee: String,
+    #[prost(message, repeated, tag = "2")]
+    pub msgs: Vec<Any>,
+}
+
+impl TypeUrl for MsgExec {
+    const TYPE_URL: &'static str = "/cosmos.authz.v1beta1.MsgExec";
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct GenericAuthorization {
+    #[prost(string, tag = "1")]
+    pub msg: String,
+}
+
+impl TypeUrl for GenericAuthorization {
+    const TYPE_URL: &'static str = "/cosmos.authz.v1beta1.GenericAuthorization";
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct SendAuthorization {
+    #[prost(message, repeated, tag = "1")]
+    pub spend_limit: Vec<Coin>,
+}
+
+impl TypeUrl for SendAuthorization {
+    const TYPE_URL: &'static str = "/cosmos.bank.v1beta1.SendAuthorization";
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct StakeAuthorization {
+    #[prost(message, optional, tag = "1")]
+    pub max_tokens: Option<Coin>,
+    #[prost(
+        oneof = "cosmrs::proto::cosmos::staking::v1beta1::stake_authorization::Policy",
+        tags = "2, 3"
+    )]
+    pub validators: Option<Policy>,
+    #[prost(enumeration = "AuthorizationType", tag = "4")]
+    pub authorization_type: i32,
+}
+
+impl TypeUrl for StakeAuthorization {
+    const TYPE_URL: &'static str = "/cosmos.staking.v1beta1.StakeAuthorization";
+}
@@ -44,18 +44,12 @@ impl<T: CosmosClient> CosmTome<T> {
     where
         I: IntoIterator<Item = SendRequest>,
     {
-        let sender_addr = key.to_addr(&self.cfg.prefix).await?;
-
         let msgs = reqs
             .into_iter()
             .map(Into::into)
             .collect::<Vec<SendRequestProto>>();
 
-        let tx_raw = self
-            .tx_sign(msgs, Some(sender_addr), key, tx_options)
-            .await?;
-
-        let res = self.tx_broadcast_block(&tx_raw).await?;
+        let res = self.tx_send(msgs, key, tx_options).await?;
 
         Ok(SendResponse { res })
     }
@@ -254,7 +248,9 @@ mod tests {
             response::{ChainResponse, ChainTxResponse, Code},
         },
         clients::client::MockCosmosClient,
-        modules::{bank::model::SendResponse, tx::error::TxError},
+        modules::{
+            auth::model::BASE_ACCOUNT_TYPE_URL, bank::model::SendResponse, tx::error::TxError,
+        },
     };
     use cosmrs::proto::{
         cosmos::auth::v1beta1::{BaseAccount, QueryAccountRequest, QueryAccountResponse},
@@ -278,6 +274,7 @@ mod tests {
             derivation_path: "m/44'/118'/0'/0/0".to_string(),
             rpc_endpoint: Some("localhost".to_string()),
             grpc_endpoint: None,
+            rest_endpoint: None,
             gas_price: 0.1,
             gas_adjustment: 1.5,
         };
@@ -290,10 +287,10 @@ mod tests {
         mock_client
             .expect_query::<QueryAccountRequest, QueryAccountResponse>()
             .times(2)
-            .returning(move |_, t: &str| {
+            .returning(move |_, _| {
                 Ok(QueryAccountResponse {
                     account: Some(cosmrs::proto::Any {
-                        type_url: t.to_owned(),
+                        type_url: BASE_ACCOUNT_TYPE_URL.to_string(),
                         value: BaseAccount {
                             address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
                             pub_key: None,
@@ -306,10 +303,7 @@ mod tests {
                 })
             });
 
-        let cosm_tome = CosmTome {
-            cfg: cfg.clone(),
-            client: mock_client,
-        };
+        let cosm_tome = CosmTome::new(cfg.clone(), mock_client);
 
         // empty amount vec errors:
         let req = SendRequest {
@@ -374,6 +368,7 @@ mod tests {
             derivation_path: "m/44'/118'/0'/0/0".to_string(),
             rpc_endpoint: None,
             grpc_endpoint: None,
+            rest_endpoint: None,
             gas_price: 0.1,
             gas_adjustment: 1.5,
         };
@@ -385,10 +380,10 @@ mod tests {
         mock_client
             .expect_query::<QueryAccountRequest, QueryAccountResponse>()
             .times(1)
-            .returning(move |_, t: &str| {
+            .returning(move |_, _| {
                 Ok(QueryAccountResponse {
                     account: Some(cosmrs::proto::Any {
-                        type_url: t.to_owned(),
+                        type_url: BASE_ACCOUNT_TYPE_URL.to_string(),
                         value: BaseAccount {
                             address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
                             pub_key: None,
@@ -415,6 +410,7 @@ mod tests {
                 Ok(ChainTxResponse {
                     res: ChainResponse {
                         code: Code::Ok,
+                        codespace: String::new(),
                         data: None,
                         log: "log log log".to_string(),
                     },
@@ -426,10 +422,7 @@ mod tests {
                 })
             });
 
-        let cosm_tome = CosmTome {
-            cfg: cfg.clone(),
-            client: mock_client,
-        };
+        let cosm_tome = CosmTome::new(cfg.clone(), mock_client);
 
         let req = SendRequest {
             from: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
@@ -452,6 +445,7 @@ mod tests {
                 res: ChainTxResponse {
                     res: ChainResponse {
                         code: Code::Ok,
+                        codespace: String::new(),
                         data: None,
                         log: "log log log".to_string()
                     },
@@ -474,6 +468,7 @@ mod tests {
             derivation_path: "m/44'/118'/0'/0/0".to_string(),
             rpc_endpoint: None,
             grpc_endpoint: None,
+            rest_endpoint: None,
             gas_price: 0.1,
             gas_adjustment: 1.5,
         };
@@ -490,16 +485,14 @@ mod tests {
                 Err(ChainError::CosmosSdk {
                     res: ChainResponse {
                         code: Code::Err(1),
+                        codespace: "sdk".to_string(),
                         data: None,
                         log: "error".to_string(),
                     },
                 })
             });
 
-        let cosm_tome = CosmTome {
-            cfg: cfg.clone(),
-            client: mock_client,
-        };
+        let cosm_tome = CosmTome::new(cfg.clone(), mock_client);
 
         let req = SendRequest {
             from: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
@@ -1,18 +1,27 @@
 use serde::Serialize;
 
-use crate::chain::request::TxOptions;
+use crate::chain::request::{PaginationRequest, TxOptions};
 use crate::clients::client::CosmTome;
 use cosmrs::proto::cosmwasm::wasm::v1::{
-    QuerySmartContractStateRequest, QuerySmartContractStateResponse,
+    QueryAllContractStateRequest, QueryAllContractStateResponse, QueryCodeRequest,
+    QueryCodesRequest, QueryContractHistoryRequest, QueryContractHistoryResponse,
+    QueryContractInfoRequest, QueryContractInfoResponse, QueryContractsByCodeRequest,
+    QueryContractsByCodeResponse, QueryPinnedCodesRequest, QueryPinnedCodesResponse,
+    QueryRawContractStateRequest, QueryRawContractStateResponse, QuerySmartContractStateRequest,
+    QuerySmartContractStateResponse,
 };
 
 use crate::modules::auth::model::Address;
 use crate::{clients::client::CosmosClient, signing_key::key::SigningKey};
 
 use super::model::{
+    AllContractStateResponse, CodeInfo, CodeResponse, CodesResponse, ContractHistoryEntry,
+    ContractHistoryResponse, ContractInfo, ContractInfoResponse, ContractsByCodeResponse,
     ExecRequest, ExecResponse, InstantiateBatchResponse, InstantiateRequest, MigrateRequest,
-    MigrateResponse, QueryResponse, StoreCodeBatchResponse, StoreCodeRequest,
+    MigrateResponse, ParamsResponse, PinnedCodesResponse, QueryResponse, RawContractStateResponse,
+    StoreCodeBatchResponse, StoreCodeRequest,
 };
+use super::v1::{QueryCodeResponse, QueryCodesResponse, QueryParamsRequest, QueryParamsResponse};
 use super::{
     error::CosmwasmError,
     model::{InstantiateResponse, StoreCodeResponse},
@@ -49,11 +58,7 @@ impl<T: CosmosClient> CosmTome<T> {
             .map(|r| r.to_proto(sender_addr.clone()))
             .collect::<Result<Vec<_>, _>>()?;
 
-        let tx_raw = self
-            .tx_sign(msgs, Some(sender_addr), key, tx_options)
-            .await?;
-
-        let res = self.tx_broadcast_block(&tx_raw).await?;
+        let res = self.tx_send(msgs, key, tx_options).await?;
 
         let code_ids = res
             .find_event_tags("store_code".to_string(), "code_id".to_string())
@@ -101,11 +106,7 @@ impl<T: CosmosClient> CosmTome<T> {
             .map(|r| r.to_proto(sender_addr.clone()))
             .collect::<Result<Vec<_>, _>>()?;
 
-        let tx_raw = self
-            .tx_sign(msgs, Some(sender_addr), key, tx_options)
-            .await?;
-
-        let res = self.tx_broadcast_block(&tx_raw).await?;
+        let res = self.tx_send(msgs, key, tx_options).await?;
 
         let events =
             res.find_event_tags("instantiate".to_string(), "_contract_address".to_string());
@@ -154,11 +155,7 @@ impl<T: CosmosClient> CosmTome<T> {
             .map(|r| r.to_proto(sender_addr.clone()))
             .collect::<Result<Vec<_>, _>>()?;
 
-        let tx_raw = self
-            .tx_sign(msgs, Some(sender_addr), key, tx_options)
-            .await?;
-
-        let res = self.tx_broadcast_block(&tx_raw).await?;
+        let res = self.tx_send(msgs, key, tx_options).await?;
 
         Ok(ExecResponse { res })
     }
@@ -215,14 +212,363 @@ impl<T: CosmosClient> CosmTome<T> {
             .map(|r| r.to_proto(sender_addr.clone()))
             .collect::<Result<Vec<_>, _>>()?;
 
-        let tx_raw = self
-            .tx_sign(msgs, Some(sender_addr), key, tx_options)
+        let res = self.tx_send(msgs, key, tx_options).await?;
+
+        Ok(MigrateResponse { res })
+    }
+
+    pub async fn wasm_query_contract_info(
+        &self,
+        address: Address,
+    ) -> Result<ContractInfoResponse, CosmwasmError> {
+        let req = QueryContractInfoRequest {
+            address: address.into(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryContractInfoResponse>(req, "/cosmwasm.wasm.v1.Query/ContractInfo")
             .await?;
 
-        let res = self.tx_broadcast_block(&tx_raw).await?;
+        Ok(ContractInfoResponse {
+            contract_info: res
+                .contract_info
+                .map(|info| ContractInfo::from_proto(&res.address, info))
+                .transpose()?,
+        })
+    }
 
-        Ok(MigrateResponse { res })
+    /// Query the instantiate and migrate operations of a contract
+    pub async fn wasm_query_contract_history(
+        &self,
+        address: Address,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<ContractHistoryResponse, CosmwasmError> {
+        let req = QueryContractHistoryRequest {
+            address: address.into(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryContractHistoryResponse>(
+                req,
+                "/cosmwasm.wasm.v1.Query/ContractHistory",
+            )
+            .await?;
+
+        Ok(ContractHistoryResponse {
+            entries: res
+                .entries
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<ContractHistoryEntry>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query the addresses of the contracts instantiated from `code_id`
+    pub async fn wasm_query_contracts_by_code(
+        &self,
+        code_id: u64,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<ContractsByCodeResponse, CosmwasmError> {
+        let req = QueryContractsByCodeRequest {
+            code_id,
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryContractsByCodeResponse>(
+                req,
+                "/cosmwasm.wasm.v1.Query/ContractsByCode",
+            )
+            .await?;
+
+        Ok(ContractsByCodeResponse {
+            contracts: res
+                .contracts
+                .into_iter()
+                .map(|a| a.parse())
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query every raw key value pair stored by a contract
+    pub async fn wasm_query_all_contract_state(
+        &self,
+        address: Address,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<AllContractStateResponse, CosmwasmError> {
+        let req = QueryAllContractStateRequest {
+            address: address.into(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryAllContractStateResponse>(
+                req,
+                "/cosmwasm.wasm.v1.Query/AllContractState",
+            )
+            .await?;
+
+        Ok(AllContractStateResponse {
+            models: res.models.into_iter().map(Into::into).collect(),
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query the raw value stored by a contract under `key`, without going through the contract's query entry point
+    pub async fn wasm_query_raw(
+        &self,
+        address: Address,
+        key: &[u8],
+    ) -> Result<RawContractStateResponse, CosmwasmError> {
+        let req = QueryRawContractStateRequest {
+            address: address.into(),
+            query_data: key.to_vec(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryRawContractStateResponse>(
+                req,
+                "/cosmwasm.wasm.v1.Query/RawContractState",
+            )
+            .await?;
+
+        Ok(RawContractStateResponse {
+            data: Some(res.data).filter(|d| !d.is_empty()),
+        })
     }
 
-    // TODO: Finish
+    /// Query the info and wasm byte code of `code_id`
+    pub async fn wasm_query_code(&self, code_id: u64) -> Result<CodeResponse, CosmwasmError> {
+        let req = QueryCodeRequest { code_id };
+
+        let res = self
+            .client
+            .query::<_, QueryCodeResponse>(req, "/cosmwasm.wasm.v1.Query/Code")
+            .await?;
+
+        Ok(CodeResponse {
+            code_info: res.code_info.map(TryInto::try_into).transpose()?,
+            data: res.data,
+        })
+    }
+
+    pub async fn wasm_query_codes(
+        &self,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<CodesResponse, CosmwasmError> {
+        let req = QueryCodesRequest {
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryCodesResponse>(req, "/cosmwasm.wasm.v1.Query/Codes")
+            .await?;
+
+        Ok(CodesResponse {
+            code_infos: res
+                .code_infos
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<CodeInfo>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    pub async fn wasm_query_pinned_codes(
+        &self,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<PinnedCodesResponse, CosmwasmError> {
+        let req = QueryPinnedCodesRequest {
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryPinnedCodesResponse>(req, "/cosmwasm.wasm.v1.Query/PinnedCodes")
+            .await?;
+
+        Ok(PinnedCodesResponse {
+            code_ids: res.code_ids,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Requires wasmd 0.30+ on the chain
+    pub async fn wasm_query_params(&self) -> Result<ParamsResponse, CosmwasmError> {
+        let req = QueryParamsRequest {};
+
+        let res = self
+            .client
+            .query::<_, QueryParamsResponse>(req, "/cosmwasm.wasm.v1.Query/Params")
+            .await?;
+
+        Ok(ParamsResponse {
+            params: res.params.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use cosmrs::proto::cosmwasm::wasm::v1::{
+        AbsoluteTxPosition, ContractInfo, QueryContractInfoRequest, QueryContractInfoResponse,
+    };
+
+    use cosmrs::proto::cosmwasm::wasm::v1::QueryCodeRequest;
+
+    use crate::{
+        clients::client::{CosmTome, MockCosmosClient},
+        modules::cosmwasm::{
+            model::{AccessPermission, AccessType},
+            v1::{
+                AccessConfig, CodeInfoResponse, Params, QueryCodeResponse, QueryParamsRequest,
+                QueryParamsResponse, ACCESS_TYPE_ANY_OF_ADDRESSES,
+            },
+        },
+        test_utils::test_cfg,
+    };
+
+    #[tokio::test]
+    async fn test_wasm_query_contract_info() {
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QueryContractInfoRequest, QueryContractInfoResponse>()
+            .times(1)
+            .returning(move |req, path| {
+                assert_eq!(path, "/cosmwasm.wasm.v1.Query/ContractInfo");
+
+                Ok(QueryContractInfoResponse {
+                    address: req.address,
+                    contract_info: Some(ContractInfo {
+                        code_id: 42,
+                        creator: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                        admin: String::new(),
+                        label: "tome".to_string(),
+                        created: Some(AbsoluteTxPosition {
+                            block_height: 1337,
+                            tx_index: 2,
+                        }),
+                        ibc_port_id: String::new(),
+                        extension: None,
+                    }),
+                })
+            });
+
+        let cosm_tome = CosmTome::new(test_cfg(), mock_client);
+
+        let address = "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+            .parse()
+            .unwrap();
+        let info = cosm_tome
+            .wasm_query_contract_info(address)
+            .await
+            .unwrap()
+            .contract_info
+            .unwrap();
+
+        assert_eq!(
+            info.address.to_string(),
+            "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+        );
+        assert_eq!(info.code_id, 42);
+        assert_eq!(info.admin, None);
+        assert_eq!(info.label, "tome");
+        assert_eq!(info.created.unwrap().block_height, 1337);
+        assert_eq!(info.ibc_port_id, None);
+    }
+
+    #[tokio::test]
+    async fn test_wasm_query_params_any_of_addresses() {
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QueryParamsRequest, QueryParamsResponse>()
+            .times(1)
+            .returning(move |_, path| {
+                assert_eq!(path, "/cosmwasm.wasm.v1.Query/Params");
+
+                Ok(QueryParamsResponse {
+                    params: Some(Params {
+                        code_upload_access: Some(AccessConfig {
+                            permission: ACCESS_TYPE_ANY_OF_ADDRESSES,
+                            address: String::new(),
+                            addresses: vec![
+                                "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                                "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea".to_string(),
+                            ],
+                        }),
+                        instantiate_default_permission: AccessType::Everybody as i32,
+                    }),
+                })
+            });
+
+        let cosm_tome = CosmTome::new(test_cfg(), mock_client);
+
+        let params = cosm_tome.wasm_query_params().await.unwrap().params.unwrap();
+
+        assert_eq!(
+            params.code_upload_access,
+            AccessPermission::AnyOfAddresses(vec![
+                "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                    .parse()
+                    .unwrap(),
+                "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                    .parse()
+                    .unwrap(),
+            ])
+        );
+        assert_eq!(params.instantiate_default_permission, AccessType::Everybody);
+    }
+
+    #[tokio::test]
+    async fn test_wasm_query_code_instantiate_permission() {
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QueryCodeRequest, QueryCodeResponse>()
+            .times(1)
+            .returning(move |req, _| {
+                Ok(QueryCodeResponse {
+                    code_info: Some(CodeInfoResponse {
+                        code_id: req.code_id,
+                        creator: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                        data_hash: vec![1, 2, 3],
+                        // wasmd < 0.30 only sets the deprecated single address
+                        instantiate_permission: Some(AccessConfig {
+                            permission: AccessType::OnlyAddress as i32,
+                            address: "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea".to_string(),
+                            addresses: vec![],
+                        }),
+                    }),
+                    data: vec![0, 97, 115, 109],
+                })
+            });
+
+        let cosm_tome = CosmTome::new(test_cfg(), mock_client);
+
+        let res = cosm_tome.wasm_query_code(7).await.unwrap();
+        let info = res.code_info.unwrap();
+
+        assert_eq!(info.code_id, 7);
+        assert_eq!(
+            info.instantiate_permission,
+            Some(AccessPermission::OnlyAddress(
+                "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                    .parse()
+                    .unwrap()
+            ))
+        );
+        assert_eq!(res.data, vec![0, 97, 115, 109]);
+    }
 }
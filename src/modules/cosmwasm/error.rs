@@ -15,6 +15,9 @@ pub enum CosmwasmError {
     #[error("unsupported instantiate permission AccessType: {i:?}")]
     AccessType { i: i32 },
 
+    #[error("unsupported contract code history operation type: {i:?}")]
+    OperationType { i: i32 },
+
     #[error("missing event from chain response")]
     MissingEvent,
 
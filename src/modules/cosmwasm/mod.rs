@@ -3,3 +3,5 @@ pub mod api;
 pub mod model;
 
 pub mod error;
+
+pub mod v1;
@@ -1,7 +1,9 @@
 use cosmrs::proto::cosmwasm::wasm::v1::MsgStoreCode;
 use cosmrs::proto::cosmwasm::wasm::v1::{
-    AccessConfig as ProtoAccessConfig, AccessType as ProtoAccessType, MsgExecuteContract,
-    MsgInstantiateContract, MsgMigrateContract, QuerySmartContractStateResponse,
+    AbsoluteTxPosition as ProtoAbsoluteTxPosition, AccessConfig as ProtoAccessConfig,
+    AccessType as ProtoAccessType, ContractCodeHistoryEntry, ContractInfo as ProtoContractInfo,
+    Model, MsgExecuteContract, MsgInstantiateContract, MsgMigrateContract,
+    QuerySmartContractStateResponse,
 };
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
@@ -11,12 +13,17 @@ use crate::chain::msg::Msg;
 use crate::{
     chain::{
         coin::Coin,
+        request::PaginationResponse,
         response::{ChainResponse, ChainTxResponse, Code},
     },
     modules::auth::model::Address,
 };
 
 use super::error::CosmwasmError;
+use super::v1::{
+    AccessConfig as WasmAccessConfig, CodeInfoResponse, Params as ProtoParams,
+    ACCESS_TYPE_ANY_OF_ADDRESSES,
+};
 
 #[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
 pub struct StoreCodeRequest {
@@ -512,3 +519,302 @@ impl From<ProtoAccessType> for AccessType {
         }
     }
 }
+
+/// Access permission stored on chain for uploading or instantiating code.
+/// Unlike `AccessType`, which is all `MsgStoreCode` accepts, it also covers the permissions added by wasmd 0.30+,
+/// and more variants may be added as wasmd adds them.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum AccessPermission {
+    Unspecified,
+    Nobody,
+    OnlyAddress(Address),
+    Everybody,
+    /// Restricted to a set of addresses, wasmd 0.30+
+    AnyOfAddresses(Vec<Address>),
+}
+
+impl TryFrom<WasmAccessConfig> for AccessPermission {
+    type Error = CosmwasmError;
+
+    fn try_from(config: WasmAccessConfig) -> Result<Self, Self::Error> {
+        if config.permission == ACCESS_TYPE_ANY_OF_ADDRESSES {
+            return Ok(Self::AnyOfAddresses(
+                config
+                    .addresses
+                    .into_iter()
+                    .map(|a| a.parse())
+                    .collect::<Result<Vec<_>, _>>()?,
+            ));
+        }
+
+        Ok(match config.permission.try_into()? {
+            AccessType::Unspecified => Self::Unspecified,
+            AccessType::Nobody => Self::Nobody,
+            AccessType::OnlyAddress => Self::OnlyAddress(config.address.parse()?),
+            AccessType::Everybody => Self::Everybody,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ContractInfoResponse {
+    pub contract_info: Option<ContractInfo>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ContractInfo {
+    pub address: Address,
+
+    pub code_id: u64,
+
+    pub creator: Address,
+
+    /// Account allowed to migrate the contract, `None` if the contract is immutable
+    pub admin: Option<Address>,
+
+    pub label: String,
+
+    /// Position of the instantiate tx
+    pub created: Option<AbsoluteTxPosition>,
+
+    /// Only set for IBC enabled contracts
+    pub ibc_port_id: Option<String>,
+}
+
+impl ContractInfo {
+    pub(crate) fn from_proto(
+        address: &str,
+        info: ProtoContractInfo,
+    ) -> Result<Self, CosmwasmError> {
+        Ok(Self {
+            address: address.parse()?,
+            code_id: info.code_id,
+            creator: info.creator.parse()?,
+            admin: Some(info.admin)
+                .filter(|a| !a.is_empty())
+                .map(|a| a.parse())
+                .transpose()?,
+            label: info.label,
+            created: info.created.map(Into::into),
+            ibc_port_id: Some(info.ibc_port_id).filter(|p| !p.is_empty()),
+        })
+    }
+}
+
+#[derive(
+    Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash, PartialOrd, Ord,
+)]
+pub struct AbsoluteTxPosition {
+    pub block_height: u64,
+
+    /// Index of the tx in the block
+    pub tx_index: u64,
+}
+
+impl From<ProtoAbsoluteTxPosition> for AbsoluteTxPosition {
+    fn from(pos: ProtoAbsoluteTxPosition) -> Self {
+        Self {
+            block_height: pos.block_height,
+            tx_index: pos.tx_index,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ContractHistoryResponse {
+    pub entries: Vec<ContractHistoryEntry>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct ContractHistoryEntry {
+    pub operation: ContractHistoryOperation,
+
+    /// Code of the contract from this entry on
+    pub code_id: u64,
+
+    pub updated: Option<AbsoluteTxPosition>,
+
+    /// JSON encoded instantiate or migrate msg
+    pub msg: Vec<u8>,
+}
+
+impl ContractHistoryEntry {
+    pub fn msg<'a, T: Deserialize<'a>>(&'a self) -> Result<T, DeserializeError> {
+        Ok(serde_json::from_slice(&self.msg)?)
+    }
+}
+
+impl TryFrom<ContractCodeHistoryEntry> for ContractHistoryEntry {
+    type Error = CosmwasmError;
+
+    fn try_from(entry: ContractCodeHistoryEntry) -> Result<Self, Self::Error> {
+        Ok(Self {
+            operation: entry.operation.try_into()?,
+            code_id: entry.code_id,
+            updated: entry.updated.map(Into::into),
+            msg: entry.msg,
+        })
+    }
+}
+
+#[derive(
+    Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash, PartialOrd, Ord,
+)]
+#[repr(i32)]
+pub enum ContractHistoryOperation {
+    Unspecified = 0,
+    /// Contract instantiation
+    Init = 1,
+    /// Contract migration to a new code
+    Migrate = 2,
+    /// Contract imported from genesis
+    Genesis = 3,
+}
+
+impl TryFrom<i32> for ContractHistoryOperation {
+    type Error = CosmwasmError;
+
+    fn try_from(v: i32) -> Result<Self, Self::Error> {
+        match v {
+            x if x == ContractHistoryOperation::Unspecified as i32 => {
+                Ok(ContractHistoryOperation::Unspecified)
+            }
+            x if x == ContractHistoryOperation::Init as i32 => Ok(ContractHistoryOperation::Init),
+            x if x == ContractHistoryOperation::Migrate as i32 => {
+                Ok(ContractHistoryOperation::Migrate)
+            }
+            x if x == ContractHistoryOperation::Genesis as i32 => {
+                Ok(ContractHistoryOperation::Genesis)
+            }
+            _ => Err(CosmwasmError::OperationType { i: v }),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ContractsByCodeResponse {
+    pub contracts: Vec<Address>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct AllContractStateResponse {
+    pub models: Vec<StateModel>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+/// Raw key value pair of a contract storage
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct StateModel {
+    pub key: Vec<u8>,
+
+    pub value: Vec<u8>,
+}
+
+impl From<Model> for StateModel {
+    fn from(model: Model) -> Self {
+        Self {
+            key: model.key,
+            value: model.value,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct RawContractStateResponse {
+    /// Raw value stored under the key, `None` if the key is not set
+    pub data: Option<Vec<u8>>,
+}
+
+impl RawContractStateResponse {
+    /// Deserializes the value of a key stored as JSON, ie. by `cw-storage-plus`
+    pub fn data<'a, T: Deserialize<'a>>(&'a self) -> Result<Option<T>, DeserializeError> {
+        self.data
+            .as_deref()
+            .map(serde_json::from_slice)
+            .transpose()
+            .map_err(Into::into)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct CodeResponse {
+    pub code_info: Option<CodeInfo>,
+
+    /// Wasm byte code
+    pub data: Vec<u8>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct CodesResponse {
+    pub code_infos: Vec<CodeInfo>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct CodeInfo {
+    pub code_id: u64,
+
+    pub creator: Address,
+
+    /// Sha256 hash of the wasm byte code
+    pub data_hash: Vec<u8>,
+
+    /// Who is allowed to instantiate this code, only returned by wasmd 0.30+
+    pub instantiate_permission: Option<AccessPermission>,
+}
+
+impl TryFrom<CodeInfoResponse> for CodeInfo {
+    type Error = CosmwasmError;
+
+    fn try_from(info: CodeInfoResponse) -> Result<Self, Self::Error> {
+        Ok(Self {
+            code_id: info.code_id,
+            creator: info.creator.parse()?,
+            data_hash: info.data_hash,
+            instantiate_permission: info
+                .instantiate_permission
+                .map(TryInto::try_into)
+                .transpose()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct PinnedCodesResponse {
+    /// Codes kept in the wasm VM memory cache
+    pub code_ids: Vec<u64>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ParamsResponse {
+    pub params: Option<Params>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct Params {
+    /// Who is allowed to store code
+    pub code_upload_access: AccessPermission,
+
+    /// Permission given to new codes stored without an explicit `instantiate_perms`
+    pub instantiate_default_permission: AccessType,
+}
+
+impl TryFrom<ProtoParams> for Params {
+    type Error = CosmwasmError;
+
+    fn try_from(params: ProtoParams) -> Result<Self, Self::Error> {
+        Ok(Self {
+            code_upload_access: params.code_upload_access.unwrap_or_default().try_into()?,
+            instantiate_default_permission: params.instantiate_default_permission.try_into()?,
+        })
+    }
+}
@@ -0,0 +1,62 @@
+//! wasmd 0.30+ types, which are not part of the wasmd protos we currently depend on.
+
+use cosmrs::proto::cosmos::base::query::v1beta1::PageResponse;
+
+/// `ACCESS_TYPE_ANY_OF_ADDRESSES`, missing from the `AccessType` of the wasmd protos we currently depend on
+pub const ACCESS_TYPE_ANY_OF_ADDRESSES: i32 = 4;
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct AccessConfig {
+    #[prost(int32, tag = "1")]
+    pub permission: i32,
+    /// Deprecated in favor of `addresses`, only set by chains running wasmd < 0.30
+    #[prost(string, tag = "2")]
+    pub address: String,
+    #[prost(string, repeated, tag = "3")]
+    pub addresses: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Params {
+    #[prost(message, optional, tag = "1")]
+    pub code_upload_access: Option<AccessConfig>,
+    #[prost(int32, tag = "2")]
+    pub instantiate_default_permission: i32,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryParamsRequest {}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryParamsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub params: Option<Params>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct CodeInfoResponse {
+    #[prost(uint64, tag = "1")]
+    pub code_id: u64,
+    #[prost(string, tag = "2")]
+    pub creator: String,
+    #[prost(bytes = "vec", tag = "3")]
+    pub data_hash: Vec<u8>,
+    #[prost(message, optional, tag = "6")]
+    pub instantiate_permission: Option<AccessConfig>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryCodeResponse {
+    #[prost(message, optional, tag = "1")]
+    pub code_info: Option<CodeInfoResponse>,
+    #[prost(bytes = "vec", tag = "2")]
+    pub data: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryCodesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub code_infos: Vec<CodeInfoResponse>,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: Option<PageResponse>,
+}
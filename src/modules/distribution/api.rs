@@ -0,0 +1,471 @@
+use cosmrs::proto::cosmos::distribution::v1beta1::{
+    QueryCommunityPoolRequest, QueryCommunityPoolResponse, QueryDelegationRewardsRequest,
+    QueryDelegationRewardsResponse, QueryDelegationTotalRewardsRequest,
+    QueryDelegationTotalRewardsResponse, QueryDelegatorWithdrawAddressRequest,
+    QueryDelegatorWithdrawAddressResponse, QueryParamsRequest, QueryParamsResponse,
+    QueryValidatorCommissionRequest, QueryValidatorCommissionResponse,
+    QueryValidatorOutstandingRewardsRequest, QueryValidatorOutstandingRewardsResponse,
+    QueryValidatorSlashesRequest, QueryValidatorSlashesResponse,
+};
+
+use crate::{
+    chain::request::{PaginationRequest, TxOptions},
+    clients::client::{CosmTome, CosmosClient},
+    modules::auth::model::Address,
+    signing_key::key::SigningKey,
+};
+
+use super::{
+    error::DistributionError,
+    model::{
+        dec_coins, CommissionResponse, CommunityPoolResponse, DistributionTxResponse,
+        FundCommunityPoolRequest, ParamsResponse, RewardsResponse, SetWithdrawAddressRequest,
+        SlashesResponse, TotalRewardsResponse, WithdrawAddressResponse, WithdrawCommissionRequest,
+        WithdrawRewardsRequest,
+    },
+};
+
+impl<T: CosmosClient> CosmTome<T> {
+    /// Withdraw the delegator's staking rewards from a single validator
+    pub async fn distribution_withdraw_rewards(
+        &self,
+        req: WithdrawRewardsRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<DistributionTxResponse, DistributionError> {
+        self.distribution_withdraw_rewards_batch(vec![req], key, tx_options)
+            .await
+    }
+
+    /// Withdraw staking rewards from multiple validators in a single tx
+    pub async fn distribution_withdraw_rewards_batch<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<DistributionTxResponse, DistributionError>
+    where
+        I: IntoIterator<Item = WithdrawRewardsRequest>,
+    {
+        let msgs = reqs.into_iter().collect::<Vec<_>>();
+
+        let res = self.tx_send(msgs, key, tx_options).await?;
+
+        Ok(DistributionTxResponse { res })
+    }
+
+    /// Withdraw a validator's accumulated commission
+    pub async fn distribution_withdraw_commission(
+        &self,
+        req: WithdrawCommissionRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<DistributionTxResponse, DistributionError> {
+        let res = self.tx_send(vec![req], key, tx_options).await?;
+
+        Ok(DistributionTxResponse { res })
+    }
+
+    /// Change the address that the delegator's rewards are withdrawn to
+    pub async fn distribution_set_withdraw_address(
+        &self,
+        req: SetWithdrawAddressRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<DistributionTxResponse, DistributionError> {
+        let res = self.tx_send(vec![req], key, tx_options).await?;
+
+        Ok(DistributionTxResponse { res })
+    }
+
+    /// Send funds from the depositor to the community pool
+    pub async fn distribution_fund_community_pool(
+        &self,
+        req: FundCommunityPoolRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<DistributionTxResponse, DistributionError> {
+        let res = self.tx_send(vec![req], key, tx_options).await?;
+
+        Ok(DistributionTxResponse { res })
+    }
+
+    /// Query the pending rewards of `delegator` from a single `validator`
+    pub async fn distribution_query_rewards(
+        &self,
+        delegator: Address,
+        validator: Address,
+    ) -> Result<RewardsResponse, DistributionError> {
+        let req = QueryDelegationRewardsRequest {
+            delegator_address: delegator.into(),
+            validator_address: validator.into(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryDelegationRewardsResponse>(
+                req,
+                "/cosmos.distribution.v1beta1.Query/DelegationRewards",
+            )
+            .await?;
+
+        Ok(RewardsResponse {
+            rewards: dec_coins(res.rewards)?,
+        })
+    }
+
+    /// Query the pending rewards of `delegator` from all of its validators
+    pub async fn distribution_query_total_rewards(
+        &self,
+        delegator: Address,
+    ) -> Result<TotalRewardsResponse, DistributionError> {
+        let req = QueryDelegationTotalRewardsRequest {
+            delegator_address: delegator.into(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryDelegationTotalRewardsResponse>(
+                req,
+                "/cosmos.distribution.v1beta1.Query/DelegationTotalRewards",
+            )
+            .await?;
+
+        Ok(TotalRewardsResponse {
+            rewards: res
+                .rewards
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            total: dec_coins(res.total)?,
+        })
+    }
+
+    /// Query the rewards of `validator` that have not been withdrawn yet, for itself and all its delegators
+    pub async fn distribution_query_outstanding_rewards(
+        &self,
+        validator: Address,
+    ) -> Result<RewardsResponse, DistributionError> {
+        let req = QueryValidatorOutstandingRewardsRequest {
+            validator_address: validator.into(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryValidatorOutstandingRewardsResponse>(
+                req,
+                "/cosmos.distribution.v1beta1.Query/ValidatorOutstandingRewards",
+            )
+            .await?;
+
+        Ok(RewardsResponse {
+            rewards: dec_coins(res.rewards.map(|r| r.rewards).unwrap_or_default())?,
+        })
+    }
+
+    /// Query the accumulated commission of `validator`
+    pub async fn distribution_query_commission(
+        &self,
+        validator: Address,
+    ) -> Result<CommissionResponse, DistributionError> {
+        let req = QueryValidatorCommissionRequest {
+            validator_address: validator.into(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryValidatorCommissionResponse>(
+                req,
+                "/cosmos.distribution.v1beta1.Query/ValidatorCommission",
+            )
+            .await?;
+
+        Ok(CommissionResponse {
+            commission: dec_coins(res.commission.map(|c| c.commission).unwrap_or_default())?,
+        })
+    }
+
+    /// Query the slashing events of `validator` between `starting_height` and `ending_height`
+    pub async fn distribution_query_slashes(
+        &self,
+        validator: Address,
+        starting_height: u64,
+        ending_height: u64,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<SlashesResponse, DistributionError> {
+        let req = QueryValidatorSlashesRequest {
+            validator_address: validator.into(),
+            starting_height,
+            ending_height,
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryValidatorSlashesResponse>(
+                req,
+                "/cosmos.distribution.v1beta1.Query/ValidatorSlashes",
+            )
+            .await?;
+
+        Ok(SlashesResponse {
+            slashes: res
+                .slashes
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query the funds held by the community pool
+    pub async fn distribution_query_community_pool(
+        &self,
+    ) -> Result<CommunityPoolResponse, DistributionError> {
+        let req = QueryCommunityPoolRequest {};
+
+        let res = self
+            .client
+            .query::<_, QueryCommunityPoolResponse>(
+                req,
+                "/cosmos.distribution.v1beta1.Query/CommunityPool",
+            )
+            .await?;
+
+        Ok(CommunityPoolResponse {
+            pool: dec_coins(res.pool)?,
+        })
+    }
+
+    /// Query the address that `delegator`'s rewards are withdrawn to
+    pub async fn distribution_query_withdraw_address(
+        &self,
+        delegator: Address,
+    ) -> Result<WithdrawAddressResponse, DistributionError> {
+        let req = QueryDelegatorWithdrawAddressRequest {
+            delegator_address: delegator.into(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryDelegatorWithdrawAddressResponse>(
+                req,
+                "/cosmos.distribution.v1beta1.Query/DelegatorWithdrawAddress",
+            )
+            .await?;
+
+        Ok(WithdrawAddressResponse {
+            withdraw_address: res.withdraw_address.parse()?,
+        })
+    }
+
+    /// Query distribution module cosmos sdk params
+    pub async fn distribution_query_params(&self) -> Result<ParamsResponse, DistributionError> {
+        let req = QueryParamsRequest {};
+
+        let res = self
+            .client
+            .query::<_, QueryParamsResponse>(req, "/cosmos.distribution.v1beta1.Query/Params")
+            .await?;
+
+        Ok(ParamsResponse {
+            params: res.params.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use cosmrs::proto::cosmos::{
+        base::{query::v1beta1::PageResponse, v1beta1::DecCoin as ProtoDecCoin},
+        distribution::v1beta1::{
+            DelegationDelegatorReward, QueryDelegationTotalRewardsRequest,
+            QueryDelegationTotalRewardsResponse, QueryValidatorSlashesRequest,
+            QueryValidatorSlashesResponse, ValidatorSlashEvent as ProtoValidatorSlashEvent,
+        },
+    };
+
+    use crate::{
+        chain::{
+            coin::DecCoin,
+            error::ChainError,
+            request::{PageID, PaginationRequest, PaginationResponse},
+        },
+        clients::client::{CosmTome, MockCosmosClient},
+        modules::distribution::{
+            error::DistributionError,
+            model::{ValidatorRewards, ValidatorSlashEvent},
+        },
+        test_utils::test_cfg,
+    };
+
+    #[tokio::test]
+    async fn test_distribution_query_total_rewards() {
+        let cfg = test_cfg();
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QueryDelegationTotalRewardsRequest, QueryDelegationTotalRewardsResponse>()
+            .times(1)
+            .returning(move |_, path| {
+                assert_eq!(
+                    path,
+                    "/cosmos.distribution.v1beta1.Query/DelegationTotalRewards"
+                );
+
+                let reward = ProtoDecCoin {
+                    denom: "ujuno".to_string(),
+                    amount: "1250000000000000000".to_string(),
+                };
+
+                Ok(QueryDelegationTotalRewardsResponse {
+                    rewards: vec![DelegationDelegatorReward {
+                        validator_address: "junovaloper10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k9r4ft3"
+                            .to_string(),
+                        reward: vec![reward.clone()],
+                    }],
+                    total: vec![reward],
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let res = cosm_tome
+            .distribution_query_total_rewards(
+                "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                    .parse()
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        let reward = DecCoin {
+            denom: "ujuno".parse().unwrap(),
+            amount: "1.25".parse().unwrap(),
+        };
+
+        assert_eq!(reward.amount.truncate().unwrap(), 1);
+        assert_eq!(res.total, vec![reward.clone()]);
+        assert_eq!(
+            res.rewards,
+            vec![ValidatorRewards {
+                validator: "junovaloper10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k9r4ft3"
+                    .parse()
+                    .unwrap(),
+                rewards: vec![reward],
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_distribution_query_total_rewards_errors() {
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QueryDelegationTotalRewardsRequest, QueryDelegationTotalRewardsResponse>()
+            .times(1)
+            .returning(|_, _| {
+                Err(ChainError::tonic_status(tonic::Status::invalid_argument(
+                    "invalid delegator address",
+                )))
+            });
+
+        // a malformed reward amount fails the whole response
+        mock_client
+            .expect_query::<QueryDelegationTotalRewardsRequest, QueryDelegationTotalRewardsResponse>()
+            .times(1)
+            .returning(|_, _| {
+                Ok(QueryDelegationTotalRewardsResponse {
+                    rewards: vec![],
+                    total: vec![ProtoDecCoin {
+                        denom: "ujuno".to_string(),
+                        amount: "1.25".to_string(),
+                    }],
+                })
+            });
+
+        let cosm_tome = CosmTome::new(test_cfg(), mock_client);
+        let delegator = "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg";
+
+        let err = cosm_tome
+            .distribution_query_total_rewards(delegator.parse().unwrap())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            DistributionError::ChainError(ChainError::CosmosSdk { .. })
+        ));
+
+        let err = cosm_tome
+            .distribution_query_total_rewards(delegator.parse().unwrap())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, DistributionError::ChainError(_)));
+    }
+
+    #[tokio::test]
+    async fn test_distribution_query_slashes_pagination() {
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QueryValidatorSlashesRequest, QueryValidatorSlashesResponse>()
+            .times(1)
+            .withf(|req, _| {
+                let page = req.pagination.clone().unwrap();
+
+                req.starting_height == 100
+                    && req.ending_height == 200
+                    && page.key == b"next".to_vec()
+                    && page.limit == 10
+            })
+            .returning(|_, _| {
+                Ok(QueryValidatorSlashesResponse {
+                    slashes: vec![ProtoValidatorSlashEvent {
+                        validator_period: 7,
+                        fraction: "10000000000000000".to_string(),
+                    }],
+                    pagination: Some(PageResponse {
+                        next_key: b"last".to_vec(),
+                        total: 0,
+                    }),
+                })
+            });
+
+        let cosm_tome = CosmTome::new(test_cfg(), mock_client);
+
+        let res = cosm_tome
+            .distribution_query_slashes(
+                "junovaloper10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k9r4ft3"
+                    .parse()
+                    .unwrap(),
+                100,
+                200,
+                Some(PaginationRequest {
+                    page: PageID::Key(b"next".to_vec()),
+                    limit: 10,
+                    reverse: false,
+                }),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            res.slashes,
+            vec![ValidatorSlashEvent {
+                validator_period: 7,
+                fraction: "0.01".parse().unwrap(),
+            }]
+        );
+        assert_eq!(
+            res.next,
+            Some(PaginationResponse {
+                next_key: b"last".to_vec(),
+                total: 0,
+            })
+        );
+    }
+}
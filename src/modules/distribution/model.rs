@@ -0,0 +1,276 @@
+use cosmrs::proto::cosmos::base::v1beta1::DecCoin as ProtoDecCoin;
+use cosmrs::proto::cosmos::distribution::v1beta1::{
+    DelegationDelegatorReward, MsgFundCommunityPool, MsgSetWithdrawAddress,
+    MsgWithdrawDelegatorReward, MsgWithdrawValidatorCommission, Params as ProtoParams,
+    ValidatorSlashEvent as ProtoValidatorSlashEvent,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::chain::coin::{Coin, Dec, DecCoin};
+use crate::chain::error::ChainError;
+use crate::chain::msg::Msg;
+use crate::chain::request::PaginationResponse;
+use crate::chain::response::ChainTxResponse;
+use crate::modules::auth::model::Address;
+
+use super::error::DistributionError;
+
+pub(crate) fn dec_coins(coins: Vec<ProtoDecCoin>) -> Result<Vec<DecCoin>, ChainError> {
+    coins.into_iter().map(TryInto::try_into).collect()
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct DistributionTxResponse {
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct RewardsResponse {
+    pub rewards: Vec<DecCoin>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct TotalRewardsResponse {
+    /// Rewards of the delegator, per validator
+    pub rewards: Vec<ValidatorRewards>,
+
+    /// Sum of all rewards
+    pub total: Vec<DecCoin>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct ValidatorRewards {
+    pub validator: Address,
+    pub rewards: Vec<DecCoin>,
+}
+
+impl TryFrom<DelegationDelegatorReward> for ValidatorRewards {
+    type Error = DistributionError;
+
+    fn try_from(r: DelegationDelegatorReward) -> Result<Self, Self::Error> {
+        Ok(Self {
+            validator: r.validator_address.parse()?,
+            rewards: dec_coins(r.reward)?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct CommissionResponse {
+    pub commission: Vec<DecCoin>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct SlashesResponse {
+    pub slashes: Vec<ValidatorSlashEvent>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct ValidatorSlashEvent {
+    pub validator_period: u64,
+
+    /// Fraction of the validator's stake that was slashed
+    pub fraction: Dec,
+}
+
+impl TryFrom<ProtoValidatorSlashEvent> for ValidatorSlashEvent {
+    type Error = ChainError;
+
+    fn try_from(e: ProtoValidatorSlashEvent) -> Result<Self, Self::Error> {
+        Ok(Self {
+            validator_period: e.validator_period,
+            fraction: Dec::from_atomics(&e.fraction)?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct CommunityPoolResponse {
+    pub pool: Vec<DecCoin>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct WithdrawAddressResponse {
+    pub withdraw_address: Address,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct ParamsResponse {
+    pub params: Option<Params>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct Params {
+    pub community_tax: Dec,
+    pub base_proposer_reward: Dec,
+    pub bonus_proposer_reward: Dec,
+    pub withdraw_addr_enabled: bool,
+}
+
+impl TryFrom<ProtoParams> for Params {
+    type Error = ChainError;
+
+    fn try_from(p: ProtoParams) -> Result<Self, Self::Error> {
+        Ok(Self {
+            community_tax: Dec::from_atomics(&p.community_tax)?,
+            base_proposer_reward: Dec::from_atomics(&p.base_proposer_reward)?,
+            bonus_proposer_reward: Dec::from_atomics(&p.bonus_proposer_reward)?,
+            withdraw_addr_enabled: p.withdraw_addr_enabled,
+        })
+    }
+}
+
+impl From<Params> for ProtoParams {
+    fn from(p: Params) -> Self {
+        Self {
+            community_tax: p.community_tax.atomics(),
+            base_proposer_reward: p.base_proposer_reward.atomics(),
+            bonus_proposer_reward: p.bonus_proposer_reward.atomics(),
+            withdraw_addr_enabled: p.withdraw_addr_enabled,
+        }
+    }
+}
+
+/// Withdraw all of `delegator`'s staking rewards from `validator`
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct WithdrawRewardsRequest {
+    pub delegator: Address,
+    pub validator: Address,
+}
+
+impl Msg for WithdrawRewardsRequest {
+    type Proto = MsgWithdrawDelegatorReward;
+    type Err = DistributionError;
+}
+
+impl TryFrom<MsgWithdrawDelegatorReward> for WithdrawRewardsRequest {
+    type Error = DistributionError;
+
+    fn try_from(msg: MsgWithdrawDelegatorReward) -> Result<Self, Self::Error> {
+        Ok(Self {
+            delegator: msg.delegator_address.parse()?,
+            validator: msg.validator_address.parse()?,
+        })
+    }
+}
+
+impl TryFrom<WithdrawRewardsRequest> for MsgWithdrawDelegatorReward {
+    type Error = DistributionError;
+
+    fn try_from(req: WithdrawRewardsRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            delegator_address: req.delegator.into(),
+            validator_address: req.validator.into(),
+        })
+    }
+}
+
+/// Withdraw the accumulated commission of `validator`.
+/// Must be signed by the validator's operator account.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct WithdrawCommissionRequest {
+    pub validator: Address,
+}
+
+impl Msg for WithdrawCommissionRequest {
+    type Proto = MsgWithdrawValidatorCommission;
+    type Err = DistributionError;
+}
+
+impl TryFrom<MsgWithdrawValidatorCommission> for WithdrawCommissionRequest {
+    type Error = DistributionError;
+
+    fn try_from(msg: MsgWithdrawValidatorCommission) -> Result<Self, Self::Error> {
+        Ok(Self {
+            validator: msg.validator_address.parse()?,
+        })
+    }
+}
+
+impl TryFrom<WithdrawCommissionRequest> for MsgWithdrawValidatorCommission {
+    type Error = DistributionError;
+
+    fn try_from(req: WithdrawCommissionRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            validator_address: req.validator.into(),
+        })
+    }
+}
+
+/// Set the address that `delegator`'s rewards are withdrawn to
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct SetWithdrawAddressRequest {
+    pub delegator: Address,
+    pub withdraw_address: Address,
+}
+
+impl Msg for SetWithdrawAddressRequest {
+    type Proto = MsgSetWithdrawAddress;
+    type Err = DistributionError;
+}
+
+impl TryFrom<MsgSetWithdrawAddress> for SetWithdrawAddressRequest {
+    type Error = DistributionError;
+
+    fn try_from(msg: MsgSetWithdrawAddress) -> Result<Self, Self::Error> {
+        Ok(Self {
+            delegator: msg.delegator_address.parse()?,
+            withdraw_address: msg.withdraw_address.parse()?,
+        })
+    }
+}
+
+impl TryFrom<SetWithdrawAddressRequest> for MsgSetWithdrawAddress {
+    type Error = DistributionError;
+
+    fn try_from(req: SetWithdrawAddressRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            delegator_address: req.delegator.into(),
+            withdraw_address: req.withdraw_address.into(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct FundCommunityPoolRequest {
+    pub depositor: Address,
+    pub amounts: Vec<Coin>,
+}
+
+impl Msg for FundCommunityPoolRequest {
+    type Proto = MsgFundCommunityPool;
+    type Err = DistributionError;
+}
+
+impl TryFrom<MsgFundCommunityPool> for FundCommunityPoolRequest {
+    type Error = DistributionError;
+
+    fn try_from(msg: MsgFundCommunityPool) -> Result<Self, Self::Error> {
+        Ok(Self {
+            depositor: msg.depositor.parse()?,
+            amounts: msg
+                .amount
+                .into_iter()
+                .map(TryFrom::try_from)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+impl TryFrom<FundCommunityPoolRequest> for MsgFundCommunityPool {
+    type Error = DistributionError;
+
+    fn try_from(req: FundCommunityPoolRequest) -> Result<Self, Self::Error> {
+        if req.amounts.is_empty() || req.amounts.iter().any(|a| a.amount == 0) {
+            return Err(DistributionError::EmptyAmount);
+        }
+
+        Ok(Self {
+            depositor: req.depositor.into(),
+            amount: req.amounts.into_iter().map(Into::into).collect(),
+        })
+    }
+}
@@ -0,0 +1,202 @@
+use cosmrs::proto::cosmos::feegrant::v1beta1::{
+    QueryAllowanceRequest, QueryAllowanceResponse, QueryAllowancesRequest, QueryAllowancesResponse,
+};
+
+use crate::{
+    chain::request::{PaginationRequest, TxOptions},
+    clients::client::{CosmTome, CosmosClient},
+    modules::auth::model::Address,
+    signing_key::key::SigningKey,
+};
+
+use super::{
+    error::FeegrantError,
+    model::{
+        AllowanceResponse, AllowancesResponse, FeegrantTxResponse, GrantAllowanceRequest,
+        QueryAllowancesByGranterRequest, QueryAllowancesByGranterResponse, RevokeAllowanceRequest,
+    },
+};
+
+impl<T: CosmosClient> CosmTome<T> {
+    pub async fn feegrant_grant_allowance(
+        &self,
+        req: GrantAllowanceRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<FeegrantTxResponse, FeegrantError> {
+        let res = self.tx_send(vec![req], key, tx_options).await?;
+
+        Ok(FeegrantTxResponse { res })
+    }
+
+    pub async fn feegrant_revoke_allowance(
+        &self,
+        req: RevokeAllowanceRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<FeegrantTxResponse, FeegrantError> {
+        let res = self.tx_send(vec![req], key, tx_options).await?;
+
+        Ok(FeegrantTxResponse { res })
+    }
+
+    /// Query the allowance given by `granter` to `grantee`
+    pub async fn feegrant_query_allowance(
+        &self,
+        granter: Address,
+        grantee: Address,
+    ) -> Result<AllowanceResponse, FeegrantError> {
+        let req = QueryAllowanceRequest {
+            granter: granter.into(),
+            grantee: grantee.into(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryAllowanceResponse>(req, "/cosmos.feegrant.v1beta1.Query/Allowance")
+            .await?;
+
+        Ok(AllowanceResponse {
+            allowance: res.allowance.map(TryInto::try_into).transpose()?,
+        })
+    }
+
+    /// Query all allowances given to `grantee`
+    pub async fn feegrant_query_allowances(
+        &self,
+        grantee: Address,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<AllowancesResponse, FeegrantError> {
+        let req = QueryAllowancesRequest {
+            grantee: grantee.into(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryAllowancesResponse>(req, "/cosmos.feegrant.v1beta1.Query/Allowances")
+            .await?;
+
+        Ok(AllowancesResponse {
+            allowances: res
+                .allowances
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query all allowances given by `granter`
+    ///
+    /// Since: cosmos-sdk 0.46
+    pub async fn feegrant_query_allowances_by_granter(
+        &self,
+        granter: Address,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<AllowancesResponse, FeegrantError> {
+        let req = QueryAllowancesByGranterRequest {
+            granter: granter.into(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryAllowancesByGranterResponse>(
+                req,
+                "/cosmos.feegrant.v1beta1.Query/AllowancesByGranter",
+            )
+            .await?;
+
+        Ok(AllowancesResponse {
+            allowances: res
+                .allowances
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use std::time::Duration;
+
+    use cosmrs::proto::cosmos::feegrant::v1beta1::{
+        Grant, QueryAllowanceRequest, QueryAllowanceResponse,
+    };
+
+    use crate::{
+        chain::coin::Coin,
+        clients::client::{CosmTome, MockCosmosClient},
+        modules::feegrant::model::{Allowance, BasicAllowance, PeriodicAllowance},
+        test_utils::test_cfg,
+    };
+
+    #[tokio::test]
+    async fn test_feegrant_query_allowance() {
+        let cfg = test_cfg();
+
+        let allowance = Allowance::AllowedMsg {
+            allowance: Box::new(Allowance::Periodic(PeriodicAllowance {
+                basic: BasicAllowance {
+                    spend_limit: vec![Coin {
+                        denom: "ujuno".parse().unwrap(),
+                        amount: 1_000_000,
+                    }],
+                    expiration: None,
+                },
+                period: Duration::from_secs(86400),
+                period_spend_limit: vec![Coin {
+                    denom: "ujuno".parse().unwrap(),
+                    amount: 1_000,
+                }],
+                period_can_spend: vec![],
+                period_reset: None,
+            })),
+            allowed_messages: vec!["/cosmwasm.wasm.v1.MsgExecuteContract".to_string()],
+        };
+
+        let mut mock_client = MockCosmosClient::new();
+
+        let proto_allowance = cosmrs::Any::try_from(allowance.clone()).unwrap();
+        mock_client
+            .expect_query::<QueryAllowanceRequest, QueryAllowanceResponse>()
+            .times(1)
+            .returning(move |req, path| {
+                assert_eq!(path, "/cosmos.feegrant.v1beta1.Query/Allowance");
+
+                Ok(QueryAllowanceResponse {
+                    allowance: Some(Grant {
+                        granter: req.granter,
+                        grantee: req.grantee,
+                        allowance: Some(proto_allowance.clone()),
+                    }),
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let res = cosm_tome
+            .feegrant_query_allowance(
+                "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                    .parse()
+                    .unwrap(),
+                "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                    .parse()
+                    .unwrap(),
+            )
+            .await
+            .unwrap()
+            .allowance
+            .unwrap();
+
+        assert_eq!(res.allowance, allowance);
+        assert_eq!(
+            res.grantee.to_string(),
+            "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+        );
+    }
+}
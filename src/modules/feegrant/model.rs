@@ -0,0 +1,395 @@
+use std::time::Duration;
+
+use cosmrs::proto::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
+use cosmrs::proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+use cosmrs::proto::cosmos::feegrant::v1beta1::{
+    AllowedMsgAllowance as ProtoAllowedMsgAllowance, BasicAllowance as ProtoBasicAllowance,
+    Grant as ProtoGrant, MsgGrantAllowance, MsgRevokeAllowance,
+    PeriodicAllowance as ProtoPeriodicAllowance,
+};
+use cosmrs::proto::traits::{Message, TypeUrl};
+use cosmrs::tx::MessageExt;
+use cosmrs::{tendermint::Time, Any};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::chain::coin::Coin;
+use crate::chain::error::ChainError;
+use crate::chain::msg::Msg;
+use crate::chain::request::PaginationResponse;
+use crate::chain::response::ChainTxResponse;
+use crate::chain::time::{duration_from_proto, duration_to_proto, time_from_proto, time_to_proto};
+use crate::modules::auth::model::Address;
+
+use super::error::FeegrantError;
+
+fn coins(coins: Vec<ProtoCoin>) -> Result<Vec<Coin>, ChainError> {
+    coins.into_iter().map(TryInto::try_into).collect()
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct FeegrantTxResponse {
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct AllowanceResponse {
+    pub allowance: Option<FeeGrant>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct AllowancesResponse {
+    pub allowances: Vec<FeeGrant>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+/// Allowance given by `granter` to pay the fees of `grantee`'s txs
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct FeeGrant {
+    pub granter: Address,
+    pub grantee: Address,
+    pub allowance: Allowance,
+}
+
+impl TryFrom<ProtoGrant> for FeeGrant {
+    type Error = FeegrantError;
+
+    fn try_from(g: ProtoGrant) -> Result<Self, Self::Error> {
+        Ok(Self {
+            granter: g.granter.parse()?,
+            grantee: g.grantee.parse()?,
+            allowance: g
+                .allowance
+                .ok_or(FeegrantError::MissingAllowance)?
+                .try_into()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub enum Allowance {
+    Basic(BasicAllowance),
+
+    Periodic(PeriodicAllowance),
+
+    /// Restricts `allowance` to txs only containing messages of the `allowed_messages` type urls
+    AllowedMsg {
+        allowance: Box<Allowance>,
+        allowed_messages: Vec<String>,
+    },
+
+    /// Allowance type unknown to cosm-tome
+    Other {
+        type_url: String,
+        value: Vec<u8>,
+    },
+}
+
+impl TryFrom<Any> for Allowance {
+    type Error = FeegrantError;
+
+    fn try_from(any: Any) -> Result<Self, Self::Error> {
+        let allowance = match any.type_url.as_str() {
+            ProtoBasicAllowance::TYPE_URL => Allowance::Basic(
+                ProtoBasicAllowance::decode(any.value.as_slice())
+                    .map_err(ChainError::prost_proto_decoding)?
+                    .try_into()?,
+            ),
+            ProtoPeriodicAllowance::TYPE_URL => Allowance::Periodic(
+                ProtoPeriodicAllowance::decode(any.value.as_slice())
+                    .map_err(ChainError::prost_proto_decoding)?
+                    .try_into()?,
+            ),
+            ProtoAllowedMsgAllowance::TYPE_URL => {
+                let a = ProtoAllowedMsgAllowance::decode(any.value.as_slice())
+                    .map_err(ChainError::prost_proto_decoding)?;
+
+                Allowance::AllowedMsg {
+                    allowance: Box::new(
+                        a.allowance
+                            .ok_or(FeegrantError::MissingAllowance)?
+                            .try_into()?,
+                    ),
+                    allowed_messages: a.allowed_messages,
+                }
+            }
+            _ => Allowance::Other {
+                type_url: any.type_url,
+                value: any.value,
+            },
+        };
+
+        Ok(allowance)
+    }
+}
+
+impl TryFrom<Allowance> for Any {
+    type Error = FeegrantError;
+
+    fn try_from(a: Allowance) -> Result<Self, Self::Error> {
+        let any = match a {
+            Allowance::Basic(basic) => ProtoBasicAllowance::from(basic).to_any(),
+            Allowance::Periodic(periodic) => ProtoPeriodicAllowance::from(periodic).to_any(),
+            Allowance::AllowedMsg {
+                allowance,
+                allowed_messages,
+            } => ProtoAllowedMsgAllowance {
+                allowance: Some((*allowance).try_into()?),
+                allowed_messages,
+            }
+            .to_any(),
+            Allowance::Other { type_url, value } => return Ok(Any { type_url, value }),
+        };
+
+        Ok(any.map_err(ChainError::prost_proto_encoding)?)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Default)]
+pub struct BasicAllowance {
+    /// Maximum amount of tokens that can be spent on fees, unlimited if empty
+    pub spend_limit: Vec<Coin>,
+
+    /// Time after which the allowance can no longer be used, `None` if it never expires
+    #[schemars(with = "Option<String>")]
+    pub expiration: Option<Time>,
+}
+
+impl TryFrom<ProtoBasicAllowance> for BasicAllowance {
+    type Error = ChainError;
+
+    fn try_from(a: ProtoBasicAllowance) -> Result<Self, Self::Error> {
+        Ok(Self {
+            spend_limit: coins(a.spend_limit)?,
+            expiration: a.expiration.map(time_from_proto).transpose()?,
+        })
+    }
+}
+
+impl From<BasicAllowance> for ProtoBasicAllowance {
+    fn from(a: BasicAllowance) -> Self {
+        Self {
+            spend_limit: a.spend_limit.into_iter().map(Into::into).collect(),
+            expiration: a.expiration.map(time_to_proto),
+        }
+    }
+}
+
+/// Allowance that can spend up to `period_spend_limit` every `period`, within the limits of `basic`
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct PeriodicAllowance {
+    pub basic: BasicAllowance,
+
+    pub period: Duration,
+
+    pub period_spend_limit: Vec<Coin>,
+
+    /// Amount left to spend in the current period, set by the chain
+    pub period_can_spend: Vec<Coin>,
+
+    /// Time at which the current period ends, set by the chain
+    #[schemars(with = "Option<String>")]
+    pub period_reset: Option<Time>,
+}
+
+impl TryFrom<ProtoPeriodicAllowance> for PeriodicAllowance {
+    type Error = ChainError;
+
+    fn try_from(a: ProtoPeriodicAllowance) -> Result<Self, Self::Error> {
+        Ok(Self {
+            basic: a
+                .basic
+                .map(TryInto::try_into)
+                .transpose()?
+                .unwrap_or_default(),
+            period: a
+                .period
+                .map(duration_from_proto)
+                .transpose()?
+                .unwrap_or_default(),
+            period_spend_limit: coins(a.period_spend_limit)?,
+            period_can_spend: coins(a.period_can_spend)?,
+            period_reset: a.period_reset.map(time_from_proto).transpose()?,
+        })
+    }
+}
+
+impl From<PeriodicAllowance> for ProtoPeriodicAllowance {
+    fn from(a: PeriodicAllowance) -> Self {
+        Self {
+            basic: Some(a.basic.into()),
+            period: Some(duration_to_proto(a.period)),
+            period_spend_limit: a.period_spend_limit.into_iter().map(Into::into).collect(),
+            period_can_spend: a.period_can_spend.into_iter().map(Into::into).collect(),
+            period_reset: a.period_reset.map(time_to_proto),
+        }
+    }
+}
+
+/// Allows `grantee` to pay its tx fees with `granter`'s funds, by setting `TxOptions::fee_granter`
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct GrantAllowanceRequest {
+    pub granter: Address,
+    pub grantee: Address,
+    pub allowance: Allowance,
+}
+
+impl Msg for GrantAllowanceRequest {
+    type Proto = MsgGrantAllowance;
+    type Err = FeegrantError;
+}
+
+impl TryFrom<MsgGrantAllowance> for GrantAllowanceRequest {
+    type Error = FeegrantError;
+
+    fn try_from(msg: MsgGrantAllowance) -> Result<Self, Self::Error> {
+        Ok(Self {
+            granter: msg.granter.parse()?,
+            grantee: msg.grantee.parse()?,
+            allowance: msg
+                .allowance
+                .ok_or(FeegrantError::MissingAllowance)?
+                .try_into()?,
+        })
+    }
+}
+
+impl TryFrom<GrantAllowanceRequest> for MsgGrantAllowance {
+    type Error = FeegrantError;
+
+    fn try_from(req: GrantAllowanceRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            granter: req.granter.into(),
+            grantee: req.grantee.into(),
+            allowance: Some(req.allowance.try_into()?),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct RevokeAllowanceRequest {
+    pub granter: Address,
+    pub grantee: Address,
+}
+
+impl Msg for RevokeAllowanceRequest {
+    type Proto = MsgRevokeAllowance;
+    type Err = FeegrantError;
+}
+
+impl TryFrom<MsgRevokeAllowance> for RevokeAllowanceRequest {
+    type Error = FeegrantError;
+
+    fn try_from(msg: MsgRevokeAllowance) -> Result<Self, Self::Error> {
+        Ok(Self {
+            granter: msg.granter.parse()?,
+            grantee: msg.grantee.parse()?,
+        })
+    }
+}
+
+impl TryFrom<RevokeAllowanceRequest> for MsgRevokeAllowance {
+    type Error = FeegrantError;
+
+    fn try_from(req: RevokeAllowanceRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            granter: req.granter.into(),
+            grantee: req.grantee.into(),
+        })
+    }
+}
+
+/// `Query/AllowancesByGranter` was added in cosmos-sdk 0.46,
+/// so it is not part of the sdk protos we currently depend on.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryAllowancesByGranterRequest {
+    #[prost(string, tag = "1")]
+    pub granter: String,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: Option<PageRequest>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryAllowancesByGranterResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub allowances: Vec<ProtoGrant>,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: Option<PageResponse>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use cosmrs::proto::cosmos::feegrant::v1beta1::{
+        AllowedMsgAllowance as ProtoAllowedMsgAllowance, BasicAllowance as ProtoBasicAllowance,
+    };
+    use cosmrs::proto::traits::TypeUrl;
+    use cosmrs::tx::MessageExt;
+    use cosmrs::{tendermint::Time, Any};
+
+    use crate::chain::{coin::Coin, error::ChainError};
+
+    use super::{Allowance, BasicAllowance, FeegrantError, PeriodicAllowance};
+
+    #[test]
+    fn test_allowance_roundtrip() {
+        let allowance = Allowance::AllowedMsg {
+            allowance: Box::new(Allowance::Periodic(PeriodicAllowance {
+                basic: BasicAllowance {
+                    spend_limit: vec![],
+                    expiration: Some(Time::from_unix_timestamp(1_700_000_000, 0).unwrap()),
+                },
+                period: Duration::from_secs(3600),
+                period_spend_limit: vec![Coin {
+                    denom: "ujuno".parse().unwrap(),
+                    amount: 1000,
+                }],
+                period_can_spend: vec![],
+                period_reset: None,
+            })),
+            allowed_messages: vec!["/cosmos.bank.v1beta1.MsgSend".to_string()],
+        };
+
+        let any = Any::try_from(allowance.clone()).unwrap();
+        assert_eq!(any.type_url, ProtoAllowedMsgAllowance::TYPE_URL);
+        assert_eq!(Allowance::try_from(any).unwrap(), allowance);
+    }
+
+    #[test]
+    fn test_allowance_decode_failure() {
+        let err = Allowance::try_from(Any {
+            type_url: ProtoBasicAllowance::TYPE_URL.to_string(),
+            value: vec![0xff, 0xff],
+        })
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            FeegrantError::ChainError(ChainError::ProtoDecoding { .. })
+        ));
+
+        // an allowed msg allowance must wrap another allowance
+        let any = ProtoAllowedMsgAllowance {
+            allowance: None,
+            allowed_messages: vec![],
+        }
+        .to_any()
+        .unwrap();
+        let err = Allowance::try_from(any).unwrap_err();
+        assert!(matches!(err, FeegrantError::MissingAllowance));
+
+        // unknown allowances are kept as is, rather than failing the whole query
+        let any = Any {
+            type_url: "/juno.feeshare.v1.Allowance".to_string(),
+            value: vec![1, 2, 3],
+        };
+        assert_eq!(
+            Allowance::try_from(any.clone()).unwrap(),
+            Allowance::Other {
+                type_url: any.type_url,
+                value: any.value,
+            }
+        );
+    }
+}
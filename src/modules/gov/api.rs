@@ -0,0 +1,744 @@
+use cosmrs::proto::cosmos::gov::v1beta1 as proto;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    chain::request::{PaginationRequest, TxOptions},
+    clients::client::{CosmTome, CosmosClient},
+    modules::auth::model::Address,
+    signing_key::key::SigningKey,
+};
+
+use super::{
+    error::GovError,
+    model::{
+        DepositRequest, DepositResponse, DepositsResponse, GovTxResponse, GovVersion, Params,
+        ParamsResponse, ProposalResponse, ProposalStatus, ProposalsResponse, SubmitProposalRequest,
+        SubmitProposalResponse, TallyResponse, VoteRequest, VoteResponse, VotesResponse,
+        WeightedVoteRequest,
+    },
+    v1,
+};
+
+impl<T: CosmosClient> CosmTome<T> {
+    /// Returns the gov module version used by all `gov_*` apis.
+    ///
+    /// Unless it was set with `CosmTome::with_gov_version()`, the version is detected on first use
+    /// by querying the gov v1 params, falling back to v1beta1 if the chain does not know the query.
+    /// Any other error is returned without caching a version, so the detection is retried on the next call.
+    pub async fn gov_version(&self) -> Result<GovVersion, GovError> {
+        let version = self
+            .gov_version
+            .get_or_try_init(|| async {
+                let req = v1::QueryParamsRequest {
+                    params_type: "tallying".to_string(),
+                };
+
+                match self
+                    .client
+                    .query::<_, v1::QueryParamsResponse>(req, "/cosmos.gov.v1.Query/Params")
+                    .await
+                {
+                    Ok(_) => Ok(GovVersion::V1),
+                    Err(e) if e.is_unknown_query() => Ok(GovVersion::V1Beta1),
+                    Err(e) => Err(e),
+                }
+            })
+            .await?;
+
+        Ok(*version)
+    }
+
+    /// Address of the gov module account, the authority of legacy proposal content
+    fn gov_module_address(&self) -> Result<Address, GovError> {
+        Ok(Address::new(
+            &self.cfg.prefix,
+            &Sha256::digest(b"gov")[..20],
+        )?)
+    }
+
+    /// Whether gov v1 proposals have a `title` and `summary`, which were added in cosmos-sdk 0.47.
+    /// Unlike older chains, 0.47 chains return the new `params` from the v1 params query.
+    async fn gov_v1_proposal_title(&self) -> Result<bool, GovError> {
+        let req = v1::QueryParamsRequest {
+            params_type: "tallying".to_string(),
+        };
+
+        let res = self
+            .client
+            .query::<_, v1::QueryParamsResponse>(req, "/cosmos.gov.v1.Query/Params")
+            .await?;
+
+        Ok(res.params.is_some())
+    }
+
+    /// Submits a governance proposal and returns its id
+    pub async fn gov_submit_proposal(
+        &self,
+        req: SubmitProposalRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<SubmitProposalResponse, GovError> {
+        let version = self.gov_version().await?;
+
+        if version == GovVersion::V1
+            && !(req.title.is_empty() && req.summary.is_empty())
+            && !self.gov_v1_proposal_title().await?
+        {
+            return Err(GovError::Unsupported {
+                feature: "proposal title and summary (cosmos-sdk 0.47+)".to_string(),
+                version,
+            });
+        }
+
+        let msg = req.into_any(version, self.gov_module_address()?)?;
+
+        let res = self.tx_send_any(vec![msg], key, tx_options).await?;
+
+        let proposal_id = res
+            .find_event_tags("submit_proposal".to_string(), "proposal_id".to_string())
+            .first()
+            .and_then(|t| t.value.parse::<u64>().ok())
+            .ok_or(GovError::MissingEvent)?;
+
+        Ok(SubmitProposalResponse { proposal_id, res })
+    }
+
+    pub async fn gov_deposit(
+        &self,
+        req: DepositRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<GovTxResponse, GovError> {
+        let msg = req.into_any(self.gov_version().await?)?;
+
+        let res = self.tx_send_any(vec![msg], key, tx_options).await?;
+
+        Ok(GovTxResponse { res })
+    }
+
+    pub async fn gov_vote(
+        &self,
+        req: VoteRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<GovTxResponse, GovError> {
+        let msg = req.into_any(self.gov_version().await?)?;
+
+        let res = self.tx_send_any(vec![msg], key, tx_options).await?;
+
+        Ok(GovTxResponse { res })
+    }
+
+    pub async fn gov_vote_weighted(
+        &self,
+        req: WeightedVoteRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<GovTxResponse, GovError> {
+        let msg = req.into_any(self.gov_version().await?)?;
+
+        let res = self.tx_send_any(vec![msg], key, tx_options).await?;
+
+        Ok(GovTxResponse { res })
+    }
+
+    pub async fn gov_query_proposal(&self, proposal_id: u64) -> Result<ProposalResponse, GovError> {
+        let proposal = match self.gov_version().await? {
+            GovVersion::V1Beta1 => {
+                let req = proto::QueryProposalRequest { proposal_id };
+
+                let res = self
+                    .client
+                    .query::<_, proto::QueryProposalResponse>(
+                        req,
+                        "/cosmos.gov.v1beta1.Query/Proposal",
+                    )
+                    .await?;
+
+                res.proposal.map(TryInto::try_into).transpose()?
+            }
+            GovVersion::V1 => {
+                let req = v1::QueryProposalRequest { proposal_id };
+
+                let res = self
+                    .client
+                    .query::<_, v1::QueryProposalResponse>(req, "/cosmos.gov.v1.Query/Proposal")
+                    .await?;
+
+                res.proposal.map(TryInto::try_into).transpose()?
+            }
+        };
+
+        Ok(ProposalResponse { proposal })
+    }
+
+    /// Query proposals, optionally filtered by `status`, or by the `voter` / `depositor` that took part in them
+    pub async fn gov_query_proposals(
+        &self,
+        status: Option<ProposalStatus>,
+        voter: Option<Address>,
+        depositor: Option<Address>,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<ProposalsResponse, GovError> {
+        let proposal_status = status.unwrap_or(ProposalStatus::Unspecified).into();
+        let voter = voter.map(Into::into).unwrap_or_default();
+        let depositor = depositor.map(Into::into).unwrap_or_default();
+
+        let (proposals, next) = match self.gov_version().await? {
+            GovVersion::V1Beta1 => {
+                let req = proto::QueryProposalsRequest {
+                    proposal_status,
+                    voter,
+                    depositor,
+                    pagination: pagination.map(Into::into),
+                };
+
+                let res = self
+                    .client
+                    .query::<_, proto::QueryProposalsResponse>(
+                        req,
+                        "/cosmos.gov.v1beta1.Query/Proposals",
+                    )
+                    .await?;
+
+                (
+                    res.proposals
+                        .into_iter()
+                        .map(TryInto::try_into)
+                        .collect::<Result<Vec<_>, _>>()?,
+                    res.pagination,
+                )
+            }
+            GovVersion::V1 => {
+                let req = v1::QueryProposalsRequest {
+                    proposal_status,
+                    voter,
+                    depositor,
+                    pagination: pagination.map(Into::into),
+                };
+
+                let res = self
+                    .client
+                    .query::<_, v1::QueryProposalsResponse>(req, "/cosmos.gov.v1.Query/Proposals")
+                    .await?;
+
+                (
+                    res.proposals
+                        .into_iter()
+                        .map(TryInto::try_into)
+                        .collect::<Result<Vec<_>, _>>()?,
+                    res.pagination,
+                )
+            }
+        };
+
+        Ok(ProposalsResponse {
+            proposals,
+            next: next.map(Into::into),
+        })
+    }
+
+    pub async fn gov_query_vote(
+        &self,
+        proposal_id: u64,
+        voter: Address,
+    ) -> Result<VoteResponse, GovError> {
+        let vote = match self.gov_version().await? {
+            GovVersion::V1Beta1 => {
+                let req = proto::QueryVoteRequest {
+                    proposal_id,
+                    voter: voter.into(),
+                };
+
+                let res = self
+                    .client
+                    .query::<_, proto::QueryVoteResponse>(req, "/cosmos.gov.v1beta1.Query/Vote")
+                    .await?;
+
+                res.vote.map(TryInto::try_into).transpose()?
+            }
+            GovVersion::V1 => {
+                let req = v1::QueryVoteRequest {
+                    proposal_id,
+                    voter: voter.into(),
+                };
+
+                let res = self
+                    .client
+                    .query::<_, v1::QueryVoteResponse>(req, "/cosmos.gov.v1.Query/Vote")
+                    .await?;
+
+                res.vote.map(TryInto::try_into).transpose()?
+            }
+        };
+
+        Ok(VoteResponse { vote })
+    }
+
+    pub async fn gov_query_votes(
+        &self,
+        proposal_id: u64,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<VotesResponse, GovError> {
+        let (votes, next) = match self.gov_version().await? {
+            GovVersion::V1Beta1 => {
+                let req = proto::QueryVotesRequest {
+                    proposal_id,
+                    pagination: pagination.map(Into::into),
+                };
+
+                let res = self
+                    .client
+                    .query::<_, proto::QueryVotesResponse>(req, "/cosmos.gov.v1beta1.Query/Votes")
+                    .await?;
+
+                (
+                    res.votes
+                        .into_iter()
+                        .map(TryInto::try_into)
+                        .collect::<Result<Vec<_>, _>>()?,
+                    res.pagination,
+                )
+            }
+            GovVersion::V1 => {
+                let req = v1::QueryVotesRequest {
+                    proposal_id,
+                    pagination: pagination.map(Into::into),
+                };
+
+                let res = self
+                    .client
+                    .query::<_, v1::QueryVotesResponse>(req, "/cosmos.gov.v1.Query/Votes")
+                    .await?;
+
+                (
+                    res.votes
+                        .into_iter()
+                        .map(TryInto::try_into)
+                        .collect::<Result<Vec<_>, _>>()?,
+                    res.pagination,
+                )
+            }
+        };
+
+        Ok(VotesResponse {
+            votes,
+            next: next.map(Into::into),
+        })
+    }
+
+    pub async fn gov_query_deposit(
+        &self,
+        proposal_id: u64,
+        depositor: Address,
+    ) -> Result<DepositResponse, GovError> {
+        let deposit = match self.gov_version().await? {
+            GovVersion::V1Beta1 => {
+                let req = proto::QueryDepositRequest {
+                    proposal_id,
+                    depositor: depositor.into(),
+                };
+
+                let res = self
+                    .client
+                    .query::<_, proto::QueryDepositResponse>(
+                        req,
+                        "/cosmos.gov.v1beta1.Query/Deposit",
+                    )
+                    .await?;
+
+                res.deposit.map(TryInto::try_into).transpose()?
+            }
+            GovVersion::V1 => {
+                let req = v1::QueryDepositRequest {
+                    proposal_id,
+                    depositor: depositor.into(),
+                };
+
+                let res = self
+                    .client
+                    .query::<_, v1::QueryDepositResponse>(req, "/cosmos.gov.v1.Query/Deposit")
+                    .await?;
+
+                res.deposit.map(TryInto::try_into).transpose()?
+            }
+        };
+
+        Ok(DepositResponse { deposit })
+    }
+
+    pub async fn gov_query_deposits(
+        &self,
+        proposal_id: u64,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<DepositsResponse, GovError> {
+        let (deposits, next) = match self.gov_version().await? {
+            GovVersion::V1Beta1 => {
+                let req = proto::QueryDepositsRequest {
+                    proposal_id,
+                    pagination: pagination.map(Into::into),
+                };
+
+                let res = self
+                    .client
+                    .query::<_, proto::QueryDepositsResponse>(
+                        req,
+                        "/cosmos.gov.v1beta1.Query/Deposits",
+                    )
+                    .await?;
+
+                (
+                    res.deposits
+                        .into_iter()
+                        .map(TryInto::try_into)
+                        .collect::<Result<Vec<_>, _>>()?,
+                    res.pagination,
+                )
+            }
+            GovVersion::V1 => {
+                let req = v1::QueryDepositsRequest {
+                    proposal_id,
+                    pagination: pagination.map(Into::into),
+                };
+
+                let res = self
+                    .client
+                    .query::<_, v1::QueryDepositsResponse>(req, "/cosmos.gov.v1.Query/Deposits")
+                    .await?;
+
+                (
+                    res.deposits
+                        .into_iter()
+                        .map(TryInto::try_into)
+                        .collect::<Result<Vec<_>, _>>()?,
+                    res.pagination,
+                )
+            }
+        };
+
+        Ok(DepositsResponse {
+            deposits,
+            next: next.map(Into::into),
+        })
+    }
+
+    /// Query the current tally of a proposal in its voting period
+    pub async fn gov_query_tally(&self, proposal_id: u64) -> Result<TallyResponse, GovError> {
+        let tally = match self.gov_version().await? {
+            GovVersion::V1Beta1 => {
+                let req = proto::QueryTallyResultRequest { proposal_id };
+
+                let res = self
+                    .client
+                    .query::<_, proto::QueryTallyResultResponse>(
+                        req,
+                        "/cosmos.gov.v1beta1.Query/TallyResult",
+                    )
+                    .await?;
+
+                res.tally.map(TryInto::try_into).transpose()?
+            }
+            GovVersion::V1 => {
+                let req = v1::QueryTallyResultRequest { proposal_id };
+
+                let res = self
+                    .client
+                    .query::<_, v1::QueryTallyResultResponse>(
+                        req,
+                        "/cosmos.gov.v1.Query/TallyResult",
+                    )
+                    .await?;
+
+                res.tally.map(TryInto::try_into).transpose()?
+            }
+        };
+
+        Ok(TallyResponse { tally })
+    }
+
+    /// Query gov module cosmos sdk params.
+    /// The chain only returns one group of params per query, so this sends a query for each of them.
+    pub async fn gov_query_params(&self) -> Result<ParamsResponse, GovError> {
+        let mut params = Params::default();
+
+        match self.gov_version().await? {
+            GovVersion::V1Beta1 => {
+                for params_type in ["voting", "deposit", "tallying"] {
+                    let req = proto::QueryParamsRequest {
+                        params_type: params_type.to_string(),
+                    };
+
+                    let res = self
+                        .client
+                        .query::<_, proto::QueryParamsResponse>(
+                            req,
+                            "/cosmos.gov.v1beta1.Query/Params",
+                        )
+                        .await?;
+
+                    match params_type {
+                        "voting" => params
+                            .set_voting_params(res.voting_params.and_then(|p| p.voting_period))?,
+                        "deposit" => {
+                            let p = res.deposit_params.unwrap_or_default();
+                            params.set_deposit_params(p.min_deposit, p.max_deposit_period)?
+                        }
+                        _ => {
+                            params.set_v1beta1_tally_params(res.tally_params.unwrap_or_default())?
+                        }
+                    }
+                }
+            }
+            GovVersion::V1 => {
+                for params_type in ["voting", "deposit", "tallying"] {
+                    let req = v1::QueryParamsRequest {
+                        params_type: params_type.to_string(),
+                    };
+
+                    let res = self
+                        .client
+                        .query::<_, v1::QueryParamsResponse>(req, "/cosmos.gov.v1.Query/Params")
+                        .await?;
+
+                    match params_type {
+                        "voting" => params
+                            .set_voting_params(res.voting_params.and_then(|p| p.voting_period))?,
+                        "deposit" => {
+                            let p = res.deposit_params.unwrap_or_default();
+                            params.set_deposit_params(p.min_deposit, p.max_deposit_period)?
+                        }
+                        _ => params.set_v1_tally_params(res.tally_params.unwrap_or_default())?,
+                    }
+                }
+            }
+        }
+
+        Ok(ParamsResponse { params })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use cosmrs::proto::cosmos::gov::v1beta1 as proto;
+
+    use crate::{
+        chain::{
+            error::ChainError,
+            request::TxOptions,
+            response::{ChainResponse, Code},
+        },
+        clients::client::{CosmTome, MockCosmosClient},
+        modules::gov::{
+            error::GovError,
+            model::{GovVersion, ProposalStatus, SubmitProposalRequest, TallyResult},
+            v1,
+        },
+        signing_key::key::SigningKey,
+        test_utils::test_cfg,
+    };
+
+    #[tokio::test]
+    async fn test_gov_version_fallback() {
+        let mut mock_client = MockCosmosClient::new();
+
+        // detection only happens once
+        mock_client
+            .expect_query::<v1::QueryParamsRequest, v1::QueryParamsResponse>()
+            .times(1)
+            .returning(move |_, path| {
+                assert_eq!(path, "/cosmos.gov.v1.Query/Params");
+
+                Err(ChainError::CosmosSdk {
+                    res: ChainResponse {
+                        code: Code::Err(6),
+                        codespace: "sdk".to_string(),
+                        log: "unknown query path".to_string(),
+                        ..Default::default()
+                    },
+                })
+            });
+
+        mock_client
+            .expect_query::<proto::QueryProposalRequest, proto::QueryProposalResponse>()
+            .times(2)
+            .returning(move |req, path| {
+                assert_eq!(path, "/cosmos.gov.v1beta1.Query/Proposal");
+
+                Ok(proto::QueryProposalResponse {
+                    proposal: Some(proto::Proposal {
+                        proposal_id: req.proposal_id,
+                        status: proto::ProposalStatus::Passed as i32,
+                        final_tally_result: Some(proto::TallyResult {
+                            yes: "10".to_string(),
+                            abstain: "0".to_string(),
+                            no: "2".to_string(),
+                            no_with_veto: "0".to_string(),
+                        }),
+                        ..Default::default()
+                    }),
+                })
+            });
+
+        let cosm_tome = CosmTome::new(test_cfg(), mock_client);
+
+        for _ in 0..2 {
+            let proposal = cosm_tome
+                .gov_query_proposal(4)
+                .await
+                .unwrap()
+                .proposal
+                .unwrap();
+
+            assert_eq!(proposal.id, 4);
+            assert_eq!(proposal.status, ProposalStatus::Passed);
+            assert_eq!(
+                proposal.final_tally_result,
+                Some(TallyResult {
+                    yes: 10,
+                    abstain: 0,
+                    no: 2,
+                    no_with_veto: 0,
+                })
+            );
+        }
+
+        assert_eq!(cosm_tome.gov_version().await.unwrap(), GovVersion::V1Beta1);
+    }
+
+    #[tokio::test]
+    async fn test_gov_query_v1_proposal() {
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<v1::QueryProposalRequest, v1::QueryProposalResponse>()
+            .times(1)
+            .returning(move |req, path| {
+                assert_eq!(path, "/cosmos.gov.v1.Query/Proposal");
+
+                Ok(v1::QueryProposalResponse {
+                    proposal: Some(v1::Proposal {
+                        id: req.proposal_id,
+                        status: proto::ProposalStatus::VotingPeriod as i32,
+                        title: "Upgrade".to_string(),
+                        metadata: "ipfs://proposal".to_string(),
+                        ..Default::default()
+                    }),
+                })
+            });
+
+        let cosm_tome = CosmTome::new(test_cfg(), mock_client).with_gov_version(GovVersion::V1);
+
+        let proposal = cosm_tome
+            .gov_query_proposal(7)
+            .await
+            .unwrap()
+            .proposal
+            .unwrap();
+
+        assert_eq!(proposal.id, 7);
+        assert_eq!(proposal.status, ProposalStatus::VotingPeriod);
+        assert_eq!(proposal.title, "Upgrade");
+        assert_eq!(proposal.metadata, "ipfs://proposal");
+        assert_eq!(proposal.content, None);
+    }
+
+    #[tokio::test]
+    async fn test_gov_version_grpc_unimplemented() {
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<v1::QueryParamsRequest, v1::QueryParamsResponse>()
+            .times(1)
+            .returning(move |_, _| {
+                Err(ChainError::tonic_status(tonic::Status::unimplemented(
+                    "unknown service cosmos.gov.v1.Query",
+                )))
+            });
+
+        let cosm_tome = CosmTome::new(test_cfg(), mock_client);
+
+        assert_eq!(cosm_tome.gov_version().await.unwrap(), GovVersion::V1Beta1);
+        assert_eq!(cosm_tome.gov_version().await.unwrap(), GovVersion::V1Beta1);
+    }
+
+    #[tokio::test]
+    async fn test_gov_version_transient_error() {
+        let mut mock_client = MockCosmosClient::new();
+
+        let mut queries = 0;
+        mock_client
+            .expect_query::<v1::QueryParamsRequest, v1::QueryParamsResponse>()
+            .times(2)
+            .returning(move |_, _| {
+                queries += 1;
+
+                if queries == 1 {
+                    return Err(ChainError::tonic_status(tonic::Status::unavailable(
+                        "connection refused",
+                    )));
+                }
+
+                Ok(v1::QueryParamsResponse::default())
+            });
+
+        let cosm_tome = CosmTome::new(test_cfg(), mock_client);
+
+        // the failed detection is not cached as v1beta1, so the next call retries it
+        let err = cosm_tome.gov_version().await.unwrap_err();
+        assert!(matches!(
+            err,
+            GovError::ChainError(ChainError::CosmosSdk { .. })
+        ));
+
+        assert_eq!(cosm_tome.gov_version().await.unwrap(), GovVersion::V1);
+    }
+
+    #[tokio::test]
+    async fn test_gov_submit_proposal_title_sdk_046() {
+        let mut mock_client = MockCosmosClient::new();
+
+        let mut queries = 0;
+        mock_client
+            .expect_query::<v1::QueryParamsRequest, v1::QueryParamsResponse>()
+            .times(2)
+            .returning(move |_, path| {
+                assert_eq!(path, "/cosmos.gov.v1.Query/Params");
+                queries += 1;
+
+                // only cosmos-sdk 0.47+ chains return the new `params`
+                Ok(v1::QueryParamsResponse {
+                    params: (queries == 2).then(v1::Params::default),
+                    ..Default::default()
+                })
+            });
+
+        let cfg = test_cfg();
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
+        let cosm_tome = CosmTome::new(cfg, mock_client).with_gov_version(GovVersion::V1);
+
+        let req = SubmitProposalRequest {
+            proposer: key.to_addr("juno").await.unwrap(),
+            title: "Upgrade".to_string(),
+            summary: String::new(),
+            content: None,
+            messages: vec![],
+            metadata: String::new(),
+            initial_deposit: vec![],
+        };
+
+        let err = cosm_tome
+            .gov_submit_proposal(req, &key, &TxOptions::default())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            GovError::Unsupported {
+                version: GovVersion::V1,
+                ..
+            }
+        ));
+
+        assert!(cosm_tome.gov_v1_proposal_title().await.unwrap());
+    }
+}
@@ -0,0 +1,38 @@
+use thiserror::Error;
+
+use crate::{
+    chain::error::ChainError,
+    modules::{auth::error::AccountError, tx::error::TxError},
+};
+
+use super::model::GovVersion;
+
+#[derive(Error, Debug)]
+pub enum GovError {
+    #[error("Cannot deposit 0 amount of a token")]
+    EmptyAmount,
+
+    #[error("Invalid proposal status: {i}")]
+    ProposalStatus { i: i32 },
+
+    #[error("Invalid vote option: {i}")]
+    VoteOption { i: i32 },
+
+    #[error("Not supported by gov {version:?}: {feature}")]
+    Unsupported {
+        feature: String,
+        version: GovVersion,
+    },
+
+    #[error("Missing proposal_id in submit_proposal event")]
+    MissingEvent,
+
+    #[error(transparent)]
+    TxError(#[from] TxError),
+
+    #[error(transparent)]
+    AccountError(#[from] AccountError),
+
+    #[error(transparent)]
+    ChainError(#[from] ChainError),
+}
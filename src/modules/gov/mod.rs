@@ -0,0 +1,5 @@
+pub mod api;
+pub mod error;
+pub mod model;
+pub mod v1;
+pub mod v1beta1;
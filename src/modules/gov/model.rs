@@ -0,0 +1,718 @@
+use std::time::Duration;
+
+use cosmrs::proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+use cosmrs::proto::cosmos::gov::v1beta1 as proto;
+use cosmrs::proto::traits::Message;
+use cosmrs::tx::MessageExt;
+use cosmrs::{tendermint::Time, Any};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::chain::coin::{parse_int, Coin, Dec};
+use crate::chain::error::ChainError;
+use crate::chain::request::PaginationResponse;
+use crate::chain::response::ChainTxResponse;
+use crate::chain::time::{duration_from_proto, time_from_proto};
+use crate::modules::auth::model::Address;
+use crate::modules::tx::model::{any_msg, any_msgs};
+
+use super::error::GovError;
+use super::{v1, v1beta1};
+
+/// Gov module API version exposed by the chain.
+/// `V1` was added in cosmos-sdk 0.46, older chains only support `V1Beta1`.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub enum GovVersion {
+    V1Beta1,
+    V1,
+}
+
+fn coins(coins: Vec<ProtoCoin>) -> Result<Vec<Coin>, ChainError> {
+    coins.into_iter().map(TryInto::try_into).collect()
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct SubmitProposalResponse {
+    pub proposal_id: u64,
+
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct GovTxResponse {
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ProposalResponse {
+    pub proposal: Option<Proposal>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ProposalsResponse {
+    pub proposals: Vec<Proposal>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct VoteResponse {
+    pub vote: Option<Vote>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct VotesResponse {
+    pub votes: Vec<Vote>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct DepositResponse {
+    pub deposit: Option<Deposit>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct DepositsResponse {
+    pub deposits: Vec<Deposit>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct TallyResponse {
+    pub tally: Option<TallyResult>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ParamsResponse {
+    pub params: Params,
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub enum ProposalStatus {
+    Unspecified,
+    DepositPeriod,
+    VotingPeriod,
+    Passed,
+    Rejected,
+    Failed,
+}
+
+impl TryFrom<i32> for ProposalStatus {
+    type Error = GovError;
+
+    fn try_from(i: i32) -> Result<Self, Self::Error> {
+        match proto::ProposalStatus::from_i32(i) {
+            Some(proto::ProposalStatus::Unspecified) => Ok(ProposalStatus::Unspecified),
+            Some(proto::ProposalStatus::DepositPeriod) => Ok(ProposalStatus::DepositPeriod),
+            Some(proto::ProposalStatus::VotingPeriod) => Ok(ProposalStatus::VotingPeriod),
+            Some(proto::ProposalStatus::Passed) => Ok(ProposalStatus::Passed),
+            Some(proto::ProposalStatus::Rejected) => Ok(ProposalStatus::Rejected),
+            Some(proto::ProposalStatus::Failed) => Ok(ProposalStatus::Failed),
+            None => Err(GovError::ProposalStatus { i }),
+        }
+    }
+}
+
+/// v1 and v1beta1 share the same enum values
+impl From<ProposalStatus> for i32 {
+    fn from(status: ProposalStatus) -> Self {
+        match status {
+            ProposalStatus::Unspecified => proto::ProposalStatus::Unspecified as i32,
+            ProposalStatus::DepositPeriod => proto::ProposalStatus::DepositPeriod as i32,
+            ProposalStatus::VotingPeriod => proto::ProposalStatus::VotingPeriod as i32,
+            ProposalStatus::Passed => proto::ProposalStatus::Passed as i32,
+            ProposalStatus::Rejected => proto::ProposalStatus::Rejected as i32,
+            ProposalStatus::Failed => proto::ProposalStatus::Failed as i32,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub enum VoteOption {
+    Unspecified,
+    Yes,
+    Abstain,
+    No,
+    NoWithVeto,
+}
+
+impl TryFrom<i32> for VoteOption {
+    type Error = GovError;
+
+    fn try_from(i: i32) -> Result<Self, Self::Error> {
+        match proto::VoteOption::from_i32(i) {
+            Some(proto::VoteOption::Unspecified) => Ok(VoteOption::Unspecified),
+            Some(proto::VoteOption::Yes) => Ok(VoteOption::Yes),
+            Some(proto::VoteOption::Abstain) => Ok(VoteOption::Abstain),
+            Some(proto::VoteOption::No) => Ok(VoteOption::No),
+            Some(proto::VoteOption::NoWithVeto) => Ok(VoteOption::NoWithVeto),
+            None => Err(GovError::VoteOption { i }),
+        }
+    }
+}
+
+/// v1 and v1beta1 share the same enum values
+impl From<VoteOption> for i32 {
+    fn from(option: VoteOption) -> Self {
+        match option {
+            VoteOption::Unspecified => proto::VoteOption::Unspecified as i32,
+            VoteOption::Yes => proto::VoteOption::Yes as i32,
+            VoteOption::Abstain => proto::VoteOption::Abstain as i32,
+            VoteOption::No => proto::VoteOption::No as i32,
+            VoteOption::NoWithVeto => proto::VoteOption::NoWithVeto as i32,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct WeightedVoteOption {
+    pub option: VoteOption,
+    pub weight: Dec,
+}
+
+impl TryFrom<proto::WeightedVoteOption> for WeightedVoteOption {
+    type Error = GovError;
+
+    fn try_from(o: proto::WeightedVoteOption) -> Result<Self, Self::Error> {
+        Ok(Self {
+            option: o.option.try_into()?,
+            weight: Dec::from_atomics(&o.weight)?,
+        })
+    }
+}
+
+impl From<WeightedVoteOption> for proto::WeightedVoteOption {
+    fn from(o: WeightedVoteOption) -> Self {
+        Self {
+            option: o.option.into(),
+            weight: o.weight.atomics(),
+        }
+    }
+}
+
+impl TryFrom<v1::WeightedVoteOption> for WeightedVoteOption {
+    type Error = GovError;
+
+    fn try_from(o: v1::WeightedVoteOption) -> Result<Self, Self::Error> {
+        Ok(Self {
+            option: o.option.try_into()?,
+            weight: o.weight.parse()?,
+        })
+    }
+}
+
+impl From<WeightedVoteOption> for v1::WeightedVoteOption {
+    fn from(o: WeightedVoteOption) -> Self {
+        Self {
+            option: o.option.into(),
+            weight: o.weight.to_string(),
+        }
+    }
+}
+
+/// Every v1beta1 proposal `Content` starts with a title and description
+#[derive(Clone, PartialEq, prost::Message)]
+struct LegacyContent {
+    #[prost(string, tag = "1")]
+    title: String,
+    #[prost(string, tag = "2")]
+    description: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Proposal {
+    pub id: u64,
+
+    pub status: ProposalStatus,
+
+    pub title: String,
+
+    pub summary: String,
+
+    /// v1beta1 proposal content, ie. `TextProposal`. Always `None` for v1 proposals.
+    #[serde(with = "any_msg")]
+    pub content: Option<Any>,
+
+    /// Messages executed if a v1 proposal passes. Always empty for v1beta1 proposals.
+    #[serde(with = "any_msgs")]
+    pub messages: Vec<Any>,
+
+    /// Always empty for v1beta1 proposals
+    pub metadata: String,
+
+    pub final_tally_result: Option<TallyResult>,
+
+    pub submit_time: Option<Time>,
+
+    pub deposit_end_time: Option<Time>,
+
+    pub total_deposit: Vec<Coin>,
+
+    pub voting_start_time: Option<Time>,
+
+    pub voting_end_time: Option<Time>,
+}
+
+impl TryFrom<proto::Proposal> for Proposal {
+    type Error = GovError;
+
+    fn try_from(p: proto::Proposal) -> Result<Self, Self::Error> {
+        let legacy = p
+            .content
+            .as_ref()
+            .map(|c| LegacyContent::decode(c.value.as_slice()))
+            .transpose()
+            .map_err(ChainError::prost_proto_decoding)?
+            .unwrap_or_default();
+
+        Ok(Self {
+            id: p.proposal_id,
+            status: p.status.try_into()?,
+            title: legacy.title,
+            summary: legacy.description,
+            content: p.content,
+            messages: vec![],
+            metadata: String::new(),
+            final_tally_result: p.final_tally_result.map(TryInto::try_into).transpose()?,
+            submit_time: p.submit_time.map(time_from_proto).transpose()?,
+            deposit_end_time: p.deposit_end_time.map(time_from_proto).transpose()?,
+            total_deposit: coins(p.total_deposit)?,
+            voting_start_time: p.voting_start_time.map(time_from_proto).transpose()?,
+            voting_end_time: p.voting_end_time.map(time_from_proto).transpose()?,
+        })
+    }
+}
+
+impl TryFrom<v1::Proposal> for Proposal {
+    type Error = GovError;
+
+    fn try_from(p: v1::Proposal) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: p.id,
+            status: p.status.try_into()?,
+            title: p.title,
+            summary: p.summary,
+            content: None,
+            messages: p.messages,
+            metadata: p.metadata,
+            final_tally_result: p.final_tally_result.map(TryInto::try_into).transpose()?,
+            submit_time: p.submit_time.map(time_from_proto).transpose()?,
+            deposit_end_time: p.deposit_end_time.map(time_from_proto).transpose()?,
+            total_deposit: coins(p.total_deposit)?,
+            voting_start_time: p.voting_start_time.map(time_from_proto).transpose()?,
+            voting_end_time: p.voting_end_time.map(time_from_proto).transpose()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct TallyResult {
+    pub yes: u128,
+    pub abstain: u128,
+    pub no: u128,
+    pub no_with_veto: u128,
+}
+
+impl TryFrom<proto::TallyResult> for TallyResult {
+    type Error = ChainError;
+
+    fn try_from(t: proto::TallyResult) -> Result<Self, Self::Error> {
+        Ok(Self {
+            yes: parse_int(&t.yes)?,
+            abstain: parse_int(&t.abstain)?,
+            no: parse_int(&t.no)?,
+            no_with_veto: parse_int(&t.no_with_veto)?,
+        })
+    }
+}
+
+impl TryFrom<v1::TallyResult> for TallyResult {
+    type Error = ChainError;
+
+    fn try_from(t: v1::TallyResult) -> Result<Self, Self::Error> {
+        Ok(Self {
+            yes: parse_int(&t.yes_count)?,
+            abstain: parse_int(&t.abstain_count)?,
+            no: parse_int(&t.no_count)?,
+            no_with_veto: parse_int(&t.no_with_veto_count)?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct Vote {
+    pub proposal_id: u64,
+
+    pub voter: Address,
+
+    pub options: Vec<WeightedVoteOption>,
+
+    /// Always empty for v1beta1 votes
+    pub metadata: String,
+}
+
+impl TryFrom<proto::Vote> for Vote {
+    type Error = GovError;
+
+    #[allow(deprecated)]
+    fn try_from(v: proto::Vote) -> Result<Self, Self::Error> {
+        let mut options = v
+            .options
+            .into_iter()
+            .map(TryInto::try_into)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // votes cast before weighted voting was added only set the deprecated `option` field
+        if options.is_empty() && v.option != proto::VoteOption::Unspecified as i32 {
+            options.push(WeightedVoteOption {
+                option: v.option.try_into()?,
+                weight: "1".parse()?,
+            });
+        }
+
+        Ok(Self {
+            proposal_id: v.proposal_id,
+            voter: v.voter.parse()?,
+            options,
+            metadata: String::new(),
+        })
+    }
+}
+
+impl TryFrom<v1::Vote> for Vote {
+    type Error = GovError;
+
+    fn try_from(v: v1::Vote) -> Result<Self, Self::Error> {
+        Ok(Self {
+            proposal_id: v.proposal_id,
+            voter: v.voter.parse()?,
+            options: v
+                .options
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            metadata: v.metadata,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct Deposit {
+    pub proposal_id: u64,
+    pub depositor: Address,
+    pub amount: Vec<Coin>,
+}
+
+impl TryFrom<proto::Deposit> for Deposit {
+    type Error = GovError;
+
+    fn try_from(d: proto::Deposit) -> Result<Self, Self::Error> {
+        Ok(Self {
+            proposal_id: d.proposal_id,
+            depositor: d.depositor.parse()?,
+            amount: coins(d.amount)?,
+        })
+    }
+}
+
+impl TryFrom<v1::Deposit> for Deposit {
+    type Error = GovError;
+
+    fn try_from(d: v1::Deposit) -> Result<Self, Self::Error> {
+        Ok(Self {
+            proposal_id: d.proposal_id,
+            depositor: d.depositor.parse()?,
+            amount: coins(d.amount)?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq, Default)]
+pub struct Params {
+    pub min_deposit: Vec<Coin>,
+
+    pub max_deposit_period: Option<Duration>,
+
+    pub voting_period: Option<Duration>,
+
+    /// Minimum fraction of voting power that needs to vote for a proposal to be valid
+    pub quorum: Option<Dec>,
+
+    /// Minimum fraction of yes votes for a proposal to pass
+    pub threshold: Option<Dec>,
+
+    /// Minimum fraction of no with veto votes for a proposal to be vetoed
+    pub veto_threshold: Option<Dec>,
+}
+
+impl Params {
+    pub(crate) fn set_deposit_params(
+        &mut self,
+        min_deposit: Vec<ProtoCoin>,
+        max_deposit_period: Option<prost_types::Duration>,
+    ) -> Result<(), ChainError> {
+        self.min_deposit = coins(min_deposit)?;
+        self.max_deposit_period = max_deposit_period.map(duration_from_proto).transpose()?;
+        Ok(())
+    }
+
+    pub(crate) fn set_voting_params(
+        &mut self,
+        voting_period: Option<prost_types::Duration>,
+    ) -> Result<(), ChainError> {
+        self.voting_period = voting_period.map(duration_from_proto).transpose()?;
+        Ok(())
+    }
+
+    /// v1beta1 encodes the tally params as `sdk.Dec` bytes
+    pub(crate) fn set_v1beta1_tally_params(
+        &mut self,
+        params: proto::TallyParams,
+    ) -> Result<(), ChainError> {
+        self.quorum = Some(Dec::from_atomics_bytes(params.quorum)?);
+        self.threshold = Some(Dec::from_atomics_bytes(params.threshold)?);
+        self.veto_threshold = Some(Dec::from_atomics_bytes(params.veto_threshold)?);
+        Ok(())
+    }
+
+    pub(crate) fn set_v1_tally_params(
+        &mut self,
+        params: v1::TallyParams,
+    ) -> Result<(), ChainError> {
+        self.quorum = Some(params.quorum.parse()?);
+        self.threshold = Some(params.threshold.parse()?);
+        self.veto_threshold = Some(params.veto_threshold.parse()?);
+        Ok(())
+    }
+}
+
+/// Submits a new governance proposal.
+///
+/// v1beta1 chains only support a single `content` proposal. If `content` is `None`,
+/// a `TextProposal` is created from `title` and `summary`.
+///
+/// v1 chains execute `messages` if the proposal passes. A legacy `content` is wrapped
+/// in a `MsgExecLegacyContent` message. v1 `title` and `summary` were added in cosmos-sdk 0.47,
+/// `CosmTome::gov_submit_proposal()` rejects them for chains running cosmos-sdk 0.46.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct SubmitProposalRequest {
+    pub proposer: Address,
+    pub title: String,
+    pub summary: String,
+    #[serde(with = "any_msg")]
+    pub content: Option<Any>,
+    #[serde(with = "any_msgs")]
+    pub messages: Vec<Any>,
+    pub metadata: String,
+    pub initial_deposit: Vec<Coin>,
+}
+
+impl SubmitProposalRequest {
+    pub(crate) fn into_any(self, version: GovVersion, gov_addr: Address) -> Result<Any, GovError> {
+        let initial_deposit = self.initial_deposit.into_iter().map(Into::into).collect();
+
+        let any = match version {
+            GovVersion::V1Beta1 => {
+                if !self.messages.is_empty() || !self.metadata.is_empty() {
+                    return Err(GovError::Unsupported {
+                        feature: "proposal messages and metadata".to_string(),
+                        version,
+                    });
+                }
+
+                let content = match self.content {
+                    Some(content) => content,
+                    None => v1beta1::TextProposal {
+                        title: self.title,
+                        description: self.summary,
+                    }
+                    .to_any()
+                    .map_err(ChainError::prost_proto_encoding)?,
+                };
+
+                v1beta1::MsgSubmitProposal {
+                    content: Some(content),
+                    initial_deposit,
+                    proposer: self.proposer.into(),
+                }
+                .to_any()
+                .map_err(ChainError::prost_proto_encoding)?
+            }
+            GovVersion::V1 => {
+                let mut messages = self.messages;
+
+                if let Some(content) = self.content {
+                    messages.push(
+                        v1::MsgExecLegacyContent {
+                            content: Some(content),
+                            authority: gov_addr.into(),
+                        }
+                        .to_any()
+                        .map_err(ChainError::prost_proto_encoding)?,
+                    );
+                }
+
+                v1::MsgSubmitProposal {
+                    messages,
+                    initial_deposit,
+                    proposer: self.proposer.into(),
+                    metadata: self.metadata,
+                    title: self.title,
+                    summary: self.summary,
+                }
+                .to_any()
+                .map_err(ChainError::prost_proto_encoding)?
+            }
+        };
+
+        Ok(any)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct DepositRequest {
+    pub proposal_id: u64,
+    pub depositor: Address,
+    pub amount: Vec<Coin>,
+}
+
+impl DepositRequest {
+    pub(crate) fn into_any(self, version: GovVersion) -> Result<Any, GovError> {
+        if self.amount.is_empty() || self.amount.iter().any(|a| a.amount == 0) {
+            return Err(GovError::EmptyAmount);
+        }
+
+        let amount = self.amount.into_iter().map(Into::into).collect();
+
+        match version {
+            GovVersion::V1Beta1 => Ok(v1beta1::MsgDeposit {
+                proposal_id: self.proposal_id,
+                depositor: self.depositor.into(),
+                amount,
+            }
+            .to_any()
+            .map_err(ChainError::prost_proto_encoding)?),
+            GovVersion::V1 => Ok(v1::MsgDeposit {
+                proposal_id: self.proposal_id,
+                depositor: self.depositor.into(),
+                amount,
+            }
+            .to_any()
+            .map_err(ChainError::prost_proto_encoding)?),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct VoteRequest {
+    pub proposal_id: u64,
+    pub voter: Address,
+    pub option: VoteOption,
+
+    /// Only supported by v1 chains
+    pub metadata: String,
+}
+
+impl VoteRequest {
+    pub(crate) fn into_any(self, version: GovVersion) -> Result<Any, GovError> {
+        match version {
+            GovVersion::V1Beta1 => {
+                if !self.metadata.is_empty() {
+                    return Err(GovError::Unsupported {
+                        feature: "vote metadata".to_string(),
+                        version,
+                    });
+                }
+
+                Ok(v1beta1::MsgVote {
+                    proposal_id: self.proposal_id,
+                    voter: self.voter.into(),
+                    option: self.option.into(),
+                }
+                .to_any()
+                .map_err(ChainError::prost_proto_encoding)?)
+            }
+            GovVersion::V1 => Ok(v1::MsgVote {
+                proposal_id: self.proposal_id,
+                voter: self.voter.into(),
+                option: self.option.into(),
+                metadata: self.metadata,
+            }
+            .to_any()
+            .map_err(ChainError::prost_proto_encoding)?),
+        }
+    }
+}
+
+/// Splits the voter's voting power across multiple options, weights must add up to 1
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct WeightedVoteRequest {
+    pub proposal_id: u64,
+    pub voter: Address,
+    pub options: Vec<WeightedVoteOption>,
+
+    /// Only supported by v1 chains
+    pub metadata: String,
+}
+
+impl WeightedVoteRequest {
+    pub(crate) fn into_any(self, version: GovVersion) -> Result<Any, GovError> {
+        match version {
+            GovVersion::V1Beta1 => {
+                if !self.metadata.is_empty() {
+                    return Err(GovError::Unsupported {
+                        feature: "vote metadata".to_string(),
+                        version,
+                    });
+                }
+
+                Ok(v1beta1::MsgVoteWeighted {
+                    proposal_id: self.proposal_id,
+                    voter: self.voter.into(),
+                    options: self.options.into_iter().map(Into::into).collect(),
+                }
+                .to_any()
+                .map_err(ChainError::prost_proto_encoding)?)
+            }
+            GovVersion::V1 => Ok(v1::MsgVoteWeighted {
+                proposal_id: self.proposal_id,
+                voter: self.voter.into(),
+                options: self.options.into_iter().map(Into::into).collect(),
+                metadata: self.metadata,
+            }
+            .to_any()
+            .map_err(ChainError::prost_proto_encoding)?),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_vote_any_by_version() {
+        let req = VoteRequest {
+            proposal_id: 4,
+            voter: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                .parse()
+                .unwrap(),
+            option: VoteOption::Yes,
+            metadata: String::new(),
+        };
+
+        let any = req.clone().into_any(GovVersion::V1Beta1).unwrap();
+        assert_eq!(any.type_url, "/cosmos.gov.v1beta1.MsgVote");
+
+        // decodes as the sdk proto
+        let msg = proto::MsgVote::decode(any.value.as_slice()).unwrap();
+        assert_eq!(msg.proposal_id, 4);
+        assert_eq!(msg.voter, "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg");
+        assert_eq!(msg.option, proto::VoteOption::Yes as i32);
+
+        let any = req.into_any(GovVersion::V1).unwrap();
+        assert_eq!(any.type_url, "/cosmos.gov.v1.MsgVote");
+    }
+}
@@ -0,0 +1,329 @@
+//! Gov v1 protos (cosmos-sdk 0.46+), which are not part of the sdk protos we currently depend on.
+//! Fields added in cosmos-sdk 0.47 (`title`, `summary`, `proposer`, `params`) are included.
+//! Older chains leave them empty in their responses, and reject msgs setting them.
+
+use cosmrs::proto::cosmos::base::{
+    query::v1beta1::{PageRequest, PageResponse},
+    v1beta1::Coin,
+};
+use cosmrs::proto::traits::TypeUrl;
+use cosmrs::Any;
+use prost_types::{Duration, Timestamp};
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct WeightedVoteOption {
+    #[prost(int32, tag = "1")]
+    pub option: i32,
+    /// Human readable decimal, unlike v1beta1
+    #[prost(string, tag = "2")]
+    pub weight: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Deposit {
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+    #[prost(string, tag = "2")]
+    pub depositor: String,
+    #[prost(message, repeated, tag = "3")]
+    pub amount: Vec<Coin>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Proposal {
+    #[prost(uint64, tag = "1")]
+    pub id: u64,
+    #[prost(message, repeated, tag = "2")]
+    pub messages: Vec<Any>,
+    #[prost(int32, tag = "3")]
+    pub status: i32,
+    #[prost(message, optional, tag = "4")]
+    pub final_tally_result: Option<TallyResult>,
+    #[prost(message, optional, tag = "5")]
+    pub submit_time: Option<Timestamp>,
+    #[prost(message, optional, tag = "6")]
+    pub deposit_end_time: Option<Timestamp>,
+    #[prost(message, repeated, tag = "7")]
+    pub total_deposit: Vec<Coin>,
+    #[prost(message, optional, tag = "8")]
+    pub voting_start_time: Option<Timestamp>,
+    #[prost(message, optional, tag = "9")]
+    pub voting_end_time: Option<Timestamp>,
+    #[prost(string, tag = "10")]
+    pub metadata: String,
+    #[prost(string, tag = "11")]
+    pub title: String,
+    #[prost(string, tag = "12")]
+    pub summary: String,
+    #[prost(string, tag = "13")]
+    pub proposer: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct TallyResult {
+    #[prost(string, tag = "1")]
+    pub yes_count: String,
+    #[prost(string, tag = "2")]
+    pub abstain_count: String,
+    #[prost(string, tag = "3")]
+    pub no_count: String,
+    #[prost(string, tag = "4")]
+    pub no_with_veto_count: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Vote {
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+    #[prost(string, tag = "2")]
+    pub voter: String,
+    #[prost(message, repeated, tag = "4")]
+    pub options: Vec<WeightedVoteOption>,
+    #[prost(string, tag = "5")]
+    pub metadata: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct DepositParams {
+    #[prost(message, repeated, tag = "1")]
+    pub min_deposit: Vec<Coin>,
+    #[prost(message, optional, tag = "2")]
+    pub max_deposit_period: Option<Duration>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct VotingParams {
+    #[prost(message, optional, tag = "1")]
+    pub voting_period: Option<Duration>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct TallyParams {
+    #[prost(string, tag = "1")]
+    pub quorum: String,
+    #[prost(string, tag = "2")]
+    pub threshold: String,
+    #[prost(string, tag = "3")]
+    pub veto_threshold: String,
+}
+
+/// All the gov params, replacing the per type params in cosmos-sdk 0.47
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Params {
+    #[prost(message, repeated, tag = "1")]
+    pub min_deposit: Vec<Coin>,
+    #[prost(message, optional, tag = "2")]
+    pub max_deposit_period: Option<Duration>,
+    #[prost(message, optional, tag = "3")]
+    pub voting_period: Option<Duration>,
+    #[prost(string, tag = "4")]
+    pub quorum: String,
+    #[prost(string, tag = "5")]
+    pub threshold: String,
+    #[prost(string, tag = "6")]
+    pub veto_threshold: String,
+    #[prost(string, tag = "7")]
+    pub min_initial_deposit_ratio: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgSubmitProposal {
+    #[prost(message, repeated, tag = "1")]
+    pub messages: Vec<Any>,
+    #[prost(message, repeated, tag = "2")]
+    pub initial_deposit: Vec<Coin>,
+    #[prost(string, tag = "3")]
+    pub proposer: String,
+    #[prost(string, tag = "4")]
+    pub metadata: String,
+    #[prost(string, tag = "5")]
+    pub title: String,
+    #[prost(string, tag = "6")]
+    pub summary: String,
+}
+
+impl TypeUrl for MsgSubmitProposal {
+    const TYPE_URL: &'static str = "/cosmos.gov.v1.MsgSubmitProposal";
+}
+
+/// Wraps a legacy v1beta1 proposal `Content` so it can be submitted as a v1 proposal message
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgExecLegacyContent {
+    #[prost(message, optional, tag = "1")]
+    pub content: Option<Any>,
+    /// Gov module account address
+    #[prost(string, tag = "2")]
+    pub authority: String,
+}
+
+impl TypeUrl for MsgExecLegacyContent {
+    const TYPE_URL: &'static str = "/cosmos.gov.v1.MsgExecLegacyContent";
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgVote {
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+    #[prost(string, tag = "2")]
+    pub voter: String,
+    #[prost(int32, tag = "3")]
+    pub option: i32,
+    #[prost(string, tag = "4")]
+    pub metadata: String,
+}
+
+impl TypeUrl for MsgVote {
+    const TYPE_URL: &'static str = "/cosmos.gov.v1.MsgVote";
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgVoteWeighted {
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+    #[prost(string, tag = "2")]
+    pub voter: String,
+    #[prost(message, repeated, tag = "3")]
+    pub options: Vec<WeightedVoteOption>,
+    #[prost(string, tag = "4")]
+    pub metadata: String,
+}
+
+impl TypeUrl for MsgVoteWeighted {
+    const TYPE_URL: &'static str = "/cosmos.gov.v1.MsgVoteWeighted";
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgDeposit {
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+    #[prost(string, tag = "2")]
+    pub depositor: String,
+    #[prost(message, repeated, tag = "3")]
+    pub amount: Vec<Coin>,
+}
+
+impl TypeUrl for MsgDeposit {
+    const TYPE_URL: &'static str = "/cosmos.gov.v1.MsgDeposit";
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryProposalRequest {
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryProposalResponse {
+    #[prost(message, optional, tag = "1")]
+    pub proposal: Option<Proposal>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryProposalsRequest {
+    #[prost(int32, tag = "1")]
+    pub proposal_status: i32,
+    #[prost(string, tag = "2")]
+    pub voter: String,
+    #[prost(string, tag = "3")]
+    pub depositor: String,
+    #[prost(message, optional, tag = "4")]
+    pub pagination: Option<PageRequest>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryProposalsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub proposals: Vec<Proposal>,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: Option<PageResponse>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryVoteRequest {
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+    #[prost(string, tag = "2")]
+    pub voter: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryVoteResponse {
+    #[prost(message, optional, tag = "1")]
+    pub vote: Option<Vote>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryVotesRequest {
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: Option<PageRequest>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryVotesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub votes: Vec<Vote>,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: Option<PageResponse>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryParamsRequest {
+    #[prost(string, tag = "1")]
+    pub params_type: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryParamsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub voting_params: Option<VotingParams>,
+    #[prost(message, optional, tag = "2")]
+    pub deposit_params: Option<DepositParams>,
+    #[prost(message, optional, tag = "3")]
+    pub tally_params: Option<TallyParams>,
+    #[prost(message, optional, tag = "4")]
+    pub params: Option<Params>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryDepositRequest {
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+    #[prost(string, tag = "2")]
+    pub depositor: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryDepositResponse {
+    #[prost(message, optional, tag = "1")]
+    pub deposit: Option<Deposit>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryDepositsRequest {
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: Option<PageRequest>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryDepositsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub deposits: Vec<Deposit>,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: Option<PageResponse>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryTallyResultRequest {
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryTallyResultResponse {
+    #[prost(message, optional, tag = "1")]
+    pub tally: Option<TallyResult>,
+}
@@ -0,0 +1,75 @@
+//! Gov v1beta1 msgs implementing `TypeUrl`, which the sdk protos we currently depend on are missing.
+//! The v1beta1 queries are still sent with the sdk protos.
+
+use cosmrs::proto::cosmos::base::v1beta1::Coin;
+use cosmrs::proto::cosmos::gov::v1beta1::WeightedVoteOption;
+use cosmrs::proto::traits::TypeUrl;
+use cosmrs::Any;
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct TextProposal {
+    #[prost(string, tag = "1")]
+    pub title: String,
+    #[prost(string, tag = "2")]
+    pub description: String,
+}
+
+impl TypeUrl for TextProposal {
+    const TYPE_URL: &'static str = "/cosmos.gov.v1beta1.TextProposal";
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgSubmitProposal {
+    #[prost(message, optional, tag = "1")]
+    pub content: Option<Any>,
+    #[prost(message, repeated, tag = "2")]
+    pub initial_deposit: Vec<Coin>,
+    #[prost(string, tag = "3")]
+    pub proposer: String,
+}
+
+impl TypeUrl for MsgSubmitProposal {
+    const TYPE_URL: &'static str = "/cosmos.gov.v1beta1.MsgSubmitProposal";
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgVote {
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+    #[prost(string, tag = "2")]
+    pub voter: String,
+    #[prost(int32, tag = "3")]
+    pub option: i32,
+}
+
+impl TypeUrl for MsgVote {
+    const TYPE_URL: &'static str = "/cosmos.gov.v1beta1.MsgVote";
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgVoteWeighted {
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+    #[prost(string, tag = "2")]
+    pub voter: String,
+    #[prost(message, repeated, tag = "3")]
+    pub options: Vec<WeightedVoteOption>,
+}
+
+impl TypeUrl for MsgVoteWeighted {
+    const TYPE_URL: &'static str = "/cosmos.gov.v1beta1.MsgVoteWeighted";
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgDeposit {
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+    #[prost(string, tag = "2")]
+    pub depositor: String,
+    #[prost(message, repeated, tag = "3")]
+    pub amount: Vec<Coin>,
+}
+
+impl TypeUrl for MsgDeposit {
+    const TYPE_URL: &'static str = "/cosmos.gov.v1beta1.MsgDeposit";
+}
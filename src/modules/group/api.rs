@@ -0,0 +1,555 @@
+use crate::{
+    chain::{
+        error::ChainError,
+        request::{PaginationRequest, TxOptions},
+        response::ChainTxResponse,
+    },
+    clients::client::{CosmTome, CosmosClient},
+    modules::auth::model::Address,
+    signing_key::key::SigningKey,
+};
+
+use super::{
+    error::GroupError,
+    model::{
+        CreateGroupPolicyRequest, CreateGroupPolicyResponse, CreateGroupRequest,
+        CreateGroupResponse, ExecRequest, ExecResponse, GroupPoliciesResponse, GroupPolicyResponse,
+        GroupResponse, GroupTxResponse, GroupsResponse, MembersResponse, ProposalExecutorResult,
+        ProposalResponse, ProposalsResponse, SubmitProposalRequest, SubmitProposalResponse,
+        TallyResponse, VoteRequest, VoteResponse, VotesResponse,
+    },
+    v1,
+};
+
+/// Group events are typed events, whose attribute values are JSON encoded (ie. `"\"1\""`)
+fn event_value(res: &ChainTxResponse, event: &str, key: &str) -> Result<String, GroupError> {
+    res.find_event_tags(event.to_string(), key.to_string())
+        .first()
+        .map(|t| t.value.trim_matches('"').to_string())
+        .ok_or_else(|| GroupError::MissingEvent {
+            event: event.to_string(),
+            key: key.to_string(),
+        })
+}
+
+fn parse_id(value: String) -> Result<u64, ChainError> {
+    value.parse().map_err(|_| ChainError::ProtoDecoding {
+        message: format!("invalid id in group event: {value}"),
+    })
+}
+
+impl<T: CosmosClient> CosmTome<T> {
+    /// Creates a group and returns its id
+    pub async fn group_create_group(
+        &self,
+        req: CreateGroupRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<CreateGroupResponse, GroupError> {
+        let res = self.tx_send(vec![req], key, tx_options).await?;
+
+        let group_id = parse_id(event_value(
+            &res,
+            "cosmos.group.v1.EventCreateGroup",
+            "group_id",
+        )?)?;
+
+        Ok(CreateGroupResponse { group_id, res })
+    }
+
+    /// Creates a group policy and returns its account address
+    pub async fn group_create_group_policy(
+        &self,
+        req: CreateGroupPolicyRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<CreateGroupPolicyResponse, GroupError> {
+        let res = self.tx_send(vec![req], key, tx_options).await?;
+
+        let address =
+            event_value(&res, "cosmos.group.v1.EventCreateGroupPolicy", "address")?.parse()?;
+
+        Ok(CreateGroupPolicyResponse { address, res })
+    }
+
+    /// Submits a group proposal and returns its id
+    pub async fn group_submit_proposal(
+        &self,
+        req: SubmitProposalRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<SubmitProposalResponse, GroupError> {
+        let res = self.tx_send(vec![req], key, tx_options).await?;
+
+        let proposal_id = parse_id(event_value(
+            &res,
+            "cosmos.group.v1.EventSubmitProposal",
+            "proposal_id",
+        )?)?;
+
+        Ok(SubmitProposalResponse { proposal_id, res })
+    }
+
+    pub async fn group_vote(
+        &self,
+        req: VoteRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<GroupTxResponse, GroupError> {
+        let res = self.tx_send(vec![req], key, tx_options).await?;
+
+        Ok(GroupTxResponse { res })
+    }
+
+    /// Executes the messages of an accepted proposal, with `key` as the executor.
+    ///
+    /// The tx succeeds even if the messages fail, check `ExecResponse::result`.
+    pub async fn group_exec(
+        &self,
+        proposal_id: u64,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<ExecResponse, GroupError> {
+        let req = ExecRequest {
+            proposal_id,
+            executor: key.to_addr(&self.cfg.prefix).await?,
+        };
+
+        let res = self.tx_send(vec![req], key, tx_options).await?;
+
+        let result = event_value(&res, "cosmos.group.v1.EventExec", "result")?;
+        let result = ProposalExecutorResult::from_event_value(&result).ok_or_else(|| {
+            ChainError::ProtoDecoding {
+                message: format!("invalid proposal executor result: {result}"),
+            }
+        })?;
+
+        Ok(ExecResponse { result, res })
+    }
+
+    pub async fn group_query_group(&self, group_id: u64) -> Result<GroupResponse, GroupError> {
+        let req = v1::QueryGroupInfoRequest { group_id };
+
+        let res = self
+            .client
+            .query::<_, v1::QueryGroupInfoResponse>(req, "/cosmos.group.v1.Query/GroupInfo")
+            .await?;
+
+        Ok(GroupResponse {
+            group: res.info.map(TryInto::try_into).transpose()?,
+        })
+    }
+
+    pub async fn group_query_groups_by_admin(
+        &self,
+        admin: Address,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<GroupsResponse, GroupError> {
+        self.group_query_groups(admin, pagination, "/cosmos.group.v1.Query/GroupsByAdmin")
+            .await
+    }
+
+    pub async fn group_query_groups_by_member(
+        &self,
+        member: Address,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<GroupsResponse, GroupError> {
+        self.group_query_groups(member, pagination, "/cosmos.group.v1.Query/GroupsByMember")
+            .await
+    }
+
+    async fn group_query_groups(
+        &self,
+        address: Address,
+        pagination: Option<PaginationRequest>,
+        path: &str,
+    ) -> Result<GroupsResponse, GroupError> {
+        let req = v1::QueryGroupsByAddressRequest {
+            address: address.into(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, v1::QueryGroupsResponse>(req, path)
+            .await?;
+
+        Ok(GroupsResponse {
+            groups: res
+                .groups
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    pub async fn group_query_members(
+        &self,
+        group_id: u64,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<MembersResponse, GroupError> {
+        let req = v1::QueryGroupMembersRequest {
+            group_id,
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, v1::QueryGroupMembersResponse>(req, "/cosmos.group.v1.Query/GroupMembers")
+            .await?;
+
+        Ok(MembersResponse {
+            members: res
+                .members
+                .into_iter()
+                .filter_map(|m| m.member)
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    pub async fn group_query_group_policy(
+        &self,
+        address: Address,
+    ) -> Result<GroupPolicyResponse, GroupError> {
+        let req = v1::QueryGroupPolicyInfoRequest {
+            address: address.into(),
+        };
+
+        let res = self
+            .client
+            .query::<_, v1::QueryGroupPolicyInfoResponse>(
+                req,
+                "/cosmos.group.v1.Query/GroupPolicyInfo",
+            )
+            .await?;
+
+        Ok(GroupPolicyResponse {
+            policy: res.info.map(TryInto::try_into).transpose()?,
+        })
+    }
+
+    pub async fn group_query_group_policies_by_group(
+        &self,
+        group_id: u64,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<GroupPoliciesResponse, GroupError> {
+        let req = v1::QueryGroupPoliciesByGroupRequest {
+            group_id,
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, v1::QueryGroupPoliciesResponse>(
+                req,
+                "/cosmos.group.v1.Query/GroupPoliciesByGroup",
+            )
+            .await?;
+
+        Ok(GroupPoliciesResponse {
+            policies: res
+                .group_policies
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    pub async fn group_query_group_policies_by_admin(
+        &self,
+        admin: Address,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<GroupPoliciesResponse, GroupError> {
+        let req = v1::QueryGroupPoliciesByAdminRequest {
+            admin: admin.into(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, v1::QueryGroupPoliciesResponse>(
+                req,
+                "/cosmos.group.v1.Query/GroupPoliciesByAdmin",
+            )
+            .await?;
+
+        Ok(GroupPoliciesResponse {
+            policies: res
+                .group_policies
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    pub async fn group_query_proposal(
+        &self,
+        proposal_id: u64,
+    ) -> Result<ProposalResponse, GroupError> {
+        let req = v1::QueryProposalRequest { proposal_id };
+
+        let res = self
+            .client
+            .query::<_, v1::QueryProposalResponse>(req, "/cosmos.group.v1.Query/Proposal")
+            .await?;
+
+        Ok(ProposalResponse {
+            proposal: res.proposal.map(TryInto::try_into).transpose()?,
+        })
+    }
+
+    pub async fn group_query_proposals_by_group_policy(
+        &self,
+        address: Address,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<ProposalsResponse, GroupError> {
+        let req = v1::QueryProposalsByGroupPolicyRequest {
+            address: address.into(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, v1::QueryProposalsByGroupPolicyResponse>(
+                req,
+                "/cosmos.group.v1.Query/ProposalsByGroupPolicy",
+            )
+            .await?;
+
+        Ok(ProposalsResponse {
+            proposals: res
+                .proposals
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    pub async fn group_query_vote(
+        &self,
+        proposal_id: u64,
+        voter: Address,
+    ) -> Result<VoteResponse, GroupError> {
+        let req = v1::QueryVoteByProposalVoterRequest {
+            proposal_id,
+            voter: voter.into(),
+        };
+
+        let res = self
+            .client
+            .query::<_, v1::QueryVoteByProposalVoterResponse>(
+                req,
+                "/cosmos.group.v1.Query/VoteByProposalVoter",
+            )
+            .await?;
+
+        Ok(VoteResponse {
+            vote: res.vote.map(TryInto::try_into).transpose()?,
+        })
+    }
+
+    pub async fn group_query_votes_by_proposal(
+        &self,
+        proposal_id: u64,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<VotesResponse, GroupError> {
+        let req = v1::QueryVotesByProposalRequest {
+            proposal_id,
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, v1::QueryVotesResponse>(req, "/cosmos.group.v1.Query/VotesByProposal")
+            .await?;
+
+        Ok(VotesResponse {
+            votes: res
+                .votes
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    pub async fn group_query_votes_by_voter(
+        &self,
+        voter: Address,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<VotesResponse, GroupError> {
+        let req = v1::QueryVotesByVoterRequest {
+            voter: voter.into(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, v1::QueryVotesResponse>(req, "/cosmos.group.v1.Query/VotesByVoter")
+            .await?;
+
+        Ok(VotesResponse {
+            votes: res
+                .votes
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query the current tally of a proposal that is still being voted on
+    pub async fn group_query_tally(&self, proposal_id: u64) -> Result<TallyResponse, GroupError> {
+        let req = v1::QueryTallyResultRequest { proposal_id };
+
+        let res = self
+            .client
+            .query::<_, v1::QueryTallyResultResponse>(req, "/cosmos.group.v1.Query/TallyResult")
+            .await?;
+
+        Ok(TallyResponse {
+            tally: res.tally.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use cosmrs::proto::cosmos::auth::v1beta1::{
+        BaseAccount, QueryAccountRequest, QueryAccountResponse,
+    };
+    use cosmrs::proto::traits::{Message, MessageExt};
+
+    use crate::{
+        chain::{
+            coin::Coin,
+            fee::GasInfo,
+            msg::Msg,
+            request::TxOptions,
+            response::{ChainResponse, ChainTxResponse, Code, Event, Tag},
+        },
+        clients::client::{CosmTome, MockCosmosClient},
+        modules::{
+            auth::model::{Address, BASE_ACCOUNT_TYPE_URL},
+            bank::model::SendRequest,
+            group::{model::SubmitProposalRequest, v1},
+            tx::model::RawTx,
+        },
+        signing_key::key::SigningKey,
+        test_utils::test_cfg,
+    };
+
+    #[tokio::test]
+    async fn test_group_submit_proposal() {
+        let cfg = test_cfg();
+        let tx_options = TxOptions::default();
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
+        let proposer = key.to_addr(&cfg.prefix).await.unwrap();
+
+        let policy: Address = "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+            .parse()
+            .unwrap();
+
+        let mut mock_client = MockCosmosClient::new();
+
+        let account_addr = proposer.to_string();
+        mock_client
+            .expect_query::<QueryAccountRequest, QueryAccountResponse>()
+            .times(1)
+            .returning(move |_, _| {
+                Ok(QueryAccountResponse {
+                    account: Some(cosmrs::proto::Any {
+                        type_url: BASE_ACCOUNT_TYPE_URL.to_string(),
+                        value: BaseAccount {
+                            address: account_addr.clone(),
+                            pub_key: None,
+                            account_number: 1337,
+                            sequence: 1,
+                        }
+                        .to_bytes()
+                        .unwrap(),
+                    }),
+                })
+            });
+
+        mock_client.expect_simulate_tx().times(1).returning(|_| {
+            Ok(GasInfo {
+                gas_wanted: 200u16.into(),
+                gas_used: 100u16.into(),
+            })
+        });
+
+        let expected_policy = policy.to_string();
+        let expected_proposer = proposer.to_string();
+        mock_client
+            .expect_broadcast_tx_block()
+            .times(1)
+            .withf(move |tx: &RawTx| {
+                let tx = cosmrs::Tx::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+                let msg =
+                    v1::MsgSubmitProposal::decode(tx.body.messages[0].value.as_slice()).unwrap();
+
+                tx.body.messages[0].type_url == "/cosmos.group.v1.MsgSubmitProposal"
+                    && msg.group_policy_address == expected_policy
+                    && msg.proposers == vec![expected_proposer.clone()]
+                    && msg.messages[0].type_url == "/cosmos.bank.v1beta1.MsgSend"
+                    && msg.exec == v1::Exec::Try as i32
+            })
+            .returning(|_| {
+                Ok(ChainTxResponse {
+                    res: ChainResponse {
+                        code: Code::Ok,
+                        codespace: String::new(),
+                        data: None,
+                        log: "log log log".to_string(),
+                    },
+                    events: vec![Event {
+                        type_str: "cosmos.group.v1.EventSubmitProposal".to_string(),
+                        attributes: vec![Tag {
+                            key: "proposal_id".to_string(),
+                            value: "\"4\"".to_string(),
+                        }],
+                    }],
+                    gas_wanted: 200,
+                    gas_used: 100,
+                    tx_hash: "TX_HASH_0".to_string(),
+                    height: 1337,
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg.clone(), mock_client);
+
+        let send = SendRequest {
+            from: policy.clone(),
+            to: proposer.clone(),
+            amounts: vec![Coin {
+                denom: cfg.denom.parse().unwrap(),
+                amount: 10,
+            }],
+        };
+
+        let req = SubmitProposalRequest {
+            group_policy_address: policy,
+            proposers: vec![proposer],
+            metadata: String::new(),
+            messages: vec![send.into_any().unwrap()],
+            exec: true,
+            title: String::new(),
+            summary: String::new(),
+        };
+
+        let res = cosm_tome
+            .group_submit_proposal(req, &key, &tx_options)
+            .await
+            .unwrap();
+
+        assert_eq!(res.proposal_id, 4);
+    }
+}
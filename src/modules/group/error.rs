@@ -0,0 +1,33 @@
+use thiserror::Error;
+
+use crate::{
+    chain::error::ChainError,
+    modules::{auth::error::AccountError, tx::error::TxError},
+};
+
+#[derive(Error, Debug)]
+pub enum GroupError {
+    #[error("Invalid proposal status: {i}")]
+    ProposalStatus { i: i32 },
+
+    #[error("Invalid proposal executor result: {i}")]
+    ExecutorResult { i: i32 },
+
+    #[error("Invalid vote option: {i}")]
+    VoteOption { i: i32 },
+
+    #[error("Group policy is missing its decision policy")]
+    MissingDecisionPolicy,
+
+    #[error("Missing {key} in {event} event")]
+    MissingEvent { event: String, key: String },
+
+    #[error(transparent)]
+    TxError(#[from] TxError),
+
+    #[error(transparent)]
+    AccountError(#[from] AccountError),
+
+    #[error(transparent)]
+    ChainError(#[from] ChainError),
+}
@@ -0,0 +1,4 @@
+pub mod api;
+pub mod error;
+pub mod model;
+pub mod v1;
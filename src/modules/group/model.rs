@@ -0,0 +1,844 @@
+use std::time::Duration;
+
+use cosmrs::proto::traits::{Message, TypeUrl};
+use cosmrs::tx::MessageExt;
+use cosmrs::{tendermint::Time, Any};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::chain::coin::Dec;
+use crate::chain::error::ChainError;
+use crate::chain::msg::Msg;
+use crate::chain::request::PaginationResponse;
+use crate::chain::response::ChainTxResponse;
+use crate::chain::time::{duration_from_proto, duration_to_proto, time_from_proto};
+use crate::modules::auth::model::Address;
+use crate::modules::tx::model::any_msgs;
+
+use super::error::GroupError;
+use super::v1;
+
+/// Group votes use the same options as gov votes
+pub use crate::modules::gov::model::VoteOption;
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct GroupTxResponse {
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct CreateGroupResponse {
+    pub group_id: u64,
+
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct CreateGroupPolicyResponse {
+    /// Account of the group policy, which holds the funds and signs the messages of its proposals
+    pub address: Address,
+
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct SubmitProposalResponse {
+    pub proposal_id: u64,
+
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ExecResponse {
+    /// Whether the proposal messages were executed successfully
+    pub result: ProposalExecutorResult,
+
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct GroupResponse {
+    pub group: Option<GroupInfo>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct GroupsResponse {
+    pub groups: Vec<GroupInfo>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct MembersResponse {
+    pub members: Vec<Member>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct GroupPolicyResponse {
+    pub policy: Option<GroupPolicyInfo>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct GroupPoliciesResponse {
+    pub policies: Vec<GroupPolicyInfo>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ProposalResponse {
+    pub proposal: Option<Proposal>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ProposalsResponse {
+    pub proposals: Vec<Proposal>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct VoteResponse {
+    pub vote: Option<Vote>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct VotesResponse {
+    pub votes: Vec<Vote>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct TallyResponse {
+    pub tally: Option<TallyResult>,
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub enum ProposalStatus {
+    Unspecified,
+    Submitted,
+    Accepted,
+    Rejected,
+    Aborted,
+    Withdrawn,
+}
+
+impl TryFrom<i32> for ProposalStatus {
+    type Error = GroupError;
+
+    fn try_from(i: i32) -> Result<Self, Self::Error> {
+        match v1::ProposalStatus::from_i32(i) {
+            Some(v1::ProposalStatus::Unspecified) => Ok(ProposalStatus::Unspecified),
+            Some(v1::ProposalStatus::Submitted) => Ok(ProposalStatus::Submitted),
+            Some(v1::ProposalStatus::Accepted) => Ok(ProposalStatus::Accepted),
+            Some(v1::ProposalStatus::Rejected) => Ok(ProposalStatus::Rejected),
+            Some(v1::ProposalStatus::Aborted) => Ok(ProposalStatus::Aborted),
+            Some(v1::ProposalStatus::Withdrawn) => Ok(ProposalStatus::Withdrawn),
+            None => Err(GroupError::ProposalStatus { i }),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub enum ProposalExecutorResult {
+    Unspecified,
+    NotRun,
+    Success,
+    Failure,
+}
+
+impl TryFrom<i32> for ProposalExecutorResult {
+    type Error = GroupError;
+
+    fn try_from(i: i32) -> Result<Self, Self::Error> {
+        match v1::ProposalExecutorResult::from_i32(i) {
+            Some(v1::ProposalExecutorResult::Unspecified) => {
+                Ok(ProposalExecutorResult::Unspecified)
+            }
+            Some(v1::ProposalExecutorResult::NotRun) => Ok(ProposalExecutorResult::NotRun),
+            Some(v1::ProposalExecutorResult::Success) => Ok(ProposalExecutorResult::Success),
+            Some(v1::ProposalExecutorResult::Failure) => Ok(ProposalExecutorResult::Failure),
+            None => Err(GroupError::ExecutorResult { i }),
+        }
+    }
+}
+
+impl ProposalExecutorResult {
+    /// Parses the proto enum name used in `EventExec`, ie. `PROPOSAL_EXECUTOR_RESULT_SUCCESS`
+    pub(crate) fn from_event_value(value: &str) -> Option<Self> {
+        match value.trim_start_matches("PROPOSAL_EXECUTOR_RESULT_") {
+            "UNSPECIFIED" => Some(ProposalExecutorResult::Unspecified),
+            "NOT_RUN" => Some(ProposalExecutorResult::NotRun),
+            "SUCCESS" => Some(ProposalExecutorResult::Success),
+            "FAILURE" => Some(ProposalExecutorResult::Failure),
+            _ => None,
+        }
+    }
+}
+
+fn vote_option(i: i32) -> Result<VoteOption, GroupError> {
+    i.try_into().map_err(|_| GroupError::VoteOption { i })
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct GroupInfo {
+    pub id: u64,
+    pub admin: Address,
+    pub metadata: String,
+
+    /// Incremented every time the group or its members are updated
+    pub version: u64,
+
+    /// Sum of the weights of all members
+    pub total_weight: Dec,
+
+    pub created_at: Option<Time>,
+}
+
+impl TryFrom<v1::GroupInfo> for GroupInfo {
+    type Error = GroupError;
+
+    fn try_from(g: v1::GroupInfo) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: g.id,
+            admin: g.admin.parse()?,
+            metadata: g.metadata,
+            version: g.version,
+            total_weight: g.total_weight.parse()?,
+            created_at: g.created_at.map(time_from_proto).transpose()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct Member {
+    pub address: Address,
+    pub weight: Dec,
+    pub metadata: String,
+    pub added_at: Option<Time>,
+}
+
+impl TryFrom<v1::Member> for Member {
+    type Error = GroupError;
+
+    fn try_from(m: v1::Member) -> Result<Self, Self::Error> {
+        Ok(Self {
+            address: m.address.parse()?,
+            weight: m.weight.parse()?,
+            metadata: m.metadata,
+            added_at: m.added_at.map(time_from_proto).transpose()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct MemberRequest {
+    pub address: Address,
+    pub weight: Dec,
+    pub metadata: String,
+}
+
+impl TryFrom<v1::MemberRequest> for MemberRequest {
+    type Error = GroupError;
+
+    fn try_from(m: v1::MemberRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            address: m.address.parse()?,
+            weight: m.weight.parse()?,
+            metadata: m.metadata,
+        })
+    }
+}
+
+impl From<MemberRequest> for v1::MemberRequest {
+    fn from(m: MemberRequest) -> Self {
+        Self {
+            address: m.address.into(),
+            weight: m.weight.to_string(),
+            metadata: m.metadata,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct DecisionPolicyWindows {
+    /// How long members can vote once a proposal is submitted
+    pub voting_period: Duration,
+
+    /// Minimum time after submission before a proposal can be executed
+    pub min_execution_period: Duration,
+}
+
+impl TryFrom<v1::DecisionPolicyWindows> for DecisionPolicyWindows {
+    type Error = ChainError;
+
+    fn try_from(w: v1::DecisionPolicyWindows) -> Result<Self, Self::Error> {
+        Ok(Self {
+            voting_period: w
+                .voting_period
+                .map(duration_from_proto)
+                .transpose()?
+                .unwrap_or_default(),
+            min_execution_period: w
+                .min_execution_period
+                .map(duration_from_proto)
+                .transpose()?
+                .unwrap_or_default(),
+        })
+    }
+}
+
+impl From<DecisionPolicyWindows> for v1::DecisionPolicyWindows {
+    fn from(w: DecisionPolicyWindows) -> Self {
+        Self {
+            voting_period: Some(duration_to_proto(w.voting_period)),
+            min_execution_period: Some(duration_to_proto(w.min_execution_period)),
+        }
+    }
+}
+
+/// Rules deciding whether a group policy proposal passes
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub enum DecisionPolicy {
+    /// Passes once the weight of the yes votes reaches `threshold`,
+    /// or the total weight of the group if it is lower
+    Threshold {
+        threshold: Dec,
+        windows: DecisionPolicyWindows,
+    },
+
+    /// Passes once the weight of the yes votes reaches `percentage` of the total weight of the group
+    Percentage {
+        percentage: Dec,
+        windows: DecisionPolicyWindows,
+    },
+
+    /// Decision policy type unknown to cosm-tome
+    Other { type_url: String, value: Vec<u8> },
+}
+
+impl TryFrom<Any> for DecisionPolicy {
+    type Error = GroupError;
+
+    fn try_from(any: Any) -> Result<Self, Self::Error> {
+        let policy = match any.type_url.as_str() {
+            v1::ThresholdDecisionPolicy::TYPE_URL => {
+                let p = v1::ThresholdDecisionPolicy::decode(any.value.as_slice())
+                    .map_err(ChainError::prost_proto_decoding)?;
+
+                DecisionPolicy::Threshold {
+                    threshold: p.threshold.parse()?,
+                    windows: p.windows.unwrap_or_default().try_into()?,
+                }
+            }
+            v1::PercentageDecisionPolicy::TYPE_URL => {
+                let p = v1::PercentageDecisionPolicy::decode(any.value.as_slice())
+                    .map_err(ChainError::prost_proto_decoding)?;
+
+                DecisionPolicy::Percentage {
+                    percentage: p.percentage.parse()?,
+                    windows: p.windows.unwrap_or_default().try_into()?,
+                }
+            }
+            _ => DecisionPolicy::Other {
+                type_url: any.type_url,
+                value: any.value,
+            },
+        };
+
+        Ok(policy)
+    }
+}
+
+impl TryFrom<DecisionPolicy> for Any {
+    type Error = GroupError;
+
+    fn try_from(p: DecisionPolicy) -> Result<Self, Self::Error> {
+        let any = match p {
+            DecisionPolicy::Threshold { threshold, windows } => v1::ThresholdDecisionPolicy {
+                threshold: threshold.to_string(),
+                windows: Some(windows.into()),
+            }
+            .to_any(),
+            DecisionPolicy::Percentage {
+                percentage,
+                windows,
+            } => v1::PercentageDecisionPolicy {
+                percentage: percentage.to_string(),
+                windows: Some(windows.into()),
+            }
+            .to_any(),
+            DecisionPolicy::Other { type_url, value } => return Ok(Any { type_url, value }),
+        };
+
+        Ok(any.map_err(ChainError::prost_proto_encoding)?)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct GroupPolicyInfo {
+    /// Account of the group policy
+    pub address: Address,
+
+    pub group_id: u64,
+    pub admin: Address,
+    pub metadata: String,
+
+    /// Incremented every time the group policy is updated
+    pub version: u64,
+
+    pub decision_policy: DecisionPolicy,
+    pub created_at: Option<Time>,
+}
+
+impl TryFrom<v1::GroupPolicyInfo> for GroupPolicyInfo {
+    type Error = GroupError;
+
+    fn try_from(p: v1::GroupPolicyInfo) -> Result<Self, Self::Error> {
+        Ok(Self {
+            address: p.address.parse()?,
+            group_id: p.group_id,
+            admin: p.admin.parse()?,
+            metadata: p.metadata,
+            version: p.version,
+            decision_policy: p
+                .decision_policy
+                .ok_or(GroupError::MissingDecisionPolicy)?
+                .try_into()?,
+            created_at: p.created_at.map(time_from_proto).transpose()?,
+        })
+    }
+}
+
+/// Sum of the weights of the members who voted for each option
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq, Hash)]
+pub struct TallyResult {
+    pub yes: Dec,
+    pub abstain: Dec,
+    pub no: Dec,
+    pub no_with_veto: Dec,
+}
+
+impl TryFrom<v1::TallyResult> for TallyResult {
+    type Error = ChainError;
+
+    fn try_from(t: v1::TallyResult) -> Result<Self, Self::Error> {
+        Ok(Self {
+            yes: t.yes_count.parse()?,
+            abstain: t.abstain_count.parse()?,
+            no: t.no_count.parse()?,
+            no_with_veto: t.no_with_veto_count.parse()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Proposal {
+    pub id: u64,
+    pub group_policy_address: Address,
+    pub metadata: String,
+    pub proposers: Vec<Address>,
+    pub submit_time: Option<Time>,
+
+    /// Group version at submission, the proposal is aborted if the group is updated before it is finalized
+    pub group_version: u64,
+
+    /// Group policy version at submission, the proposal is aborted if the policy is updated before it is finalized
+    pub group_policy_version: u64,
+
+    pub status: ProposalStatus,
+
+    /// Only set once voting is over, use `group_query_tally()` while the proposal is `Submitted`
+    pub final_tally_result: Option<TallyResult>,
+
+    pub voting_period_end: Option<Time>,
+    pub executor_result: ProposalExecutorResult,
+
+    /// Messages executed by the group policy account if the proposal passes
+    #[serde(with = "any_msgs")]
+    pub messages: Vec<Any>,
+
+    /// Always empty before cosmos-sdk 0.47
+    pub title: String,
+
+    /// Always empty before cosmos-sdk 0.47
+    pub summary: String,
+}
+
+impl TryFrom<v1::Proposal> for Proposal {
+    type Error = GroupError;
+
+    fn try_from(p: v1::Proposal) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: p.id,
+            group_policy_address: p.group_policy_address.parse()?,
+            metadata: p.metadata,
+            proposers: p
+                .proposers
+                .into_iter()
+                .map(|p| p.parse())
+                .collect::<Result<Vec<_>, _>>()?,
+            submit_time: p.submit_time.map(time_from_proto).transpose()?,
+            group_version: p.group_version,
+            group_policy_version: p.group_policy_version,
+            status: p.status.try_into()?,
+            final_tally_result: p.final_tally_result.map(TryInto::try_into).transpose()?,
+            voting_period_end: p.voting_period_end.map(time_from_proto).transpose()?,
+            executor_result: p.executor_result.try_into()?,
+            messages: p.messages,
+            title: p.title,
+            summary: p.summary,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct Vote {
+    pub proposal_id: u64,
+    pub voter: Address,
+    pub option: VoteOption,
+    pub metadata: String,
+    pub submit_time: Option<Time>,
+}
+
+impl TryFrom<v1::Vote> for Vote {
+    type Error = GroupError;
+
+    fn try_from(v: v1::Vote) -> Result<Self, Self::Error> {
+        Ok(Self {
+            proposal_id: v.proposal_id,
+            voter: v.voter.parse()?,
+            option: vote_option(v.option)?,
+            metadata: v.metadata,
+            submit_time: v.submit_time.map(time_from_proto).transpose()?,
+        })
+    }
+}
+
+/// Creates a group administered by `admin`, with an initial set of weighted members
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct CreateGroupRequest {
+    pub admin: Address,
+    pub members: Vec<MemberRequest>,
+    pub metadata: String,
+}
+
+impl Msg for CreateGroupRequest {
+    type Proto = v1::MsgCreateGroup;
+    type Err = GroupError;
+}
+
+impl TryFrom<v1::MsgCreateGroup> for CreateGroupRequest {
+    type Error = GroupError;
+
+    fn try_from(msg: v1::MsgCreateGroup) -> Result<Self, Self::Error> {
+        Ok(Self {
+            admin: msg.admin.parse()?,
+            members: msg
+                .members
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            metadata: msg.metadata,
+        })
+    }
+}
+
+impl TryFrom<CreateGroupRequest> for v1::MsgCreateGroup {
+    type Error = GroupError;
+
+    fn try_from(req: CreateGroupRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            admin: req.admin.into(),
+            members: req.members.into_iter().map(Into::into).collect(),
+            metadata: req.metadata,
+        })
+    }
+}
+
+/// Creates a group policy account for `group_id`, whose proposals are decided by `decision_policy`.
+/// `admin` must be the group admin.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct CreateGroupPolicyRequest {
+    pub admin: Address,
+    pub group_id: u64,
+    pub metadata: String,
+    pub decision_policy: DecisionPolicy,
+}
+
+impl Msg for CreateGroupPolicyRequest {
+    type Proto = v1::MsgCreateGroupPolicy;
+    type Err = GroupError;
+}
+
+impl TryFrom<v1::MsgCreateGroupPolicy> for CreateGroupPolicyRequest {
+    type Error = GroupError;
+
+    fn try_from(msg: v1::MsgCreateGroupPolicy) -> Result<Self, Self::Error> {
+        Ok(Self {
+            admin: msg.admin.parse()?,
+            group_id: msg.group_id,
+            metadata: msg.metadata,
+            decision_policy: msg
+                .decision_policy
+                .ok_or(GroupError::MissingDecisionPolicy)?
+                .try_into()?,
+        })
+    }
+}
+
+impl TryFrom<CreateGroupPolicyRequest> for v1::MsgCreateGroupPolicy {
+    type Error = GroupError;
+
+    fn try_from(req: CreateGroupPolicyRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            admin: req.admin.into(),
+            group_id: req.group_id,
+            metadata: req.metadata,
+            decision_policy: Some(req.decision_policy.try_into()?),
+        })
+    }
+}
+
+/// Submits a proposal to execute `messages` from the group policy account.
+///
+/// The messages must be built with the group policy as their signer, ie. `SendRequest { from: group_policy_address, .. }`,
+/// and can be converted with `Msg::into_any()`. `proposers` must be group members and sign the tx.
+/// If `exec` is set, the proposal is executed right away if the proposers' votes are enough for it to pass.
+/// `title` and `summary` were added in cosmos-sdk 0.47, leave them empty for chains running cosmos-sdk 0.46.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct SubmitProposalRequest {
+    pub group_policy_address: Address,
+    pub proposers: Vec<Address>,
+    pub metadata: String,
+    #[serde(with = "any_msgs")]
+    pub messages: Vec<Any>,
+    pub exec: bool,
+    pub title: String,
+    pub summary: String,
+}
+
+fn exec(try_exec: bool) -> i32 {
+    if try_exec {
+        v1::Exec::Try as i32
+    } else {
+        v1::Exec::Unspecified as i32
+    }
+}
+
+impl Msg for SubmitProposalRequest {
+    type Proto = v1::MsgSubmitProposal;
+    type Err = GroupError;
+}
+
+impl TryFrom<v1::MsgSubmitProposal> for SubmitProposalRequest {
+    type Error = GroupError;
+
+    fn try_from(msg: v1::MsgSubmitProposal) -> Result<Self, Self::Error> {
+        Ok(Self {
+            group_policy_address: msg.group_policy_address.parse()?,
+            proposers: msg
+                .proposers
+                .into_iter()
+                .map(|p| p.parse())
+                .collect::<Result<Vec<_>, _>>()?,
+            metadata: msg.metadata,
+            messages: msg.messages,
+            exec: msg.exec == v1::Exec::Try as i32,
+            title: msg.title,
+            summary: msg.summary,
+        })
+    }
+}
+
+impl TryFrom<SubmitProposalRequest> for v1::MsgSubmitProposal {
+    type Error = GroupError;
+
+    fn try_from(req: SubmitProposalRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            group_policy_address: req.group_policy_address.into(),
+            proposers: req.proposers.into_iter().map(Into::into).collect(),
+            metadata: req.metadata,
+            messages: req.messages,
+            exec: exec(req.exec),
+            title: req.title,
+            summary: req.summary,
+        })
+    }
+}
+
+/// If `exec` is set, the proposal is executed right away if this vote makes it pass
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct VoteRequest {
+    pub proposal_id: u64,
+    pub voter: Address,
+    pub option: VoteOption,
+    pub metadata: String,
+    pub exec: bool,
+}
+
+impl Msg for VoteRequest {
+    type Proto = v1::MsgVote;
+    type Err = GroupError;
+}
+
+impl TryFrom<v1::MsgVote> for VoteRequest {
+    type Error = GroupError;
+
+    fn try_from(msg: v1::MsgVote) -> Result<Self, Self::Error> {
+        Ok(Self {
+            proposal_id: msg.proposal_id,
+            voter: msg.voter.parse()?,
+            option: vote_option(msg.option)?,
+            metadata: msg.metadata,
+            exec: msg.exec == v1::Exec::Try as i32,
+        })
+    }
+}
+
+impl TryFrom<VoteRequest> for v1::MsgVote {
+    type Error = GroupError;
+
+    fn try_from(req: VoteRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            proposal_id: req.proposal_id,
+            voter: req.voter.into(),
+            option: req.option.into(),
+            metadata: req.metadata,
+            exec: exec(req.exec),
+        })
+    }
+}
+
+/// Executes the messages of the accepted proposal `proposal_id`, signed by `executor`
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ExecRequest {
+    pub proposal_id: u64,
+    pub executor: Address,
+}
+
+impl Msg for ExecRequest {
+    type Proto = v1::MsgExec;
+    type Err = GroupError;
+}
+
+impl TryFrom<v1::MsgExec> for ExecRequest {
+    type Error = GroupError;
+
+    fn try_from(msg: v1::MsgExec) -> Result<Self, Self::Error> {
+        Ok(Self {
+            proposal_id: msg.proposal_id,
+            executor: msg.executor.parse()?,
+        })
+    }
+}
+
+impl TryFrom<ExecRequest> for v1::MsgExec {
+    type Error = GroupError;
+
+    fn try_from(req: ExecRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            proposal_id: req.proposal_id,
+            executor: req.executor.into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::chain::msg::Msg;
+    use crate::modules::auth::model::Address;
+
+    use super::*;
+
+    fn addr(s: &str) -> Address {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_group_msgs_any_roundtrip() {
+        let admin = addr("juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg");
+        let member = addr("juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea");
+
+        let create = CreateGroupRequest {
+            admin: admin.clone(),
+            members: vec![MemberRequest {
+                address: member.clone(),
+                weight: "1".parse().unwrap(),
+                metadata: "member".to_string(),
+            }],
+            metadata: "group".to_string(),
+        };
+        let any = create.to_any().unwrap();
+        assert_eq!(any.type_url, "/cosmos.group.v1.MsgCreateGroup");
+        assert_eq!(CreateGroupRequest::from_any(&any).unwrap(), create);
+
+        let policy = CreateGroupPolicyRequest {
+            admin: admin.clone(),
+            group_id: 1,
+            metadata: String::new(),
+            decision_policy: DecisionPolicy::Threshold {
+                threshold: "1".parse().unwrap(),
+                windows: DecisionPolicyWindows {
+                    voting_period: Duration::from_secs(60),
+                    min_execution_period: Duration::ZERO,
+                },
+            },
+        };
+        let any = policy.to_any().unwrap();
+        assert_eq!(any.type_url, "/cosmos.group.v1.MsgCreateGroupPolicy");
+        assert_eq!(CreateGroupPolicyRequest::from_any(&any).unwrap(), policy);
+
+        let proposal = SubmitProposalRequest {
+            group_policy_address: member.clone(),
+            proposers: vec![admin.clone()],
+            metadata: String::new(),
+            messages: vec![],
+            exec: true,
+            title: "title".to_string(),
+            summary: "summary".to_string(),
+        };
+        let any = proposal.to_any().unwrap();
+        assert_eq!(any.type_url, "/cosmos.group.v1.MsgSubmitProposal");
+        assert_eq!(SubmitProposalRequest::from_any(&any).unwrap(), proposal);
+
+        let vote = VoteRequest {
+            proposal_id: 4,
+            voter: admin.clone(),
+            option: VoteOption::Yes,
+            metadata: String::new(),
+            exec: false,
+        };
+        let any = vote.to_any().unwrap();
+        assert_eq!(any.type_url, "/cosmos.group.v1.MsgVote");
+        assert_eq!(VoteRequest::from_any(&any).unwrap(), vote);
+
+        let exec = ExecRequest {
+            proposal_id: 4,
+            executor: admin,
+        };
+        let any = exec.to_any().unwrap();
+        assert_eq!(any.type_url, "/cosmos.group.v1.MsgExec");
+        assert_eq!(ExecRequest::from_any(&any).unwrap(), exec);
+    }
+
+    #[test]
+    fn test_create_group_policy_missing_decision_policy() {
+        let msg = v1::MsgCreateGroupPolicy {
+            admin: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+            group_id: 1,
+            metadata: String::new(),
+            decision_policy: None,
+        };
+
+        assert!(matches!(
+            CreateGroupPolicyRequest::try_from(msg),
+            Err(GroupError::MissingDecisionPolicy)
+        ));
+    }
+}
@@ -0,0 +1,1163 @@
+use std::time::Instant;
+
+use cosmrs::proto::cosmos::tx::v1beta1::{GetTxsEventRequest, GetTxsEventResponse};
+use cosmrs::proto::ibc::core::channel::v1::{
+    QueryChannelClientStateRequest, QueryChannelClientStateResponse, QueryChannelRequest,
+    QueryChannelResponse, QueryChannelsRequest, QueryChannelsResponse,
+    QueryConnectionChannelsRequest, QueryConnectionChannelsResponse,
+    QueryNextSequenceReceiveRequest, QueryNextSequenceReceiveResponse,
+    QueryPacketAcknowledgementRequest, QueryPacketAcknowledgementResponse,
+    QueryPacketAcknowledgementsRequest, QueryPacketAcknowledgementsResponse,
+    QueryPacketCommitmentRequest, QueryPacketCommitmentResponse, QueryPacketCommitmentsRequest,
+    QueryPacketCommitmentsResponse, QueryPacketReceiptRequest, QueryPacketReceiptResponse,
+    QueryUnreceivedAcksRequest, QueryUnreceivedAcksResponse, QueryUnreceivedPacketsRequest,
+    QueryUnreceivedPacketsResponse,
+};
+use cosmrs::proto::ibc::core::client::v1::{
+    QueryClientStateRequest, QueryClientStateResponse, QueryClientStatesRequest,
+    QueryClientStatesResponse, QueryConsensusStateRequest, QueryConsensusStateResponse,
+    QueryConsensusStatesRequest, QueryConsensusStatesResponse,
+};
+use cosmrs::proto::ibc::core::connection::v1::{
+    QueryConnectionRequest, QueryConnectionResponse, QueryConnectionsRequest,
+    QueryConnectionsResponse,
+};
+use cosmrs::tendermint::Time;
+
+use crate::{
+    chain::{error::ChainError, request::PaginationRequest, response::ChainTxResponse},
+    clients::client::{CosmTome, CosmosClient},
+    modules::tendermint::error::TendermintError,
+};
+
+use super::{
+    error::IbcCoreError,
+    model::{
+        Acknowledgement, ChannelClientStateResponse, ChannelResponse, ChannelsResponse,
+        ClientStateResponse, ClientStatesResponse, ConnectionResponse, ConnectionsResponse,
+        ConsensusStateResponse, ConsensusStatesResponse, Height, NextSequenceReceiveResponse,
+        PacketAcknowledgementResponse, PacketCommitmentResponse, PacketLifecycle, PacketOutcome,
+        PacketPollOptions, PacketReceiptResponse, PacketStatesResponse, SentPacket,
+        SequencesResponse, WRITE_ACKNOWLEDGEMENT_EVENT,
+    },
+};
+
+impl<T: CosmosClient> CosmTome<T> {
+    pub async fn ibc_core_query_client_state(
+        &self,
+        client_id: &str,
+    ) -> Result<ClientStateResponse, IbcCoreError> {
+        let req = QueryClientStateRequest {
+            client_id: client_id.to_string(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryClientStateResponse>(req, "/ibc.core.client.v1.Query/ClientState")
+            .await?;
+
+        Ok(ClientStateResponse {
+            client_state: res.client_state.map(TryInto::try_into).transpose()?,
+        })
+    }
+
+    pub async fn ibc_core_query_client_states(
+        &self,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<ClientStatesResponse, IbcCoreError> {
+        let req = QueryClientStatesRequest {
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryClientStatesResponse>(req, "/ibc.core.client.v1.Query/ClientStates")
+            .await?;
+
+        Ok(ClientStatesResponse {
+            client_states: res
+                .client_states
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query the consensus state of `client_id` at `height`, or at its latest height if `None`
+    pub async fn ibc_core_query_consensus_state(
+        &self,
+        client_id: &str,
+        height: Option<Height>,
+    ) -> Result<ConsensusStateResponse, IbcCoreError> {
+        let req = QueryConsensusStateRequest {
+            client_id: client_id.to_string(),
+            revision_number: height.map(|h| h.revision_number).unwrap_or_default(),
+            revision_height: height.map(|h| h.revision_height).unwrap_or_default(),
+            latest_height: height.is_none(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryConsensusStateResponse>(
+                req,
+                "/ibc.core.client.v1.Query/ConsensusState",
+            )
+            .await?;
+
+        Ok(ConsensusStateResponse {
+            consensus_state: res.consensus_state.map(TryInto::try_into).transpose()?,
+        })
+    }
+
+    pub async fn ibc_core_query_consensus_states(
+        &self,
+        client_id: &str,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<ConsensusStatesResponse, IbcCoreError> {
+        let req = QueryConsensusStatesRequest {
+            client_id: client_id.to_string(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryConsensusStatesResponse>(
+                req,
+                "/ibc.core.client.v1.Query/ConsensusStates",
+            )
+            .await?;
+
+        Ok(ConsensusStatesResponse {
+            consensus_states: res
+                .consensus_states
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    pub async fn ibc_core_query_connection(
+        &self,
+        connection_id: &str,
+    ) -> Result<ConnectionResponse, IbcCoreError> {
+        let req = QueryConnectionRequest {
+            connection_id: connection_id.to_string(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryConnectionResponse>(req, "/ibc.core.connection.v1.Query/Connection")
+            .await?;
+
+        Ok(ConnectionResponse {
+            connection: res.connection.map(TryInto::try_into).transpose()?,
+        })
+    }
+
+    pub async fn ibc_core_query_connections(
+        &self,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<ConnectionsResponse, IbcCoreError> {
+        let req = QueryConnectionsRequest {
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryConnectionsResponse>(req, "/ibc.core.connection.v1.Query/Connections")
+            .await?;
+
+        Ok(ConnectionsResponse {
+            connections: res
+                .connections
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    pub async fn ibc_core_query_channel(
+        &self,
+        port_id: &str,
+        channel_id: &str,
+    ) -> Result<ChannelResponse, IbcCoreError> {
+        let req = QueryChannelRequest {
+            port_id: port_id.to_string(),
+            channel_id: channel_id.to_string(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryChannelResponse>(req, "/ibc.core.channel.v1.Query/Channel")
+            .await?;
+
+        Ok(ChannelResponse {
+            channel: res.channel.map(TryInto::try_into).transpose()?,
+        })
+    }
+
+    pub async fn ibc_core_query_channels(
+        &self,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<ChannelsResponse, IbcCoreError> {
+        let req = QueryChannelsRequest {
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryChannelsResponse>(req, "/ibc.core.channel.v1.Query/Channels")
+            .await?;
+
+        Ok(ChannelsResponse {
+            channels: res
+                .channels
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query all channels built on top of `connection_id`
+    pub async fn ibc_core_query_connection_channels(
+        &self,
+        connection_id: &str,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<ChannelsResponse, IbcCoreError> {
+        let req = QueryConnectionChannelsRequest {
+            connection: connection_id.to_string(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryConnectionChannelsResponse>(
+                req,
+                "/ibc.core.channel.v1.Query/ConnectionChannels",
+            )
+            .await?;
+
+        Ok(ChannelsResponse {
+            channels: res
+                .channels
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query the light client that the channel's connection is built on
+    pub async fn ibc_core_query_channel_client_state(
+        &self,
+        port_id: &str,
+        channel_id: &str,
+    ) -> Result<ChannelClientStateResponse, IbcCoreError> {
+        let req = QueryChannelClientStateRequest {
+            port_id: port_id.to_string(),
+            channel_id: channel_id.to_string(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryChannelClientStateResponse>(
+                req,
+                "/ibc.core.channel.v1.Query/ChannelClientState",
+            )
+            .await?;
+
+        Ok(ChannelClientStateResponse {
+            client_state: res
+                .identified_client_state
+                .map(TryInto::try_into)
+                .transpose()?,
+        })
+    }
+
+    /// Query the commitment of a sent packet, which is deleted once the packet is acknowledged or timed out
+    pub async fn ibc_core_query_packet_commitment(
+        &self,
+        port_id: &str,
+        channel_id: &str,
+        sequence: u64,
+    ) -> Result<PacketCommitmentResponse, IbcCoreError> {
+        let req = QueryPacketCommitmentRequest {
+            port_id: port_id.to_string(),
+            channel_id: channel_id.to_string(),
+            sequence,
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryPacketCommitmentResponse>(
+                req,
+                "/ibc.core.channel.v1.Query/PacketCommitment",
+            )
+            .await?;
+
+        Ok(PacketCommitmentResponse {
+            commitment: res.commitment,
+        })
+    }
+
+    /// Query the commitments of all the packets sent through the channel that are still in flight
+    pub async fn ibc_core_query_packet_commitments(
+        &self,
+        port_id: &str,
+        channel_id: &str,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<PacketStatesResponse, IbcCoreError> {
+        let req = QueryPacketCommitmentsRequest {
+            port_id: port_id.to_string(),
+            channel_id: channel_id.to_string(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryPacketCommitmentsResponse>(
+                req,
+                "/ibc.core.channel.v1.Query/PacketCommitments",
+            )
+            .await?;
+
+        Ok(PacketStatesResponse {
+            packets: res.commitments.into_iter().map(Into::into).collect(),
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query whether a packet was received on the destination chain.
+    /// `port_id` and `channel_id` are the destination port and channel of the packet.
+    pub async fn ibc_core_query_packet_receipt(
+        &self,
+        port_id: &str,
+        channel_id: &str,
+        sequence: u64,
+    ) -> Result<PacketReceiptResponse, IbcCoreError> {
+        let req = QueryPacketReceiptRequest {
+            port_id: port_id.to_string(),
+            channel_id: channel_id.to_string(),
+            sequence,
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryPacketReceiptResponse>(req, "/ibc.core.channel.v1.Query/PacketReceipt")
+            .await?;
+
+        Ok(PacketReceiptResponse {
+            received: res.received,
+        })
+    }
+
+    /// Query the acknowledgement written by the destination chain for a received packet.
+    /// `port_id` and `channel_id` are the destination port and channel of the packet.
+    pub async fn ibc_core_query_packet_acknowledgement(
+        &self,
+        port_id: &str,
+        channel_id: &str,
+        sequence: u64,
+    ) -> Result<PacketAcknowledgementResponse, IbcCoreError> {
+        let req = QueryPacketAcknowledgementRequest {
+            port_id: port_id.to_string(),
+            channel_id: channel_id.to_string(),
+            sequence,
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryPacketAcknowledgementResponse>(
+                req,
+                "/ibc.core.channel.v1.Query/PacketAcknowledgement",
+            )
+            .await?;
+
+        Ok(PacketAcknowledgementResponse {
+            acknowledgement: res.acknowledgement,
+        })
+    }
+
+    /// Query the acknowledgements written by the channel, optionally only for `packet_commitment_sequences`
+    pub async fn ibc_core_query_packet_acknowledgements(
+        &self,
+        port_id: &str,
+        channel_id: &str,
+        packet_commitment_sequences: Vec<u64>,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<PacketStatesResponse, IbcCoreError> {
+        let req = QueryPacketAcknowledgementsRequest {
+            port_id: port_id.to_string(),
+            channel_id: channel_id.to_string(),
+            pagination: pagination.map(Into::into),
+            packet_commitment_sequences,
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryPacketAcknowledgementsResponse>(
+                req,
+                "/ibc.core.channel.v1.Query/PacketAcknowledgements",
+            )
+            .await?;
+
+        Ok(PacketStatesResponse {
+            packets: res.acknowledgements.into_iter().map(Into::into).collect(),
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query which of the `packet_commitment_sequences` sent by the counterparty chain have not been received yet.
+    /// `port_id` and `channel_id` are the destination port and channel of the packets.
+    pub async fn ibc_core_query_unreceived_packets(
+        &self,
+        port_id: &str,
+        channel_id: &str,
+        packet_commitment_sequences: Vec<u64>,
+    ) -> Result<SequencesResponse, IbcCoreError> {
+        let req = QueryUnreceivedPacketsRequest {
+            port_id: port_id.to_string(),
+            channel_id: channel_id.to_string(),
+            packet_commitment_sequences,
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryUnreceivedPacketsResponse>(
+                req,
+                "/ibc.core.channel.v1.Query/UnreceivedPackets",
+            )
+            .await?;
+
+        Ok(SequencesResponse {
+            sequences: res.sequences,
+            height: res.height.map(Into::into),
+        })
+    }
+
+    /// Query which of the `packet_ack_sequences` acknowledged by the counterparty chain have not been relayed back yet.
+    /// `port_id` and `channel_id` are the source port and channel of the packets.
+    pub async fn ibc_core_query_unreceived_acks(
+        &self,
+        port_id: &str,
+        channel_id: &str,
+        packet_ack_sequences: Vec<u64>,
+    ) -> Result<SequencesResponse, IbcCoreError> {
+        let req = QueryUnreceivedAcksRequest {
+            port_id: port_id.to_string(),
+            channel_id: channel_id.to_string(),
+            packet_ack_sequences,
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryUnreceivedAcksResponse>(
+                req,
+                "/ibc.core.channel.v1.Query/UnreceivedAcks",
+            )
+            .await?;
+
+        Ok(SequencesResponse {
+            sequences: res.sequences,
+            height: res.height.map(Into::into),
+        })
+    }
+
+    /// Query the sequence of the next packet to be received on an ordered channel
+    pub async fn ibc_core_query_next_sequence_receive(
+        &self,
+        port_id: &str,
+        channel_id: &str,
+    ) -> Result<NextSequenceReceiveResponse, IbcCoreError> {
+        let req = QueryNextSequenceReceiveRequest {
+            port_id: port_id.to_string(),
+            channel_id: channel_id.to_string(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryNextSequenceReceiveResponse>(
+                req,
+                "/ibc.core.channel.v1.Query/NextSequenceReceive",
+            )
+            .await?;
+
+        Ok(NextSequenceReceiveResponse {
+            next_sequence_receive: res.next_sequence_receive,
+        })
+    }
+
+    /// Follows the packet sent by `res` until it is acknowledged by the counterparty chain, or times out.
+    /// `counterparty` must be connected to the destination chain of the packet.
+    ///
+    /// Use `SentPacket::from_tx()` and `ibc_core_wait_for_packet()` to follow txs sending multiple packets.
+    pub async fn ibc_core_follow_packet<C: CosmosClient>(
+        &self,
+        res: &ChainTxResponse,
+        counterparty: &CosmTome<C>,
+        options: &PacketPollOptions,
+    ) -> Result<PacketLifecycle, IbcCoreError> {
+        let packet = SentPacket::from_tx(res)?
+            .into_iter()
+            .next()
+            .ok_or(IbcCoreError::MissingEvent)?;
+
+        self.ibc_core_wait_for_packet(packet, counterparty, options)
+            .await
+    }
+
+    /// Polls `counterparty` until `packet` is acknowledged or times out,
+    /// then checks whether the outcome was relayed back to this chain.
+    pub async fn ibc_core_wait_for_packet<C: CosmosClient>(
+        &self,
+        packet: SentPacket,
+        counterparty: &CosmTome<C>,
+        options: &PacketPollOptions,
+    ) -> Result<PacketLifecycle, IbcCoreError> {
+        let start = Instant::now();
+        let (port, channel) = (&packet.destination_port, &packet.destination_channel);
+
+        let outcome = loop {
+            // the packet acknowledgements query ignores `packet_commitment_sequences` on older ibc-go versions,
+            // so query the single packet and treat a missing acknowledgement as pending
+            match counterparty
+                .ibc_core_query_packet_acknowledgement(port, channel, packet.sequence)
+                .await
+            {
+                Ok(ack) => {
+                    break PacketOutcome::Acknowledged {
+                        acknowledgement: find_acknowledgement(counterparty, &packet).await,
+                        commitment: ack.acknowledgement,
+                    }
+                }
+                Err(IbcCoreError::ChainError(e)) if e.is_not_found() => {}
+                Err(e) => return Err(e),
+            }
+
+            let unreceived = counterparty
+                .ibc_core_query_unreceived_packets(port, channel, vec![packet.sequence])
+                .await?;
+
+            // a received packet can still be waiting for an asynchronous acknowledgement
+            if unreceived.sequences.contains(&packet.sequence) {
+                let time = match packet.timeout_timestamp {
+                    Some(_) => Some(counterparty_time(counterparty).await?),
+                    None => None,
+                };
+
+                if packet.is_timed_out(unreceived.height.unwrap_or_default(), time) {
+                    break PacketOutcome::TimedOut;
+                }
+            }
+
+            if start.elapsed() >= options.timeout {
+                return Err(IbcCoreError::PacketPollTimeout {
+                    port_id: packet.source_port,
+                    channel_id: packet.source_channel,
+                    sequence: packet.sequence,
+                });
+            }
+
+            tokio::time::sleep(options.interval).await;
+        };
+
+        // the packet commitment is deleted once the acknowledgement or timeout is relayed back
+        let relayed_back = self
+            .ibc_core_query_unreceived_acks(
+                &packet.source_port,
+                &packet.source_channel,
+                vec![packet.sequence],
+            )
+            .await?
+            .sequences
+            .is_empty();
+
+        Ok(PacketLifecycle {
+            packet,
+            outcome,
+            relayed_back,
+        })
+    }
+}
+
+// Searches the counterparty txs for the `write_acknowledgement` event of `packet`,
+// since the chain only stores the hash of the acknowledgement.
+// The search is best effort: the acknowledgement was already committed, so a counterparty node
+// that cannot search its txs, ie. with its tx indexer disabled, only leaves it unknown
+async fn find_acknowledgement<C: CosmosClient>(
+    counterparty: &CosmTome<C>,
+    packet: &SentPacket,
+) -> Option<Acknowledgement> {
+    let req = GetTxsEventRequest {
+        events: vec![
+            format!(
+                "{WRITE_ACKNOWLEDGEMENT_EVENT}.packet_sequence='{}'",
+                packet.sequence
+            ),
+            format!(
+                "{WRITE_ACKNOWLEDGEMENT_EVENT}.packet_dst_port='{}'",
+                packet.destination_port
+            ),
+            format!(
+                "{WRITE_ACKNOWLEDGEMENT_EVENT}.packet_dst_channel='{}'",
+                packet.destination_channel
+            ),
+        ],
+        pagination: None,
+        order_by: 0,
+    };
+
+    // only the tx responses are decoded, since the txs can contain messages unknown to cosm-tome
+    let res = counterparty
+        .client
+        .query::<_, GetTxsEventResponse>(req, "/cosmos.tx.v1beta1.Service/GetTxsEvent")
+        .await
+        .ok()?;
+
+    res.tx_responses
+        .into_iter()
+        .filter_map(|tx_res| ChainTxResponse::try_from(tx_res).ok())
+        .find_map(|tx_res| Acknowledgement::from_tx(&tx_res, packet))
+}
+
+async fn counterparty_time<C: CosmosClient>(
+    counterparty: &CosmTome<C>,
+) -> Result<Time, IbcCoreError> {
+    let block = counterparty.tendermint_query_latest_block().await?;
+
+    let time = block
+        .block
+        .header
+        .and_then(|h| h.time)
+        .ok_or(TendermintError::MissingBlock)?;
+
+    Ok(Time::try_from(time).map_err(ChainError::from)?)
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use crate::{
+        chain::{
+            error::ChainError,
+            msg::encode_any,
+            response::{ChainResponse, ChainTxResponse, Code, Event, Tag},
+        },
+        clients::client::{CosmTome, MockCosmosClient},
+        modules::ibc_core::{
+            error::IbcCoreError,
+            model::{
+                Acknowledgement, ChannelOrder, ChannelState, ClientState as IbcClientState, Height,
+                PacketOutcome, PacketPollOptions, SentPacket, TENDERMINT_CLIENT_STATE_TYPE_URL,
+            },
+        },
+        test_utils::test_cfg,
+    };
+    use cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse;
+    use cosmrs::proto::cosmos::tx::v1beta1::{GetTxsEventRequest, GetTxsEventResponse};
+    use cosmrs::proto::ibc::core::channel::v1::{
+        Channel, Counterparty, Order, QueryChannelClientStateRequest,
+        QueryChannelClientStateResponse, QueryChannelRequest, QueryChannelResponse,
+        QueryPacketAcknowledgementRequest, QueryPacketAcknowledgementResponse,
+        QueryUnreceivedAcksRequest, QueryUnreceivedAcksResponse, QueryUnreceivedPacketsRequest,
+        QueryUnreceivedPacketsResponse, State,
+    };
+    use cosmrs::proto::ibc::core::client::v1::{Height as ProtoHeight, IdentifiedClientState};
+    use cosmrs::proto::ibc::lightclients::tendermint::v1::{ClientState, Fraction};
+    use cosmrs::proto::tendermint::abci::{Event as ProtoEvent, EventAttribute};
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_ibc_core_query_channel() {
+        let cfg = test_cfg();
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QueryChannelRequest, QueryChannelResponse>()
+            .times(1)
+            .returning(|req, path| {
+                assert_eq!(path, "/ibc.core.channel.v1.Query/Channel");
+                assert_eq!(req.channel_id, "channel-0");
+
+                Ok(QueryChannelResponse {
+                    channel: Some(Channel {
+                        state: State::Open as i32,
+                        ordering: Order::Unordered as i32,
+                        counterparty: Some(Counterparty {
+                            port_id: "transfer".to_string(),
+                            channel_id: "channel-42".to_string(),
+                        }),
+                        connection_hops: vec!["connection-0".to_string()],
+                        version: "ics20-1".to_string(),
+                    }),
+                    proof: vec![],
+                    proof_height: None,
+                })
+            });
+
+        mock_client
+            .expect_query::<QueryChannelClientStateRequest, QueryChannelClientStateResponse>()
+            .times(1)
+            .returning(|_, _| {
+                Ok(QueryChannelClientStateResponse {
+                    identified_client_state: Some(IdentifiedClientState {
+                        client_id: "07-tendermint-0".to_string(),
+                        client_state: Some(encode_any(
+                            TENDERMINT_CLIENT_STATE_TYPE_URL,
+                            &ClientState {
+                                chain_id: "osmosis-1".to_string(),
+                                trust_level: Some(Fraction {
+                                    numerator: 1,
+                                    denominator: 3,
+                                }),
+                                frozen_height: Some(ProtoHeight::default()),
+                                latest_height: Some(ProtoHeight {
+                                    revision_number: 1,
+                                    revision_height: 1337,
+                                }),
+                                ..Default::default()
+                            },
+                        )),
+                    }),
+                    proof: vec![],
+                    proof_height: None,
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let channel = cosm_tome
+            .ibc_core_query_channel("transfer", "channel-0")
+            .await
+            .unwrap()
+            .channel
+            .unwrap();
+
+        assert_eq!(channel.state, ChannelState::Open);
+        assert_eq!(channel.ordering, ChannelOrder::Unordered);
+        assert_eq!(channel.counterparty.channel_id, "channel-42");
+
+        let client = cosm_tome
+            .ibc_core_query_channel_client_state("transfer", "channel-0")
+            .await
+            .unwrap()
+            .client_state
+            .unwrap();
+
+        assert_eq!(client.client_id, "07-tendermint-0");
+        match client.client_state {
+            IbcClientState::Tendermint(c) => {
+                assert_eq!(c.chain_id, "osmosis-1");
+                assert_eq!(c.trust_level, (1, 3));
+                assert_eq!(c.frozen_height, None);
+                assert_eq!(
+                    c.latest_height,
+                    Height {
+                        revision_number: 1,
+                        revision_height: 1337,
+                    }
+                );
+            }
+            state => panic!("unexpected client state: {state:?}"),
+        }
+    }
+
+    // Mocks the counterparty tx that received packet `sequence` and wrote `ack` for it
+    fn mock_write_acknowledgement(client: &mut MockCosmosClient, sequence: u64, ack: &'static str) {
+        client
+            .expect_query::<GetTxsEventRequest, GetTxsEventResponse>()
+            .withf(move |req, path| {
+                path == "/cosmos.tx.v1beta1.Service/GetTxsEvent"
+                    && req.events.contains(&format!(
+                        "write_acknowledgement.packet_sequence='{sequence}'"
+                    ))
+            })
+            .times(1)
+            .returning(move |_, _| {
+                let attrs = [
+                    ("packet_sequence", sequence.to_string()),
+                    ("packet_dst_port", "transfer".to_string()),
+                    ("packet_dst_channel", "channel-42".to_string()),
+                    ("packet_ack", ack.to_string()),
+                ];
+
+                Ok(GetTxsEventResponse {
+                    txs: vec![],
+                    tx_responses: vec![TxResponse {
+                        height: 42,
+                        txhash: "TX_HASH_1".to_string(),
+                        events: vec![ProtoEvent {
+                            r#type: "write_acknowledgement".to_string(),
+                            attributes: attrs
+                                .into_iter()
+                                .map(|(key, value)| EventAttribute {
+                                    key: key.as_bytes().to_vec().into(),
+                                    value: value.into_bytes().into(),
+                                    index: true,
+                                })
+                                .collect(),
+                        }],
+                        ..Default::default()
+                    }],
+                    pagination: None,
+                })
+            });
+    }
+
+    fn send_packet_event(sequence: u64, timeout_height: &str) -> Event {
+        let attrs = [
+            ("packet_sequence", sequence.to_string()),
+            ("packet_src_port", "transfer".to_string()),
+            ("packet_src_channel", "channel-0".to_string()),
+            ("packet_dst_port", "transfer".to_string()),
+            ("packet_dst_channel", "channel-42".to_string()),
+            ("packet_timeout_height", timeout_height.to_string()),
+            ("packet_timeout_timestamp", "0".to_string()),
+        ];
+
+        Event {
+            type_str: "send_packet".to_string(),
+            attributes: attrs
+                .into_iter()
+                .map(|(key, value)| Tag {
+                    key: key.to_string(),
+                    value,
+                })
+                .collect(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_ibc_core_follow_packets() {
+        let cfg = test_cfg();
+
+        let tx_res = ChainTxResponse {
+            res: ChainResponse {
+                code: Code::Ok,
+                codespace: String::new(),
+                data: None,
+                log: "log log log".to_string(),
+            },
+            events: vec![
+                send_packet_event(1, "1-2000"),
+                send_packet_event(2, "1-1000"),
+            ],
+            gas_wanted: 200,
+            gas_used: 100,
+            tx_hash: "TX_HASH_0".to_string(),
+            height: 1337,
+        };
+
+        let mut source_client = MockCosmosClient::new();
+        let mut counterparty_client = MockCosmosClient::new();
+
+        // packet 1 was received and acknowledged
+        counterparty_client
+            .expect_query::<QueryPacketAcknowledgementRequest, QueryPacketAcknowledgementResponse>()
+            .withf(|req, path| {
+                req.sequence == 1
+                    && req.channel_id == "channel-42"
+                    && path == "/ibc.core.channel.v1.Query/PacketAcknowledgement"
+            })
+            .times(1)
+            .returning(|_, _| {
+                Ok(QueryPacketAcknowledgementResponse {
+                    acknowledgement: vec![1, 2, 3],
+                    proof: vec![],
+                    proof_height: None,
+                })
+            });
+
+        mock_write_acknowledgement(
+            &mut counterparty_client,
+            1,
+            r#"{"error":"ABCI code: 5: error handling packet: see events for details"}"#,
+        );
+
+        source_client
+            .expect_query::<QueryUnreceivedAcksRequest, QueryUnreceivedAcksResponse>()
+            .withf(|req, _| req.packet_ack_sequences == [1] && req.channel_id == "channel-0")
+            .times(1)
+            .returning(|_, _| {
+                Ok(QueryUnreceivedAcksResponse {
+                    sequences: vec![],
+                    height: None,
+                })
+            });
+
+        // packet 2 was never received, and the counterparty chain is past its timeout height
+        counterparty_client
+            .expect_query::<QueryPacketAcknowledgementRequest, QueryPacketAcknowledgementResponse>()
+            .withf(|req, _| req.sequence == 2)
+            .times(1)
+            .returning(|_, _| {
+                Err(ChainError::tonic_status(tonic::Status::not_found(
+                    "packet acknowledgement hash not found",
+                )))
+            });
+
+        counterparty_client
+            .expect_query::<QueryUnreceivedPacketsRequest, QueryUnreceivedPacketsResponse>()
+            .withf(|req, _| req.packet_commitment_sequences == [2])
+            .times(1)
+            .returning(|req, _| {
+                Ok(QueryUnreceivedPacketsResponse {
+                    sequences: req.packet_commitment_sequences,
+                    height: Some(ProtoHeight {
+                        revision_number: 1,
+                        revision_height: 1001,
+                    }),
+                })
+            });
+
+        source_client
+            .expect_query::<QueryUnreceivedAcksRequest, QueryUnreceivedAcksResponse>()
+            .withf(|req, _| req.packet_ack_sequences == [2])
+            .times(1)
+            .returning(|req, _| {
+                Ok(QueryUnreceivedAcksResponse {
+                    sequences: req.packet_ack_sequences,
+                    height: None,
+                })
+            });
+
+        let source = CosmTome::new(cfg.clone(), source_client);
+        let counterparty = CosmTome::new(cfg, counterparty_client);
+        let options = PacketPollOptions::default();
+
+        let res = source
+            .ibc_core_follow_packet(&tx_res, &counterparty, &options)
+            .await
+            .unwrap();
+
+        assert_eq!(res.packet.sequence, 1);
+        assert_eq!(
+            res.outcome,
+            PacketOutcome::Acknowledged {
+                commitment: vec![1, 2, 3],
+                acknowledgement: Some(Acknowledgement::Error(
+                    "ABCI code: 5: error handling packet: see events for details".to_string()
+                )),
+            }
+        );
+        assert!(res.relayed_back);
+
+        let packets = SentPacket::from_tx(&tx_res).unwrap();
+        assert_eq!(packets.len(), 2);
+        assert_eq!(packets[1].destination_channel, "channel-42");
+
+        let res = source
+            .ibc_core_wait_for_packet(packets[1].clone(), &counterparty, &options)
+            .await
+            .unwrap();
+
+        assert_eq!(res.outcome, PacketOutcome::TimedOut);
+        assert!(!res.relayed_back);
+    }
+
+    #[tokio::test]
+    async fn test_ibc_core_wait_for_packet_pending_ack() {
+        let cfg = test_cfg();
+
+        let tx_res = ChainTxResponse {
+            res: ChainResponse {
+                code: Code::Ok,
+                codespace: String::new(),
+                data: None,
+                log: "log log log".to_string(),
+            },
+            events: vec![send_packet_event(7, "1-2000")],
+            gas_wanted: 200,
+            gas_used: 100,
+            tx_hash: "TX_HASH_0".to_string(),
+            height: 1337,
+        };
+        let packet = SentPacket::from_tx(&tx_res).unwrap().remove(0);
+
+        let mut source_client = MockCosmosClient::new();
+        let mut counterparty_client = MockCosmosClient::new();
+        let mut seq = mockall::Sequence::new();
+
+        // the packet was received, but its acknowledgement is only written on the second poll
+        counterparty_client
+            .expect_query::<QueryPacketAcknowledgementRequest, QueryPacketAcknowledgementResponse>()
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_, _| {
+                Err(ChainError::tonic_status(tonic::Status::not_found(
+                    "packet acknowledgement hash not found",
+                )))
+            });
+
+        counterparty_client
+            .expect_query::<QueryUnreceivedPacketsRequest, QueryUnreceivedPacketsResponse>()
+            .times(1)
+            .returning(|_, _| {
+                Ok(QueryUnreceivedPacketsResponse {
+                    sequences: vec![],
+                    height: None,
+                })
+            });
+
+        counterparty_client
+            .expect_query::<QueryPacketAcknowledgementRequest, QueryPacketAcknowledgementResponse>()
+            .times(1)
+            .in_sequence(&mut seq)
+            .returning(|_, _| {
+                Ok(QueryPacketAcknowledgementResponse {
+                    acknowledgement: vec![4, 5, 6],
+                    proof: vec![],
+                    proof_height: None,
+                })
+            });
+
+        mock_write_acknowledgement(&mut counterparty_client, 7, r#"{"result":"AQ=="}"#);
+
+        source_client
+            .expect_query::<QueryUnreceivedAcksRequest, QueryUnreceivedAcksResponse>()
+            .times(1)
+            .returning(|req, _| {
+                Ok(QueryUnreceivedAcksResponse {
+                    sequences: req.packet_ack_sequences,
+                    height: None,
+                })
+            });
+
+        let source = CosmTome::new(cfg.clone(), source_client);
+        let counterparty = CosmTome::new(cfg, counterparty_client);
+        let options = PacketPollOptions {
+            interval: Duration::from_millis(1),
+            timeout: Duration::from_secs(5),
+        };
+
+        let res = source
+            .ibc_core_wait_for_packet(packet, &counterparty, &options)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            res.outcome,
+            PacketOutcome::Acknowledged {
+                commitment: vec![4, 5, 6],
+                acknowledgement: Some(Acknowledgement::Result(vec![1])),
+            }
+        );
+        assert!(!res.relayed_back);
+    }
+
+    #[tokio::test]
+    async fn test_ibc_core_wait_for_packet_tx_search_error() {
+        let cfg = test_cfg();
+
+        let tx_res = ChainTxResponse {
+            res: ChainResponse {
+                code: Code::Ok,
+                codespace: String::new(),
+                data: None,
+                log: "log log log".to_string(),
+            },
+            events: vec![send_packet_event(7, "1-2000")],
+            gas_wanted: 200,
+            gas_used: 100,
+            tx_hash: "TX_HASH_0".to_string(),
+            height: 1337,
+        };
+
+        let mut source_client = MockCosmosClient::new();
+        let mut counterparty_client = MockCosmosClient::new();
+
+        counterparty_client
+            .expect_query::<QueryPacketAcknowledgementRequest, QueryPacketAcknowledgementResponse>()
+            .times(1)
+            .returning(|_, _| {
+                Ok(QueryPacketAcknowledgementResponse {
+                    acknowledgement: vec![4, 5, 6],
+                    proof: vec![],
+                    proof_height: None,
+                })
+            });
+
+        // the counterparty node does not index txs, the packet is still acknowledged
+        counterparty_client
+            .expect_query::<GetTxsEventRequest, GetTxsEventResponse>()
+            .times(1)
+            .returning(|_, _| {
+                Err(ChainError::tonic_status(tonic::Status::internal(
+                    "transaction indexing is disabled",
+                )))
+            });
+
+        source_client
+            .expect_query::<QueryUnreceivedAcksRequest, QueryUnreceivedAcksResponse>()
+            .times(1)
+            .returning(|_, _| {
+                Ok(QueryUnreceivedAcksResponse {
+                    sequences: vec![],
+                    height: None,
+                })
+            });
+
+        let source = CosmTome::new(cfg.clone(), source_client);
+        let counterparty = CosmTome::new(cfg, counterparty_client);
+
+        let res = source
+            .ibc_core_follow_packet(&tx_res, &counterparty, &PacketPollOptions::default())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            res.outcome,
+            PacketOutcome::Acknowledged {
+                commitment: vec![4, 5, 6],
+                acknowledgement: None,
+            }
+        );
+        assert!(res.relayed_back);
+    }
+
+    #[tokio::test]
+    async fn test_ibc_core_wait_for_packet_query_error() {
+        let cfg = test_cfg();
+
+        let tx_res = ChainTxResponse {
+            res: ChainResponse {
+                code: Code::Ok,
+                codespace: String::new(),
+                data: None,
+                log: "log log log".to_string(),
+            },
+            events: vec![send_packet_event(7, "1-2000")],
+            gas_wanted: 200,
+            gas_used: 100,
+            tx_hash: "TX_HASH_0".to_string(),
+            height: 1337,
+        };
+
+        let mut counterparty_client = MockCosmosClient::new();
+
+        // errors other than NotFound are not mistaken for a pending acknowledgement
+        counterparty_client
+            .expect_query::<QueryPacketAcknowledgementRequest, QueryPacketAcknowledgementResponse>()
+            .times(1)
+            .returning(|_, _| {
+                Err(ChainError::tonic_status(tonic::Status::unavailable(
+                    "connection refused",
+                )))
+            });
+
+        let source = CosmTome::new(cfg.clone(), MockCosmosClient::new());
+        let counterparty = CosmTome::new(cfg, counterparty_client);
+
+        let err = source
+            .ibc_core_follow_packet(&tx_res, &counterparty, &PacketPollOptions::default())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, IbcCoreError::ChainError(_)));
+    }
+}
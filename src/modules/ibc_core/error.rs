@@ -0,0 +1,37 @@
+use thiserror::Error;
+
+use crate::{chain::error::ChainError, modules::tendermint::error::TendermintError};
+
+#[derive(Error, Debug)]
+pub enum IbcCoreError {
+    #[error("Invalid connection state: {i}")]
+    ConnectionState { i: i32 },
+
+    #[error("Invalid channel state: {i}")]
+    ChannelState { i: i32 },
+
+    #[error("Invalid channel order: {i}")]
+    ChannelOrder { i: i32 },
+
+    #[error("{field} missing from ibc response")]
+    MissingField { field: &'static str },
+
+    #[error("Missing send_packet event in tx response")]
+    MissingEvent,
+
+    #[error("Invalid send_packet event: {message}")]
+    PacketEvent { message: String },
+
+    #[error("timed out waiting for packet {sequence} sent through {port_id}/{channel_id}")]
+    PacketPollTimeout {
+        port_id: String,
+        channel_id: String,
+        sequence: u64,
+    },
+
+    #[error(transparent)]
+    TendermintError(#[from] TendermintError),
+
+    #[error(transparent)]
+    ChainError(#[from] ChainError),
+}
@@ -0,0 +1,836 @@
+use std::time::Duration;
+
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+use cosmrs::proto::ibc::core::channel::v1::{
+    Channel as ProtoChannel, Counterparty as ProtoChannelCounterparty,
+    IdentifiedChannel as ProtoIdentifiedChannel, Order as ProtoOrder,
+    PacketState as ProtoPacketState, State as ProtoChannelState,
+};
+use cosmrs::proto::ibc::core::client::v1::{
+    ConsensusStateWithHeight as ProtoConsensusStateWithHeight, Height as ProtoHeight,
+    IdentifiedClientState as ProtoIdentifiedClientState,
+};
+use cosmrs::proto::ibc::core::connection::v1::{
+    ConnectionEnd as ProtoConnectionEnd, Counterparty as ProtoConnectionCounterparty,
+    IdentifiedConnection as ProtoIdentifiedConnection, State as ProtoConnectionState,
+    Version as ProtoVersion,
+};
+use cosmrs::proto::ibc::lightclients::tendermint::v1::{
+    ClientState as ProtoTendermintClientState, ConsensusState as ProtoTendermintConsensusState,
+};
+use cosmrs::proto::traits::Message;
+use cosmrs::{tendermint::Time, Any};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::chain::error::ChainError;
+use crate::chain::request::PaginationResponse;
+use crate::chain::response::ChainTxResponse;
+use crate::chain::time::{duration_from_proto, time_from_proto};
+
+use super::error::IbcCoreError;
+
+pub const TENDERMINT_CLIENT_STATE_TYPE_URL: &str = "/ibc.lightclients.tendermint.v1.ClientState";
+pub const TENDERMINT_CONSENSUS_STATE_TYPE_URL: &str =
+    "/ibc.lightclients.tendermint.v1.ConsensusState";
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ClientStateResponse {
+    pub client_state: Option<ClientState>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ClientStatesResponse {
+    pub client_states: Vec<IdentifiedClientState>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ConsensusStateResponse {
+    pub consensus_state: Option<ConsensusState>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ConsensusStatesResponse {
+    pub consensus_states: Vec<ConsensusStateWithHeight>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ConnectionResponse {
+    pub connection: Option<ConnectionEnd>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ConnectionsResponse {
+    pub connections: Vec<IdentifiedConnection>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ChannelResponse {
+    pub channel: Option<ChannelEnd>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ChannelsResponse {
+    pub channels: Vec<IdentifiedChannel>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ChannelClientStateResponse {
+    pub client_state: Option<IdentifiedClientState>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct PacketCommitmentResponse {
+    /// Hash of the packet, empty if the packet was acknowledged, timed out or never sent
+    pub commitment: Vec<u8>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct PacketReceiptResponse {
+    pub received: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct PacketAcknowledgementResponse {
+    /// Hash of the acknowledgement written by the receiving chain, empty if not yet written
+    pub acknowledgement: Vec<u8>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct PacketStatesResponse {
+    pub packets: Vec<PacketState>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct SequencesResponse {
+    pub sequences: Vec<u64>,
+
+    /// Height of the chain at which the sequences were queried
+    pub height: Option<Height>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct NextSequenceReceiveResponse {
+    pub next_sequence_receive: u64,
+}
+
+/// Height of an IBC counterparty chain.
+/// `revision_number` is incremented every time the chain is upgraded with a new chain-id, ie. `osmosis-1` -> `osmosis-2`.
+/// Heights are ordered by `revision_number` first, then by `revision_height`.
+#[derive(
+    Copy,
+    Clone,
+    Debug,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Default,
+)]
+pub struct Height {
+    pub revision_number: u64,
+    pub revision_height: u64,
+}
+
+impl Height {
+    pub fn is_zero(&self) -> bool {
+        self.revision_number == 0 && self.revision_height == 0
+    }
+}
+
+impl From<ProtoHeight> for Height {
+    fn from(h: ProtoHeight) -> Self {
+        Self {
+            revision_number: h.revision_number,
+            revision_height: h.revision_height,
+        }
+    }
+}
+
+impl From<Height> for ProtoHeight {
+    fn from(h: Height) -> Self {
+        Self {
+            revision_number: h.revision_number,
+            revision_height: h.revision_height,
+        }
+    }
+}
+
+/// Light client tracking a counterparty chain
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub enum ClientState {
+    Tendermint(TendermintClientState),
+
+    /// Light client type unknown to cosm-tome, ie. solo machine
+    Other {
+        type_url: String,
+        value: Vec<u8>,
+    },
+}
+
+impl ClientState {
+    /// Latest height of the counterparty chain known by the client
+    pub fn latest_height(&self) -> Option<Height> {
+        match self {
+            ClientState::Tendermint(c) => Some(c.latest_height),
+            ClientState::Other { .. } => None,
+        }
+    }
+}
+
+impl TryFrom<Any> for ClientState {
+    type Error = IbcCoreError;
+
+    fn try_from(any: Any) -> Result<Self, Self::Error> {
+        match any.type_url.as_str() {
+            TENDERMINT_CLIENT_STATE_TYPE_URL => Ok(ClientState::Tendermint(
+                ProtoTendermintClientState::decode(any.value.as_slice())
+                    .map_err(ChainError::prost_proto_decoding)?
+                    .try_into()?,
+            )),
+            _ => Ok(ClientState::Other {
+                type_url: any.type_url,
+                value: any.value,
+            }),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct TendermintClientState {
+    pub chain_id: String,
+
+    /// Fraction of the validator set that must sign a header for it to be trusted, ie. 1/3
+    pub trust_level: (u64, u64),
+
+    pub trusting_period: Duration,
+    pub unbonding_period: Duration,
+    pub max_clock_drift: Duration,
+
+    /// Height at which the client was frozen because of misbehaviour, `None` if it is not frozen
+    pub frozen_height: Option<Height>,
+
+    pub latest_height: Height,
+}
+
+impl TryFrom<ProtoTendermintClientState> for TendermintClientState {
+    type Error = IbcCoreError;
+
+    fn try_from(c: ProtoTendermintClientState) -> Result<Self, Self::Error> {
+        let duration = |d: Option<prost_types::Duration>| -> Result<Duration, ChainError> {
+            Ok(d.map(duration_from_proto).transpose()?.unwrap_or_default())
+        };
+
+        Ok(Self {
+            chain_id: c.chain_id,
+            trust_level: c
+                .trust_level
+                .map(|f| (f.numerator, f.denominator))
+                .unwrap_or_default(),
+            trusting_period: duration(c.trusting_period)?,
+            unbonding_period: duration(c.unbonding_period)?,
+            max_clock_drift: duration(c.max_clock_drift)?,
+            frozen_height: c.frozen_height.map(Height::from).filter(|h| !h.is_zero()),
+            latest_height: c
+                .latest_height
+                .ok_or(IbcCoreError::MissingField {
+                    field: "latest_height",
+                })?
+                .into(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct IdentifiedClientState {
+    pub client_id: String,
+    pub client_state: ClientState,
+}
+
+impl TryFrom<ProtoIdentifiedClientState> for IdentifiedClientState {
+    type Error = IbcCoreError;
+
+    fn try_from(c: ProtoIdentifiedClientState) -> Result<Self, Self::Error> {
+        Ok(Self {
+            client_id: c.client_id,
+            client_state: c
+                .client_state
+                .ok_or(IbcCoreError::MissingField {
+                    field: "client_state",
+                })?
+                .try_into()?,
+        })
+    }
+}
+
+/// Snapshot of a counterparty chain used to verify its proofs at a given height
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub enum ConsensusState {
+    Tendermint(TendermintConsensusState),
+
+    /// Light client type unknown to cosm-tome
+    Other {
+        type_url: String,
+        value: Vec<u8>,
+    },
+}
+
+impl TryFrom<Any> for ConsensusState {
+    type Error = IbcCoreError;
+
+    fn try_from(any: Any) -> Result<Self, Self::Error> {
+        match any.type_url.as_str() {
+            TENDERMINT_CONSENSUS_STATE_TYPE_URL => Ok(ConsensusState::Tendermint(
+                ProtoTendermintConsensusState::decode(any.value.as_slice())
+                    .map_err(ChainError::prost_proto_decoding)?
+                    .try_into()?,
+            )),
+            _ => Ok(ConsensusState::Other {
+                type_url: any.type_url,
+                value: any.value,
+            }),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct TendermintConsensusState {
+    /// Block time of the counterparty chain
+    pub timestamp: Time,
+
+    /// App hash of the counterparty chain
+    pub root: Vec<u8>,
+
+    pub next_validators_hash: Vec<u8>,
+}
+
+impl TryFrom<ProtoTendermintConsensusState> for TendermintConsensusState {
+    type Error = IbcCoreError;
+
+    fn try_from(c: ProtoTendermintConsensusState) -> Result<Self, Self::Error> {
+        Ok(Self {
+            timestamp: time_from_proto(
+                c.timestamp
+                    .ok_or(IbcCoreError::MissingField { field: "timestamp" })?,
+            )?,
+            root: c.root.map(|r| r.hash).unwrap_or_default(),
+            next_validators_hash: c.next_validators_hash,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ConsensusStateWithHeight {
+    pub height: Height,
+    pub consensus_state: ConsensusState,
+}
+
+impl TryFrom<ProtoConsensusStateWithHeight> for ConsensusStateWithHeight {
+    type Error = IbcCoreError;
+
+    fn try_from(c: ProtoConsensusStateWithHeight) -> Result<Self, Self::Error> {
+        Ok(Self {
+            height: c.height.unwrap_or_default().into(),
+            consensus_state: c
+                .consensus_state
+                .ok_or(IbcCoreError::MissingField {
+                    field: "consensus_state",
+                })?
+                .try_into()?,
+        })
+    }
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub enum ConnectionState {
+    Uninitialized,
+    Init,
+    TryOpen,
+    Open,
+}
+
+impl TryFrom<i32> for ConnectionState {
+    type Error = IbcCoreError;
+
+    fn try_from(i: i32) -> Result<Self, Self::Error> {
+        match ProtoConnectionState::from_i32(i) {
+            Some(ProtoConnectionState::UninitializedUnspecified) => {
+                Ok(ConnectionState::Uninitialized)
+            }
+            Some(ProtoConnectionState::Init) => Ok(ConnectionState::Init),
+            Some(ProtoConnectionState::Tryopen) => Ok(ConnectionState::TryOpen),
+            Some(ProtoConnectionState::Open) => Ok(ConnectionState::Open),
+            None => Err(IbcCoreError::ConnectionState { i }),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct ConnectionVersion {
+    pub identifier: String,
+    pub features: Vec<String>,
+}
+
+impl From<ProtoVersion> for ConnectionVersion {
+    fn from(v: ProtoVersion) -> Self {
+        Self {
+            identifier: v.identifier,
+            features: v.features,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct ConnectionCounterparty {
+    pub client_id: String,
+
+    /// Empty while the connection handshake is not complete
+    pub connection_id: String,
+
+    /// Store prefix used by the counterparty chain for its proofs
+    pub prefix: Vec<u8>,
+}
+
+impl From<ProtoConnectionCounterparty> for ConnectionCounterparty {
+    fn from(c: ProtoConnectionCounterparty) -> Self {
+        Self {
+            client_id: c.client_id,
+            connection_id: c.connection_id,
+            prefix: c.prefix.map(|p| p.key_prefix).unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ConnectionEnd {
+    pub client_id: String,
+    pub versions: Vec<ConnectionVersion>,
+    pub state: ConnectionState,
+    pub counterparty: ConnectionCounterparty,
+
+    /// Minimum delay before a packet can be processed after the counterparty client update
+    pub delay_period: Duration,
+}
+
+impl TryFrom<ProtoConnectionEnd> for ConnectionEnd {
+    type Error = IbcCoreError;
+
+    fn try_from(c: ProtoConnectionEnd) -> Result<Self, Self::Error> {
+        Ok(Self {
+            client_id: c.client_id,
+            versions: c.versions.into_iter().map(Into::into).collect(),
+            state: c.state.try_into()?,
+            counterparty: c
+                .counterparty
+                .ok_or(IbcCoreError::MissingField {
+                    field: "counterparty",
+                })?
+                .into(),
+            delay_period: Duration::from_nanos(c.delay_period),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct IdentifiedConnection {
+    pub connection_id: String,
+    pub connection: ConnectionEnd,
+}
+
+impl TryFrom<ProtoIdentifiedConnection> for IdentifiedConnection {
+    type Error = IbcCoreError;
+
+    fn try_from(c: ProtoIdentifiedConnection) -> Result<Self, Self::Error> {
+        Ok(Self {
+            connection_id: c.id,
+            connection: ProtoConnectionEnd {
+                client_id: c.client_id,
+                versions: c.versions,
+                state: c.state,
+                counterparty: c.counterparty,
+                delay_period: c.delay_period,
+            }
+            .try_into()?,
+        })
+    }
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub enum ChannelState {
+    Uninitialized,
+    Init,
+    TryOpen,
+    Open,
+    Closed,
+}
+
+impl TryFrom<i32> for ChannelState {
+    type Error = IbcCoreError;
+
+    fn try_from(i: i32) -> Result<Self, Self::Error> {
+        match ProtoChannelState::from_i32(i) {
+            Some(ProtoChannelState::UninitializedUnspecified) => Ok(ChannelState::Uninitialized),
+            Some(ProtoChannelState::Init) => Ok(ChannelState::Init),
+            Some(ProtoChannelState::Tryopen) => Ok(ChannelState::TryOpen),
+            Some(ProtoChannelState::Open) => Ok(ChannelState::Open),
+            Some(ProtoChannelState::Closed) => Ok(ChannelState::Closed),
+            None => Err(IbcCoreError::ChannelState { i }),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub enum ChannelOrder {
+    Unspecified,
+    Unordered,
+    Ordered,
+}
+
+impl TryFrom<i32> for ChannelOrder {
+    type Error = IbcCoreError;
+
+    fn try_from(i: i32) -> Result<Self, Self::Error> {
+        match ProtoOrder::from_i32(i) {
+            Some(ProtoOrder::NoneUnspecified) => Ok(ChannelOrder::Unspecified),
+            Some(ProtoOrder::Unordered) => Ok(ChannelOrder::Unordered),
+            Some(ProtoOrder::Ordered) => Ok(ChannelOrder::Ordered),
+            None => Err(IbcCoreError::ChannelOrder { i }),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct ChannelCounterparty {
+    pub port_id: String,
+
+    /// Empty while the channel handshake is not complete
+    pub channel_id: String,
+}
+
+impl From<ProtoChannelCounterparty> for ChannelCounterparty {
+    fn from(c: ProtoChannelCounterparty) -> Self {
+        Self {
+            port_id: c.port_id,
+            channel_id: c.channel_id,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct ChannelEnd {
+    pub state: ChannelState,
+    pub ordering: ChannelOrder,
+    pub counterparty: ChannelCounterparty,
+
+    /// Connections that packets travel through, only a single hop is currently supported by ibc-go
+    pub connection_hops: Vec<String>,
+
+    /// Application version negotiated during the handshake, ie. `ics20-1`
+    pub version: String,
+}
+
+impl TryFrom<ProtoChannel> for ChannelEnd {
+    type Error = IbcCoreError;
+
+    fn try_from(c: ProtoChannel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            state: c.state.try_into()?,
+            ordering: c.ordering.try_into()?,
+            counterparty: c
+                .counterparty
+                .ok_or(IbcCoreError::MissingField {
+                    field: "counterparty",
+                })?
+                .into(),
+            connection_hops: c.connection_hops,
+            version: c.version,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct IdentifiedChannel {
+    pub port_id: String,
+    pub channel_id: String,
+    pub channel: ChannelEnd,
+}
+
+impl TryFrom<ProtoIdentifiedChannel> for IdentifiedChannel {
+    type Error = IbcCoreError;
+
+    fn try_from(c: ProtoIdentifiedChannel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            port_id: c.port_id,
+            channel_id: c.channel_id,
+            channel: ProtoChannel {
+                state: c.state,
+                ordering: c.ordering,
+                counterparty: c.counterparty,
+                connection_hops: c.connection_hops,
+                version: c.version,
+            }
+            .try_into()?,
+        })
+    }
+}
+
+/// Packet commitment or acknowledgement stored by the chain
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct PacketState {
+    pub port_id: String,
+    pub channel_id: String,
+    pub sequence: u64,
+
+    /// Hash of the packet commitment or acknowledgement
+    pub data: Vec<u8>,
+}
+
+impl From<ProtoPacketState> for PacketState {
+    fn from(p: ProtoPacketState) -> Self {
+        Self {
+            port_id: p.port_id,
+            channel_id: p.channel_id,
+            sequence: p.sequence,
+            data: p.data,
+        }
+    }
+}
+
+pub const SEND_PACKET_EVENT: &str = "send_packet";
+
+/// Packet sent by a tx, parsed from its `send_packet` event
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct SentPacket {
+    pub sequence: u64,
+    pub source_port: String,
+    pub source_channel: String,
+    pub destination_port: String,
+    pub destination_channel: String,
+
+    /// Counterparty chain height after which the packet times out
+    pub timeout_height: Option<Height>,
+
+    /// Counterparty chain block time after which the packet times out
+    #[schemars(with = "Option<String>")]
+    pub timeout_timestamp: Option<Time>,
+}
+
+impl SentPacket {
+    /// Parses every packet sent by the tx, in the order they were sent
+    pub fn from_tx(res: &ChainTxResponse) -> Result<Vec<Self>, IbcCoreError> {
+        let tags = |key: &str| {
+            res.find_event_tags(SEND_PACKET_EVENT.to_string(), key.to_string())
+                .into_iter()
+                .map(|t| t.value.clone())
+                .collect::<Vec<_>>()
+        };
+
+        let sequences = tags("packet_sequence");
+        let src_ports = tags("packet_src_port");
+        let src_channels = tags("packet_src_channel");
+        let dst_ports = tags("packet_dst_port");
+        let dst_channels = tags("packet_dst_channel");
+        let timeout_heights = tags("packet_timeout_height");
+        let timeout_timestamps = tags("packet_timeout_timestamp");
+
+        let n = sequences.len();
+        if [
+            &src_ports,
+            &src_channels,
+            &dst_ports,
+            &dst_channels,
+            &timeout_heights,
+            &timeout_timestamps,
+        ]
+        .iter()
+        .any(|t| t.len() != n)
+        {
+            return Err(IbcCoreError::PacketEvent {
+                message: "missing packet attributes".to_string(),
+            });
+        }
+
+        (0..n)
+            .map(|i| {
+                Ok(Self {
+                    sequence: parse_attr("packet_sequence", &sequences[i])?,
+                    source_port: src_ports[i].clone(),
+                    source_channel: src_channels[i].clone(),
+                    destination_port: dst_ports[i].clone(),
+                    destination_channel: dst_channels[i].clone(),
+                    timeout_height: parse_timeout_height(&timeout_heights[i])?,
+                    timeout_timestamp: match parse_attr(
+                        "packet_timeout_timestamp",
+                        &timeout_timestamps[i],
+                    )? {
+                        0 => None,
+                        nanos => Some(
+                            Time::from_unix_timestamp(
+                                (nanos / 1_000_000_000) as i64,
+                                (nanos % 1_000_000_000) as u32,
+                            )
+                            .map_err(ChainError::from)?,
+                        ),
+                    },
+                })
+            })
+            .collect()
+    }
+
+    /// Returns true if the packet can no longer be received by a counterparty chain at `height` and `time`
+    pub fn is_timed_out(&self, height: Height, time: Option<Time>) -> bool {
+        let height_passed = self.timeout_height.map(|h| height >= h).unwrap_or(false);
+        let time_passed = match (self.timeout_timestamp, time) {
+            (Some(timeout), Some(time)) => time >= timeout,
+            _ => false,
+        };
+
+        height_passed || time_passed
+    }
+}
+
+fn parse_attr(key: &str, value: &str) -> Result<u64, IbcCoreError> {
+    value.parse().map_err(|_| IbcCoreError::PacketEvent {
+        message: format!("invalid {key}: {value:?}"),
+    })
+}
+
+/// Parses a `{revision_number}-{revision_height}` timeout height, `0-0` meaning no timeout
+fn parse_timeout_height(value: &str) -> Result<Option<Height>, IbcCoreError> {
+    let (number, height) = value
+        .split_once('-')
+        .ok_or_else(|| IbcCoreError::PacketEvent {
+            message: format!("invalid packet_timeout_height: {value:?}"),
+        })?;
+
+    let height = Height {
+        revision_number: parse_attr("packet_timeout_height", number)?,
+        revision_height: parse_attr("packet_timeout_height", height)?,
+    };
+
+    Ok(Some(height).filter(|h| !h.is_zero()))
+}
+
+/// Controls how `ibc_core_follow_packet()` polls the counterparty chain
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct PacketPollOptions {
+    /// Time to wait between queries
+    pub interval: Duration,
+
+    /// Total time to wait for the packet to be acknowledged or timed out
+    /// before returning `IbcCoreError::PacketPollTimeout`
+    pub timeout: Duration,
+}
+
+impl Default for PacketPollOptions {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(5),
+            timeout: Duration::from_secs(600),
+        }
+    }
+}
+
+pub const WRITE_ACKNOWLEDGEMENT_EVENT: &str = "write_acknowledgement";
+
+/// Acknowledgement written by the app that received a packet on the counterparty chain
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub enum Acknowledgement {
+    /// ICS-04 `{"result": ...}` acknowledgement of a successfully processed packet
+    Result(Vec<u8>),
+
+    /// ICS-04 `{"error": ...}` acknowledgement, ie. of a failed ICS-20 transfer whose tokens are refunded
+    Error(String),
+
+    /// App specific acknowledgement that does not follow the ICS-04 format
+    Other(Vec<u8>),
+}
+
+impl Acknowledgement {
+    pub fn from_bytes(ack: &[u8]) -> Self {
+        match serde_json::from_slice::<StandardAcknowledgement>(ack) {
+            Ok(StandardAcknowledgement::Result(result)) => match BASE64.decode(result) {
+                Ok(result) => Acknowledgement::Result(result),
+                Err(_) => Acknowledgement::Other(ack.to_vec()),
+            },
+            Ok(StandardAcknowledgement::Error(error)) => Acknowledgement::Error(error),
+            Err(_) => Acknowledgement::Other(ack.to_vec()),
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        matches!(self, Acknowledgement::Error(_))
+    }
+
+    /// Parses the acknowledgement of `packet` from the `write_acknowledgement` events of the
+    /// counterparty tx that received it. Returns `None` if the tx did not acknowledge `packet`.
+    pub fn from_tx(res: &ChainTxResponse, packet: &SentPacket) -> Option<Self> {
+        let sequence = packet.sequence.to_string();
+
+        res.events
+            .iter()
+            .filter(|e| e.type_str == WRITE_ACKNOWLEDGEMENT_EVENT)
+            .find(|e| {
+                let has = |key: &str, value: &str| {
+                    e.attributes
+                        .iter()
+                        .any(|a| a.key == key && a.value == value)
+                };
+
+                has("packet_sequence", &sequence)
+                    && has("packet_dst_port", &packet.destination_port)
+                    && has("packet_dst_channel", &packet.destination_channel)
+            })
+            .and_then(|e| e.attributes.iter().find(|a| a.key == "packet_ack"))
+            .map(|a| Acknowledgement::from_bytes(a.value.as_bytes()))
+    }
+}
+
+// ICS-04 acknowledgement envelope used by ICS-20 and ICS-27
+#[derive(Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum StandardAcknowledgement {
+    Result(String),
+    Error(String),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub enum PacketOutcome {
+    /// Received by the counterparty chain, which wrote an acknowledgement for it.
+    Acknowledged {
+        /// Hash of the acknowledgement committed by the counterparty chain
+        commitment: Vec<u8>,
+
+        /// Acknowledgement parsed from the counterparty tx that received the packet,
+        /// `None` if the tx could not be found, ie. because it was pruned by the counterparty node
+        /// or the node does not index txs
+        acknowledgement: Option<Acknowledgement>,
+    },
+
+    /// The counterparty chain passed the packet's timeout without receiving it
+    TimedOut,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct PacketLifecycle {
+    pub packet: SentPacket,
+    pub outcome: PacketOutcome,
+
+    /// Whether the acknowledgement or timeout was relayed back to the source chain, deleting its packet commitment.
+    /// Tokens of a failed or timed out transfer are only refunded once this is true.
+    pub relayed_back: bool,
+}
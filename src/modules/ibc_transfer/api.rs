@@ -0,0 +1,276 @@
+use cosmrs::proto::ibc::applications::transfer::v1::{
+    QueryDenomHashRequest, QueryDenomHashResponse, QueryDenomTraceRequest, QueryDenomTraceResponse,
+    QueryDenomTracesRequest, QueryDenomTracesResponse, QueryParamsRequest, QueryParamsResponse,
+};
+
+use crate::{
+    chain::request::{PaginationRequest, TxOptions},
+    clients::client::{CosmTome, CosmosClient},
+    signing_key::key::SigningKey,
+};
+
+use super::{
+    error::IbcTransferError,
+    model::{
+        DenomHashResponse, DenomTrace, DenomTraceResponse, DenomTracesResponse,
+        EscrowAddressResponse, IbcTransferTxResponse, ParamsResponse, QueryEscrowAddressRequest,
+        QueryEscrowAddressResponse, TransferRequest,
+    },
+};
+
+impl<T: CosmosClient> CosmTome<T> {
+    /// Send tokens to another chain over IBC (ICS-20)
+    pub async fn ibc_transfer_send(
+        &self,
+        req: TransferRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<IbcTransferTxResponse, IbcTransferError> {
+        if req.timeout_height.filter(|h| !h.is_zero()).is_none() && req.timeout_timestamp.is_none()
+        {
+            return Err(IbcTransferError::MissingTimeout);
+        }
+
+        let res = self.tx_send(vec![req], key, tx_options).await?;
+
+        Ok(IbcTransferTxResponse { res })
+    }
+
+    /// Query the trace of an IBC voucher, from its `ibc/<HASH>` denom or its hash
+    pub async fn ibc_transfer_query_denom_trace(
+        &self,
+        denom: &str,
+    ) -> Result<DenomTraceResponse, IbcTransferError> {
+        let req = QueryDenomTraceRequest {
+            hash: denom.trim_start_matches("ibc/").to_string(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryDenomTraceResponse>(
+                req,
+                "/ibc.applications.transfer.v1.Query/DenomTrace",
+            )
+            .await?;
+
+        Ok(DenomTraceResponse {
+            denom_trace: res.denom_trace.map(Into::into),
+        })
+    }
+
+    pub async fn ibc_transfer_query_denom_traces(
+        &self,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<DenomTracesResponse, IbcTransferError> {
+        let req = QueryDenomTracesRequest {
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryDenomTracesResponse>(
+                req,
+                "/ibc.applications.transfer.v1.Query/DenomTraces",
+            )
+            .await?;
+
+        Ok(DenomTracesResponse {
+            denom_traces: res.denom_traces.into_iter().map(Into::into).collect(),
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query the hash of a denom trace.
+    /// Use `DenomTrace::hash()` to compute it locally instead.
+    pub async fn ibc_transfer_query_denom_hash(
+        &self,
+        trace: &DenomTrace,
+    ) -> Result<DenomHashResponse, IbcTransferError> {
+        let req = QueryDenomHashRequest {
+            trace: trace.full_path(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryDenomHashResponse>(
+                req,
+                "/ibc.applications.transfer.v1.Query/DenomHash",
+            )
+            .await?;
+
+        Ok(DenomHashResponse { hash: res.hash })
+    }
+
+    /// Query the address holding the tokens escrowed while they are transferred out through `port_id`/`channel_id`
+    pub async fn ibc_transfer_query_escrow_address(
+        &self,
+        port_id: &str,
+        channel_id: &str,
+    ) -> Result<EscrowAddressResponse, IbcTransferError> {
+        let req = QueryEscrowAddressRequest {
+            port_id: port_id.to_string(),
+            channel_id: channel_id.to_string(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryEscrowAddressResponse>(
+                req,
+                "/ibc.applications.transfer.v1.Query/EscrowAddress",
+            )
+            .await?;
+
+        Ok(EscrowAddressResponse {
+            escrow_address: res.escrow_address.parse()?,
+        })
+    }
+
+    pub async fn ibc_transfer_query_params(&self) -> Result<ParamsResponse, IbcTransferError> {
+        let req = QueryParamsRequest {};
+
+        let res = self
+            .client
+            .query::<_, QueryParamsResponse>(req, "/ibc.applications.transfer.v1.Query/Params")
+            .await?;
+
+        Ok(ParamsResponse {
+            params: res.params.map(Into::into),
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use cosmrs::proto::cosmos::auth::v1beta1::{
+        BaseAccount, QueryAccountRequest, QueryAccountResponse,
+    };
+    use cosmrs::proto::ibc::applications::transfer::v1::MsgTransfer;
+    use cosmrs::proto::traits::{Message, MessageExt};
+
+    use crate::{
+        chain::{
+            coin::Coin,
+            fee::GasInfo,
+            request::TxOptions,
+            response::{ChainResponse, ChainTxResponse, Code},
+        },
+        clients::client::{CosmTome, MockCosmosClient},
+        modules::{
+            auth::model::{Address, BASE_ACCOUNT_TYPE_URL},
+            ibc_transfer::{
+                error::IbcTransferError,
+                model::{Height, TransferRequest, TRANSFER_PORT},
+            },
+            tx::model::RawTx,
+        },
+        signing_key::key::SigningKey,
+        test_utils::test_cfg,
+    };
+
+    #[tokio::test]
+    async fn test_ibc_transfer_send() {
+        let cfg = test_cfg();
+        let tx_options = TxOptions::default();
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
+
+        let juno_receiver: Address = "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+            .parse()
+            .unwrap();
+        let receiver = Address::new("osmo", &juno_receiver.to_bytes()).unwrap();
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QueryAccountRequest, QueryAccountResponse>()
+            .times(1)
+            .returning(move |_, _| {
+                Ok(QueryAccountResponse {
+                    account: Some(cosmrs::proto::Any {
+                        type_url: BASE_ACCOUNT_TYPE_URL.to_string(),
+                        value: BaseAccount {
+                            address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                            pub_key: None,
+                            account_number: 1337,
+                            sequence: 1,
+                        }
+                        .to_bytes()
+                        .unwrap(),
+                    }),
+                })
+            });
+
+        mock_client.expect_simulate_tx().times(1).returning(|_| {
+            Ok(GasInfo {
+                gas_wanted: 200u16.into(),
+                gas_used: 100u16.into(),
+            })
+        });
+
+        let expected_receiver = receiver.to_string();
+        mock_client
+            .expect_broadcast_tx_block()
+            .times(1)
+            .withf(move |tx: &RawTx| {
+                let tx = cosmrs::Tx::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+                let msg = MsgTransfer::decode(tx.body.messages[0].value.as_slice()).unwrap();
+
+                tx.body.messages[0].type_url == "/ibc.applications.transfer.v1.MsgTransfer"
+                    && msg.source_channel == "channel-0"
+                    && msg.receiver == expected_receiver
+                    && msg.timeout_height.unwrap().revision_height == 1000
+                    && msg.timeout_timestamp == 0
+            })
+            .returning(|_| {
+                Ok(ChainTxResponse {
+                    res: ChainResponse {
+                        code: Code::Ok,
+                        codespace: String::new(),
+                        data: None,
+                        log: "log log log".to_string(),
+                    },
+                    events: vec![],
+                    gas_wanted: 200,
+                    gas_used: 100,
+                    tx_hash: "TX_HASH_0".to_string(),
+                    height: 1337,
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg.clone(), mock_client);
+
+        let mut req = TransferRequest {
+            source_port: TRANSFER_PORT.to_string(),
+            source_channel: "channel-0".to_string(),
+            token: Coin {
+                denom: cfg.denom.parse().unwrap(),
+                amount: 10,
+            },
+            sender: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                .parse()
+                .unwrap(),
+            receiver,
+            timeout_height: None,
+            timeout_timestamp: None,
+        };
+
+        // a transfer without any timeout is rejected before reaching the chain
+        let err = cosm_tome
+            .ibc_transfer_send(req.clone(), &key, &tx_options)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, IbcTransferError::MissingTimeout));
+
+        req.timeout_height = Some(Height {
+            revision_number: 1,
+            revision_height: 1000,
+        });
+
+        let res = cosm_tome
+            .ibc_transfer_send(req, &key, &tx_options)
+            .await
+            .unwrap();
+
+        assert_eq!(res.res.tx_hash, "TX_HASH_0");
+    }
+}
@@ -0,0 +1,27 @@
+use thiserror::Error;
+
+use crate::{
+    chain::error::ChainError,
+    modules::{auth::error::AccountError, tx::error::TxError},
+};
+
+#[derive(Error, Debug)]
+pub enum IbcTransferError {
+    #[error("IBC transfer needs a timeout height or timestamp")]
+    MissingTimeout,
+
+    #[error("Invalid IBC timeout timestamp: {message}")]
+    TimeoutTimestamp { message: String },
+
+    #[error("Invalid IBC denom trace: {trace:?}")]
+    DenomTrace { trace: String },
+
+    #[error(transparent)]
+    TxError(#[from] TxError),
+
+    #[error(transparent)]
+    AccountError(#[from] AccountError),
+
+    #[error(transparent)]
+    ChainError(#[from] ChainError),
+}
@@ -0,0 +1,316 @@
+use std::fmt;
+use std::str::FromStr;
+
+use cosmrs::proto::ibc::applications::transfer::v1::{
+    DenomTrace as ProtoDenomTrace, MsgTransfer, Params as ProtoParams,
+};
+use cosmrs::tendermint::Time;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::chain::coin::Coin;
+use crate::chain::error::ChainError;
+use crate::chain::msg::Msg;
+use crate::chain::request::PaginationResponse;
+use crate::chain::response::ChainTxResponse;
+use crate::chain::time::time_to_proto;
+use crate::modules::auth::model::Address;
+
+pub use crate::modules::ibc_core::model::Height;
+
+use super::error::IbcTransferError;
+
+/// Port bound by the ICS-20 transfer module on most chains
+pub const TRANSFER_PORT: &str = "transfer";
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct IbcTransferTxResponse {
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct DenomTraceResponse {
+    pub denom_trace: Option<DenomTrace>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct DenomTracesResponse {
+    pub denom_traces: Vec<DenomTrace>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct DenomHashResponse {
+    /// Uppercase hex hash of the trace, without the `ibc/` prefix
+    pub hash: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct EscrowAddressResponse {
+    pub escrow_address: Address,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ParamsResponse {
+    pub params: Option<Params>,
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct Params {
+    pub send_enabled: bool,
+    pub receive_enabled: bool,
+}
+
+impl From<ProtoParams> for Params {
+    fn from(p: ProtoParams) -> Self {
+        Self {
+            send_enabled: p.send_enabled,
+            receive_enabled: p.receive_enabled,
+        }
+    }
+}
+
+/// Path that an IBC voucher took to reach this chain, ie. `transfer/channel-0` and its `base_denom` on the origin chain.
+/// A voucher is represented on chain by the `ibc/<HASH>` denom returned by `DenomTrace::ibc_denom()`.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct DenomTrace {
+    /// `{port}/{channel}` pairs, from the latest hop to the origin chain.
+    /// Empty if the denom is native to this chain.
+    pub path: String,
+
+    pub base_denom: String,
+}
+
+impl DenomTrace {
+    /// Full trace, ie. `transfer/channel-0/uatom`
+    pub fn full_path(&self) -> String {
+        if self.path.is_empty() {
+            self.base_denom.clone()
+        } else {
+            format!("{}/{}", self.path, self.base_denom)
+        }
+    }
+
+    /// Uppercase hex SHA-256 hash of the full trace, as used in `ibc/<HASH>` denoms
+    pub fn hash(&self) -> String {
+        let digest = Sha256::digest(self.full_path().as_bytes());
+
+        digest.iter().map(|b| format!("{b:02X}")).collect()
+    }
+
+    /// Denom of the voucher on this chain, computed locally from the trace.
+    /// Returns `base_denom` if the denom is native to this chain.
+    pub fn ibc_denom(&self) -> String {
+        if self.path.is_empty() {
+            self.base_denom.clone()
+        } else {
+            format!("ibc/{}", self.hash())
+        }
+    }
+
+    /// Returns true if the denom is native to this chain
+    pub fn is_native(&self) -> bool {
+        self.path.is_empty()
+    }
+}
+
+impl fmt::Display for DenomTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.full_path())
+    }
+}
+
+/// Parses a full trace like `transfer/channel-0/uatom`.
+/// Follows ibc-go in only treating `{port}/channel-{n}` pairs as part of the path,
+/// so `transfer/channel-0/gamm/pool/1` has `gamm/pool/1` as its base denom.
+impl FromStr for DenomTrace {
+    type Err = IbcTransferError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s.split('/').collect::<Vec<_>>();
+        if parts.iter().any(|p| p.trim().is_empty()) {
+            return Err(IbcTransferError::DenomTrace {
+                trace: s.to_string(),
+            });
+        }
+
+        let mut path_len = 0;
+        // the base denom needs at least one segment after the last port/channel pair
+        while path_len + 2 < parts.len() && is_channel_id(parts[path_len + 1]) {
+            path_len += 2;
+        }
+
+        Ok(Self {
+            path: parts[..path_len].join("/"),
+            base_denom: parts[path_len..].join("/"),
+        })
+    }
+}
+
+fn is_channel_id(id: &str) -> bool {
+    id.strip_prefix("channel-")
+        .map(|n| n.parse::<u64>().is_ok())
+        .unwrap_or(false)
+}
+
+impl From<ProtoDenomTrace> for DenomTrace {
+    fn from(t: ProtoDenomTrace) -> Self {
+        Self {
+            path: t.path,
+            base_denom: t.base_denom,
+        }
+    }
+}
+
+impl From<DenomTrace> for ProtoDenomTrace {
+    fn from(t: DenomTrace) -> Self {
+        Self {
+            path: t.path,
+            base_denom: t.base_denom,
+        }
+    }
+}
+
+/// Sends `token` to `receiver` on the counterparty chain of `source_channel` (ICS-20).
+/// At least one of `timeout_height` or `timeout_timestamp` must be set,
+/// the transfer is refunded if the packet isn't received on the counterparty chain before then.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct TransferRequest {
+    /// Usually `TRANSFER_PORT`
+    pub source_port: String,
+    pub source_channel: String,
+    pub token: Coin,
+    pub sender: Address,
+
+    /// Address on the counterparty chain, with its own bech32 prefix
+    pub receiver: Address,
+
+    /// Counterparty chain height after which the packet times out
+    pub timeout_height: Option<Height>,
+
+    /// Counterparty chain block time after which the packet times out
+    pub timeout_timestamp: Option<Time>,
+}
+
+impl Msg for TransferRequest {
+    type Proto = MsgTransfer;
+    type Err = IbcTransferError;
+}
+
+impl TryFrom<MsgTransfer> for TransferRequest {
+    type Error = IbcTransferError;
+
+    fn try_from(msg: MsgTransfer) -> Result<Self, Self::Error> {
+        let timeout_timestamp = match msg.timeout_timestamp {
+            0 => None,
+            nanos => Some(
+                Time::from_unix_timestamp(
+                    (nanos / 1_000_000_000) as i64,
+                    (nanos % 1_000_000_000) as u32,
+                )
+                .map_err(ChainError::from)?,
+            ),
+        };
+
+        Ok(Self {
+            source_port: msg.source_port,
+            source_channel: msg.source_channel,
+            token: msg
+                .token
+                .ok_or(ChainError::ProtoDecoding {
+                    message: "missing transfer token".to_string(),
+                })?
+                .try_into()?,
+            sender: msg.sender.parse()?,
+            receiver: msg.receiver.parse()?,
+            timeout_height: msg
+                .timeout_height
+                .map(Height::from)
+                .filter(|h| !h.is_zero()),
+            timeout_timestamp,
+        })
+    }
+}
+
+impl TryFrom<TransferRequest> for MsgTransfer {
+    type Error = IbcTransferError;
+
+    fn try_from(req: TransferRequest) -> Result<Self, Self::Error> {
+        let timeout_height = req.timeout_height.filter(|h| !h.is_zero());
+
+        if timeout_height.is_none() && req.timeout_timestamp.is_none() {
+            return Err(IbcTransferError::MissingTimeout);
+        }
+
+        Ok(Self {
+            source_port: req.source_port,
+            source_channel: req.source_channel,
+            token: Some(req.token.into()),
+            sender: req.sender.into(),
+            receiver: req.receiver.into(),
+            timeout_height: Some(timeout_height.unwrap_or_default().into()),
+            timeout_timestamp: req
+                .timeout_timestamp
+                .map(timestamp_nanos)
+                .transpose()?
+                .unwrap_or_default(),
+        })
+    }
+}
+
+fn timestamp_nanos(t: Time) -> Result<u64, IbcTransferError> {
+    let ts = time_to_proto(t);
+
+    u64::try_from(ts.seconds)
+        .ok()
+        .and_then(|secs| secs.checked_mul(1_000_000_000))
+        .and_then(|nanos| nanos.checked_add(ts.nanos as u64))
+        .ok_or(IbcTransferError::TimeoutTimestamp {
+            message: format!("{t} is not representable as unix nanoseconds"),
+        })
+}
+
+/// `Query/EscrowAddress` is not part of the ibc-go protos we currently depend on.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryEscrowAddressRequest {
+    #[prost(string, tag = "1")]
+    pub port_id: String,
+    #[prost(string, tag = "2")]
+    pub channel_id: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryEscrowAddressResponse {
+    #[prost(string, tag = "1")]
+    pub escrow_address: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_denom_trace_ibc_denom() {
+        let trace: DenomTrace = "transfer/channel-0/uatom".parse().unwrap();
+        assert_eq!(trace.path, "transfer/channel-0");
+        assert_eq!(trace.base_denom, "uatom");
+        assert_eq!(
+            trace.ibc_denom(),
+            "ibc/27394FB092D2ECCD56123C74F36E4C1F926001CEADA9CA97EA622B25F41E5EB2"
+        );
+
+        let trace: DenomTrace = "transfer/channel-1/transfer/channel-42/gamm/pool/1"
+            .parse()
+            .unwrap();
+        assert_eq!(trace.path, "transfer/channel-1/transfer/channel-42");
+        assert_eq!(trace.base_denom, "gamm/pool/1");
+
+        let native: DenomTrace = "gamm/pool/1".parse().unwrap();
+        assert!(native.is_native());
+        assert_eq!(native.ibc_denom(), "gamm/pool/1");
+
+        assert!("transfer//uatom".parse::<DenomTrace>().is_err());
+    }
+}
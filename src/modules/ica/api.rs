@@ -0,0 +1,272 @@
+use std::time::Duration;
+
+use cosmrs::tx::MessageExt;
+
+use crate::{
+    chain::{error::ChainError, msg::Msg, request::TxOptions, Any},
+    clients::client::{CosmTome, CosmosClient},
+    modules::{auth::model::Address, ibc_core::model::SentPacket},
+    signing_key::key::SigningKey,
+};
+
+use super::{
+    controller::{QueryInterchainAccountRequest, QueryInterchainAccountResponse},
+    error::IcaError,
+    model::{msg_send_tx, AddressResponse, RegisterRequest, RegisterResponse, SendTxResponse},
+};
+
+impl<T: CosmosClient> CosmTome<T> {
+    /// Opens a channel to register an interchain account for `req.owner` on the host chain
+    pub async fn ica_register(
+        &self,
+        req: RegisterRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<RegisterResponse, IcaError> {
+        let res = self.tx_send(vec![req], key, tx_options).await?;
+
+        let tag = |key: &str| {
+            res.find_event_tags("channel_open_init".to_string(), key.to_string())
+                .first()
+                .map(|t| t.value.clone())
+                .ok_or(IcaError::MissingEvent)
+        };
+
+        Ok(RegisterResponse {
+            port_id: tag("port_id")?,
+            channel_id: tag("channel_id")?,
+            res,
+        })
+    }
+
+    /// Execute `msgs` on the host chain at the other end of `connection_id`,
+    /// using the interchain account owned by `key`.
+    ///
+    /// The msgs must be built with the interchain account as their signer, ie. `SendRequest { from: ica_address, .. }`.
+    /// The packet times out if it is not received by the host chain within `relative_timeout`.
+    pub async fn ica_send_tx(
+        &self,
+        connection_id: &str,
+        msgs: Vec<impl Msg>,
+        relative_timeout: Duration,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<SendTxResponse, IcaError> {
+        let msgs = msgs
+            .into_iter()
+            .map(|m| m.into_any())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| ChainError::ProtoEncoding {
+                message: e.to_string(),
+            })?;
+
+        self.ica_send_tx_any(connection_id, msgs, relative_timeout, key, tx_options)
+            .await
+    }
+
+    /// Same as `ica_send_tx()`, for already encoded messages.
+    /// Use `Msg::into_any()` to execute different message types in the same interchain account tx.
+    pub async fn ica_send_tx_any(
+        &self,
+        connection_id: &str,
+        msgs: Vec<Any>,
+        relative_timeout: Duration,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<SendTxResponse, IcaError> {
+        let owner = key.to_addr(&self.cfg.prefix).await?;
+
+        let msg = msg_send_tx(owner, connection_id, msgs, relative_timeout)?
+            .to_any()
+            .map_err(|e| ChainError::ProtoEncoding {
+                message: e.to_string(),
+            })?;
+
+        let res = self.tx_send_any(vec![msg], key, tx_options).await?;
+
+        let packet = SentPacket::from_tx(&res)?
+            .into_iter()
+            .next()
+            .ok_or(IcaError::MissingEvent)?;
+
+        Ok(SendTxResponse { packet, res })
+    }
+
+    /// Query the address of the interchain account owned by `owner` on the host chain at the other end of `connection_id`
+    pub async fn ica_query_address(
+        &self,
+        owner: Address,
+        connection_id: &str,
+    ) -> Result<AddressResponse, IcaError> {
+        let req = QueryInterchainAccountRequest {
+            owner: owner.into(),
+            connection_id: connection_id.to_string(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryInterchainAccountResponse>(
+                req,
+                "/ibc.applications.interchain_accounts.controller.v1.Query/InterchainAccount",
+            )
+            .await?;
+
+        Ok(AddressResponse {
+            address: res.address.parse()?,
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use std::time::Duration;
+
+    use cosmrs::proto::cosmos::{
+        auth::v1beta1::{BaseAccount, QueryAccountRequest, QueryAccountResponse},
+        bank::v1beta1::MsgSend,
+    };
+    use cosmrs::proto::ibc::applications::interchain_accounts::v1::CosmosTx;
+    use cosmrs::proto::traits::{Message, MessageExt};
+
+    use crate::{
+        chain::{
+            coin::Coin,
+            fee::GasInfo,
+            request::TxOptions,
+            response::{ChainResponse, ChainTxResponse, Code, Event, Tag},
+        },
+        clients::client::{CosmTome, MockCosmosClient},
+        modules::{
+            auth::model::{Address, BASE_ACCOUNT_TYPE_URL},
+            bank::model::SendRequest,
+            ica::controller::MsgSendTx,
+            tx::model::RawTx,
+        },
+        signing_key::key::SigningKey,
+        test_utils::test_cfg,
+    };
+
+    #[tokio::test]
+    async fn test_ica_send_tx() {
+        let cfg = test_cfg();
+        let tx_options = TxOptions::default();
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
+        let owner = key.to_addr(&cfg.prefix).await.unwrap().to_string();
+
+        let ica_address: Address = "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+            .parse()
+            .unwrap();
+        let ica_address = Address::new("osmo", &ica_address.to_bytes()).unwrap();
+
+        let mut mock_client = MockCosmosClient::new();
+
+        let account_addr = owner.clone();
+        mock_client
+            .expect_query::<QueryAccountRequest, QueryAccountResponse>()
+            .times(1)
+            .returning(move |_, _| {
+                Ok(QueryAccountResponse {
+                    account: Some(cosmrs::proto::Any {
+                        type_url: BASE_ACCOUNT_TYPE_URL.to_string(),
+                        value: BaseAccount {
+                            address: account_addr.clone(),
+                            pub_key: None,
+                            account_number: 1337,
+                            sequence: 1,
+                        }
+                        .to_bytes()
+                        .unwrap(),
+                    }),
+                })
+            });
+
+        mock_client.expect_simulate_tx().times(1).returning(|_| {
+            Ok(GasInfo {
+                gas_wanted: 200u16.into(),
+                gas_used: 100u16.into(),
+            })
+        });
+
+        let from_address = ica_address.to_string();
+        mock_client
+            .expect_broadcast_tx_block()
+            .times(1)
+            .withf(move |tx: &RawTx| {
+                let tx = cosmrs::Tx::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+                let msg = MsgSendTx::decode(tx.body.messages[0].value.as_slice()).unwrap();
+                let packet_data = msg.packet_data.unwrap();
+                let cosmos_tx = CosmosTx::decode(packet_data.data.as_slice()).unwrap();
+                let send = MsgSend::decode(cosmos_tx.messages[0].value.as_slice()).unwrap();
+
+                tx.body.messages[0].type_url
+                    == "/ibc.applications.interchain_accounts.controller.v1.MsgSendTx"
+                    && msg.owner == owner
+                    && msg.connection_id == "connection-0"
+                    && msg.relative_timeout == 600_000_000_000
+                    && packet_data.r#type == 1
+                    && send.from_address == from_address
+            })
+            .returning(|_| {
+                let attrs = [
+                    ("packet_sequence", "7"),
+                    ("packet_src_port", "icacontroller-juno1"),
+                    ("packet_src_channel", "channel-3"),
+                    ("packet_dst_port", "icahost"),
+                    ("packet_dst_channel", "channel-9"),
+                    ("packet_timeout_height", "0-0"),
+                    ("packet_timeout_timestamp", "1700000000000000000"),
+                ];
+
+                Ok(ChainTxResponse {
+                    res: ChainResponse {
+                        code: Code::Ok,
+                        codespace: String::new(),
+                        data: None,
+                        log: "log log log".to_string(),
+                    },
+                    events: vec![Event {
+                        type_str: "send_packet".to_string(),
+                        attributes: attrs
+                            .into_iter()
+                            .map(|(key, value)| Tag {
+                                key: key.to_string(),
+                                value: value.to_string(),
+                            })
+                            .collect(),
+                    }],
+                    gas_wanted: 200,
+                    gas_used: 100,
+                    tx_hash: "TX_HASH_0".to_string(),
+                    height: 1337,
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg.clone(), mock_client);
+
+        let send = SendRequest {
+            from: ica_address.clone(),
+            to: Address::new("osmo", &[1; 20]).unwrap(),
+            amounts: vec![Coin {
+                denom: "uosmo".parse().unwrap(),
+                amount: 10,
+            }],
+        };
+
+        let res = cosm_tome
+            .ica_send_tx(
+                "connection-0",
+                vec![send],
+                Duration::from_secs(600),
+                &key,
+                &tx_options,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res.packet.sequence, 7);
+        assert_eq!(res.packet.destination_port, "icahost");
+        assert_eq!(res.packet.timeout_height, None);
+        assert!(res.packet.timeout_timestamp.is_some());
+    }
+}
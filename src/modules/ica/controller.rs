@@ -0,0 +1,50 @@
+//! ICA controller msgs and queries (ibc-go v6+), which are not part of the ibc-go protos we currently depend on.
+
+use cosmrs::proto::ibc::applications::interchain_accounts::v1::InterchainAccountPacketData;
+use cosmrs::proto::traits::TypeUrl;
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgRegisterInterchainAccount {
+    #[prost(string, tag = "1")]
+    pub owner: String,
+    #[prost(string, tag = "2")]
+    pub connection_id: String,
+    #[prost(string, tag = "3")]
+    pub version: String,
+}
+
+impl TypeUrl for MsgRegisterInterchainAccount {
+    const TYPE_URL: &'static str =
+        "/ibc.applications.interchain_accounts.controller.v1.MsgRegisterInterchainAccount";
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgSendTx {
+    #[prost(string, tag = "1")]
+    pub owner: String,
+    #[prost(string, tag = "2")]
+    pub connection_id: String,
+    #[prost(message, optional, tag = "3")]
+    pub packet_data: Option<InterchainAccountPacketData>,
+    /// Timeout in nanoseconds, relative to the block time of the controller chain
+    #[prost(uint64, tag = "4")]
+    pub relative_timeout: u64,
+}
+
+impl TypeUrl for MsgSendTx {
+    const TYPE_URL: &'static str = "/ibc.applications.interchain_accounts.controller.v1.MsgSendTx";
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryInterchainAccountRequest {
+    #[prost(string, tag = "1")]
+    pub owner: String,
+    #[prost(string, tag = "2")]
+    pub connection_id: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryInterchainAccountResponse {
+    #[prost(string, tag = "1")]
+    pub address: String,
+}
@@ -0,0 +1,27 @@
+use thiserror::Error;
+
+use crate::{
+    chain::error::ChainError,
+    modules::{auth::error::AccountError, ibc_core::error::IbcCoreError, tx::error::TxError},
+};
+
+#[derive(Error, Debug)]
+pub enum IcaError {
+    #[error("Cannot send an interchain account tx without any messages")]
+    EmptyMsgs,
+
+    #[error("Missing channel_open_init event in tx response")]
+    MissingEvent,
+
+    #[error(transparent)]
+    IbcCoreError(#[from] IbcCoreError),
+
+    #[error(transparent)]
+    TxError(#[from] TxError),
+
+    #[error(transparent)]
+    AccountError(#[from] AccountError),
+
+    #[error(transparent)]
+    ChainError(#[from] ChainError),
+}
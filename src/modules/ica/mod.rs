@@ -0,0 +1,4 @@
+pub mod api;
+pub mod controller;
+pub mod error;
+pub mod model;
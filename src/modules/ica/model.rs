@@ -0,0 +1,105 @@
+use std::time::Duration;
+
+use cosmrs::proto::ibc::applications::interchain_accounts::v1::{
+    CosmosTx, InterchainAccountPacketData, Type,
+};
+use cosmrs::proto::traits::Message;
+use cosmrs::Any;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::chain::msg::Msg;
+use crate::chain::response::ChainTxResponse;
+use crate::modules::auth::model::Address;
+use crate::modules::ibc_core::model::SentPacket;
+
+use super::controller::{MsgRegisterInterchainAccount, MsgSendTx};
+use super::error::IcaError;
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct RegisterResponse {
+    /// Controller port of the owner, ie. `icacontroller-{owner}`
+    pub port_id: String,
+
+    /// Channel opened for the interchain account, usable once a relayer completes the handshake
+    pub channel_id: String,
+
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct SendTxResponse {
+    /// Packet carrying the msgs to the host chain, see `ibc_core_wait_for_packet()` to follow it
+    pub packet: SentPacket,
+
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct AddressResponse {
+    /// Address of the interchain account on the host chain
+    pub address: Address,
+}
+
+/// Registers an interchain account for `owner` on the host chain at the other end of `connection_id`.
+/// The account is created on the host chain once a relayer completes the channel handshake.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct RegisterRequest {
+    pub owner: Address,
+    pub connection_id: String,
+
+    /// Channel version metadata, negotiated with the host chain if `None`
+    pub version: Option<String>,
+}
+
+impl Msg for RegisterRequest {
+    type Proto = MsgRegisterInterchainAccount;
+    type Err = IcaError;
+}
+
+impl TryFrom<MsgRegisterInterchainAccount> for RegisterRequest {
+    type Error = IcaError;
+
+    fn try_from(msg: MsgRegisterInterchainAccount) -> Result<Self, Self::Error> {
+        Ok(Self {
+            owner: msg.owner.parse()?,
+            connection_id: msg.connection_id,
+            version: Some(msg.version).filter(|v| !v.is_empty()),
+        })
+    }
+}
+
+impl TryFrom<RegisterRequest> for MsgRegisterInterchainAccount {
+    type Error = IcaError;
+
+    fn try_from(req: RegisterRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            owner: req.owner.into(),
+            connection_id: req.connection_id,
+            version: req.version.unwrap_or_default(),
+        })
+    }
+}
+
+/// Wraps `msgs` in a `CosmosTx` packet, executed by the interchain account of `owner` on the host chain
+pub(crate) fn msg_send_tx(
+    owner: Address,
+    connection_id: &str,
+    msgs: Vec<Any>,
+    relative_timeout: Duration,
+) -> Result<MsgSendTx, IcaError> {
+    if msgs.is_empty() {
+        return Err(IcaError::EmptyMsgs);
+    }
+
+    Ok(MsgSendTx {
+        owner: owner.into(),
+        connection_id: connection_id.to_string(),
+        packet_data: Some(InterchainAccountPacketData {
+            r#type: Type::ExecuteTx as i32,
+            data: CosmosTx { messages: msgs }.encode_to_vec(),
+            memo: String::new(),
+        }),
+        relative_timeout: relative_timeout.as_nanos() as u64,
+    })
+}
@@ -0,0 +1,95 @@
+use cosmrs::proto::cosmos::mint::v1beta1::{
+    QueryAnnualProvisionsRequest, QueryAnnualProvisionsResponse, QueryInflationRequest,
+    QueryInflationResponse, QueryParamsRequest, QueryParamsResponse,
+};
+
+use crate::{
+    chain::coin::Dec,
+    clients::client::{CosmTome, CosmosClient},
+};
+
+use super::{
+    error::MintError,
+    model::{AnnualProvisionsResponse, InflationResponse, ParamsResponse},
+};
+
+impl<T: CosmosClient> CosmTome<T> {
+    pub async fn mint_query_inflation(&self) -> Result<InflationResponse, MintError> {
+        let req = QueryInflationRequest {};
+
+        let res = self
+            .client
+            .query::<_, QueryInflationResponse>(req, "/cosmos.mint.v1beta1.Query/Inflation")
+            .await?;
+
+        Ok(InflationResponse {
+            inflation: Dec::from_atomics_bytes(res.inflation)?,
+        })
+    }
+
+    pub async fn mint_query_annual_provisions(
+        &self,
+    ) -> Result<AnnualProvisionsResponse, MintError> {
+        let req = QueryAnnualProvisionsRequest {};
+
+        let res = self
+            .client
+            .query::<_, QueryAnnualProvisionsResponse>(
+                req,
+                "/cosmos.mint.v1beta1.Query/AnnualProvisions",
+            )
+            .await?;
+
+        Ok(AnnualProvisionsResponse {
+            annual_provisions: Dec::from_atomics_bytes(res.annual_provisions)?,
+        })
+    }
+
+    pub async fn mint_query_params(&self) -> Result<ParamsResponse, MintError> {
+        let req = QueryParamsRequest {};
+
+        let res = self
+            .client
+            .query::<_, QueryParamsResponse>(req, "/cosmos.mint.v1beta1.Query/Params")
+            .await?;
+
+        Ok(ParamsResponse {
+            params: res.params.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use cosmrs::proto::cosmos::mint::v1beta1::{QueryInflationRequest, QueryInflationResponse};
+
+    use crate::{
+        clients::client::{CosmTome, MockCosmosClient},
+        test_utils::test_cfg,
+    };
+
+    #[tokio::test]
+    async fn test_mint_query_inflation() {
+        let cfg = test_cfg();
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QueryInflationRequest, QueryInflationResponse>()
+            .times(1)
+            .returning(move |_, path| {
+                assert_eq!(path, "/cosmos.mint.v1beta1.Query/Inflation");
+
+                Ok(QueryInflationResponse {
+                    inflation: b"129834501234567890".to_vec(),
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let res = cosm_tome.mint_query_inflation().await.unwrap();
+
+        assert_eq!(res.inflation.to_string(), "0.129834501234567890");
+    }
+}
@@ -0,0 +1,9 @@
+use thiserror::Error;
+
+use crate::chain::error::ChainError;
+
+#[derive(Error, Debug)]
+pub enum MintError {
+    #[error(transparent)]
+    ChainError(#[from] ChainError),
+}
@@ -0,0 +1,54 @@
+use cosmrs::proto::cosmos::mint::v1beta1::Params as ProtoParams;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::chain::coin::{Dec, Denom};
+use crate::chain::error::ChainError;
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct InflationResponse {
+    /// Current yearly inflation rate, ie. `0.13` for 13%
+    pub inflation: Dec,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct AnnualProvisionsResponse {
+    /// Amount of `mint_denom` expected to be minted over the next year at the current inflation
+    pub annual_provisions: Dec,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct ParamsResponse {
+    pub params: Option<Params>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct Params {
+    pub mint_denom: Denom,
+
+    /// Maximum yearly change of the inflation rate
+    pub inflation_rate_change: Dec,
+
+    pub inflation_max: Dec,
+    pub inflation_min: Dec,
+
+    /// Bonded ratio the inflation rate adjusts towards
+    pub goal_bonded: Dec,
+
+    pub blocks_per_year: u64,
+}
+
+impl TryFrom<ProtoParams> for Params {
+    type Error = ChainError;
+
+    fn try_from(p: ProtoParams) -> Result<Self, Self::Error> {
+        Ok(Self {
+            mint_denom: p.mint_denom.parse()?,
+            inflation_rate_change: Dec::from_atomics(&p.inflation_rate_change)?,
+            inflation_max: Dec::from_atomics(&p.inflation_max)?,
+            inflation_min: Dec::from_atomics(&p.inflation_min)?,
+            goal_bonded: Dec::from_atomics(&p.goal_bonded)?,
+            blocks_per_year: p.blocks_per_year,
+        })
+    }
+}
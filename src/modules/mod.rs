@@ -1,9 +1,40 @@
 pub mod auth;
 
+pub mod authz;
+
 pub mod bank;
 
 pub mod cosmwasm;
 
+pub mod distribution;
+
+pub mod feegrant;
+
+pub mod gov;
+
+pub mod group;
+
+pub mod ibc_core;
+
+pub mod ibc_transfer;
+
+pub mod ica;
+
+pub mod mint;
+
+pub mod nft;
+
+pub mod slashing;
+
+pub mod staking;
+
+#[cfg(feature = "tokenfactory")]
+pub mod tokenfactory;
+
 pub mod tx;
 
+pub mod upgrade;
+
+pub mod vesting;
+
 pub mod tendermint;
@@ -0,0 +1,255 @@
+use crate::{
+    chain::request::{PaginationRequest, TxOptions},
+    clients::client::{CosmTome, CosmosClient},
+    modules::auth::model::Address,
+    signing_key::key::SigningKey,
+};
+
+use super::{
+    error::NftError,
+    model::{
+        BalanceResponse, ClassResponse, ClassesResponse, NftResponse, NftTxResponse, NftsResponse,
+        OwnerResponse, SendRequest, SupplyResponse,
+    },
+    v1beta1::{
+        QueryBalanceRequest, QueryBalanceResponse, QueryClassRequest, QueryClassResponse,
+        QueryClassesRequest, QueryClassesResponse, QueryNftRequest, QueryNftResponse,
+        QueryNftsRequest, QueryNftsResponse, QueryOwnerRequest, QueryOwnerResponse,
+        QuerySupplyRequest, QuerySupplyResponse,
+    },
+};
+
+impl<T: CosmosClient> CosmTome<T> {
+    pub async fn nft_send(
+        &self,
+        req: SendRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<NftTxResponse, NftError> {
+        self.nft_send_batch(vec![req], key, tx_options).await
+    }
+
+    pub async fn nft_send_batch<I>(
+        &self,
+        reqs: I,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<NftTxResponse, NftError>
+    where
+        I: IntoIterator<Item = SendRequest>,
+    {
+        let res = self
+            .tx_send(reqs.into_iter().collect(), key, tx_options)
+            .await?;
+
+        Ok(NftTxResponse { res })
+    }
+
+    /// Query the number of NFTs of `class_id` held by `owner`
+    pub async fn nft_query_balance(
+        &self,
+        class_id: &str,
+        owner: Address,
+    ) -> Result<BalanceResponse, NftError> {
+        let req = QueryBalanceRequest {
+            class_id: class_id.to_string(),
+            owner: owner.into(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryBalanceResponse>(req, "/cosmos.nft.v1beta1.Query/Balance")
+            .await?;
+
+        Ok(BalanceResponse { amount: res.amount })
+    }
+
+    pub async fn nft_query_owner(
+        &self,
+        class_id: &str,
+        id: &str,
+    ) -> Result<OwnerResponse, NftError> {
+        let req = QueryOwnerRequest {
+            class_id: class_id.to_string(),
+            id: id.to_string(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryOwnerResponse>(req, "/cosmos.nft.v1beta1.Query/Owner")
+            .await?;
+
+        Ok(OwnerResponse {
+            owner: Some(res.owner)
+                .filter(|o| !o.is_empty())
+                .map(|o| o.parse())
+                .transpose()?,
+        })
+    }
+
+    /// Query the number of NFTs minted in `class_id`
+    pub async fn nft_query_supply(&self, class_id: &str) -> Result<SupplyResponse, NftError> {
+        let req = QuerySupplyRequest {
+            class_id: class_id.to_string(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QuerySupplyResponse>(req, "/cosmos.nft.v1beta1.Query/Supply")
+            .await?;
+
+        Ok(SupplyResponse { amount: res.amount })
+    }
+
+    /// Query the NFTs of `class_id`, of `owner`, or of `owner` within `class_id`.
+    /// At least one of `class_id` and `owner` is required.
+    pub async fn nft_query_nfts(
+        &self,
+        class_id: Option<&str>,
+        owner: Option<Address>,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<NftsResponse, NftError> {
+        if class_id.is_none() && owner.is_none() {
+            return Err(NftError::MissingNftsFilter);
+        }
+
+        let req = QueryNftsRequest {
+            class_id: class_id.unwrap_or_default().to_string(),
+            owner: owner.map(Into::into).unwrap_or_default(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryNftsResponse>(req, "/cosmos.nft.v1beta1.Query/NFTs")
+            .await?;
+
+        Ok(NftsResponse {
+            nfts: res.nfts.into_iter().map(Into::into).collect(),
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    pub async fn nft_query_nft(&self, class_id: &str, id: &str) -> Result<NftResponse, NftError> {
+        let req = QueryNftRequest {
+            class_id: class_id.to_string(),
+            id: id.to_string(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryNftResponse>(req, "/cosmos.nft.v1beta1.Query/NFT")
+            .await?;
+
+        Ok(NftResponse {
+            nft: res.nft.map(Into::into),
+        })
+    }
+
+    pub async fn nft_query_class(&self, class_id: &str) -> Result<ClassResponse, NftError> {
+        let req = QueryClassRequest {
+            class_id: class_id.to_string(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryClassResponse>(req, "/cosmos.nft.v1beta1.Query/Class")
+            .await?;
+
+        Ok(ClassResponse {
+            class: res.class.map(Into::into),
+        })
+    }
+
+    pub async fn nft_query_classes(
+        &self,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<ClassesResponse, NftError> {
+        let req = QueryClassesRequest {
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryClassesResponse>(req, "/cosmos.nft.v1beta1.Query/Classes")
+            .await?;
+
+        Ok(ClassesResponse {
+            classes: res.classes.into_iter().map(Into::into).collect(),
+            next: res.pagination.map(Into::into),
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use cosmrs::proto::cosmos::base::query::v1beta1::PageResponse;
+
+    use crate::{
+        chain::request::PaginationResponse,
+        clients::client::{CosmTome, MockCosmosClient},
+        modules::nft::{
+            error::NftError,
+            v1beta1::{Nft, QueryNftsRequest, QueryNftsResponse},
+        },
+        test_utils::test_cfg,
+    };
+
+    #[tokio::test]
+    async fn test_nft_query_nfts() {
+        let cfg = test_cfg();
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QueryNftsRequest, QueryNftsResponse>()
+            .times(1)
+            .returning(move |req, path| {
+                assert_eq!(path, "/cosmos.nft.v1beta1.Query/NFTs");
+                assert_eq!(req.class_id, "");
+                assert_eq!(req.owner, "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg");
+
+                Ok(QueryNftsResponse {
+                    nfts: vec![Nft {
+                        class_id: "kitties".to_string(),
+                        id: "kitty1".to_string(),
+                        uri: "ipfs://kitty1".to_string(),
+                        uri_hash: String::new(),
+                        data: None,
+                    }],
+                    pagination: Some(PageResponse {
+                        next_key: b"kitty2".to_vec(),
+                        total: 2,
+                    }),
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let err = cosm_tome
+            .nft_query_nfts(None, None, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, NftError::MissingNftsFilter));
+
+        let owner = "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+            .parse()
+            .unwrap();
+        let res = cosm_tome
+            .nft_query_nfts(None, Some(owner), None)
+            .await
+            .unwrap();
+
+        assert_eq!(res.nfts.len(), 1);
+        assert_eq!(res.nfts[0].class_id, "kitties");
+        assert_eq!(res.nfts[0].id, "kitty1");
+        assert_eq!(
+            res.next,
+            Some(PaginationResponse {
+                next_key: b"kitty2".to_vec(),
+                total: 2,
+            })
+        );
+    }
+}
@@ -0,0 +1,159 @@
+use cosmrs::Any;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::chain::msg::Msg;
+use crate::chain::request::PaginationResponse;
+use crate::chain::response::ChainTxResponse;
+use crate::modules::auth::model::Address;
+use crate::modules::tx::model::{any_msg, any_msgs};
+
+use super::error::NftError;
+use super::v1beta1;
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct NftTxResponse {
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct BalanceResponse {
+    /// Number of NFTs of the class held by the owner
+    pub amount: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct OwnerResponse {
+    /// `None` if the NFT does not exist
+    pub owner: Option<Address>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct SupplyResponse {
+    /// Number of NFTs minted in the class
+    pub amount: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct NftResponse {
+    pub nft: Option<Nft>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct NftsResponse {
+    pub nfts: Vec<Nft>,
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct ClassResponse {
+    pub class: Option<Class>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct ClassesResponse {
+    pub classes: Vec<Class>,
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct Class {
+    pub id: String,
+
+    pub name: String,
+
+    pub symbol: String,
+
+    pub description: String,
+
+    pub uri: String,
+
+    pub uri_hash: String,
+
+    /// App specific metadata of the class
+    #[serde(with = "any_msg")]
+    #[schemars(with = "Option<any_msgs::JsonAny>")]
+    pub data: Option<Any>,
+}
+
+impl From<v1beta1::Class> for Class {
+    fn from(class: v1beta1::Class) -> Self {
+        Self {
+            id: class.id,
+            name: class.name,
+            symbol: class.symbol,
+            description: class.description,
+            uri: class.uri,
+            uri_hash: class.uri_hash,
+            data: class.data,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct Nft {
+    pub class_id: String,
+
+    pub id: String,
+
+    pub uri: String,
+
+    pub uri_hash: String,
+
+    /// App specific metadata of the NFT
+    #[serde(with = "any_msg")]
+    #[schemars(with = "Option<any_msgs::JsonAny>")]
+    pub data: Option<Any>,
+}
+
+impl From<v1beta1::Nft> for Nft {
+    fn from(nft: v1beta1::Nft) -> Self {
+        Self {
+            class_id: nft.class_id,
+            id: nft.id,
+            uri: nft.uri,
+            uri_hash: nft.uri_hash,
+            data: nft.data,
+        }
+    }
+}
+
+/// Transfers the NFT `id` of class `class_id` from `sender` to `receiver`
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct SendRequest {
+    pub class_id: String,
+    pub id: String,
+    pub sender: Address,
+    pub receiver: Address,
+}
+
+impl Msg for SendRequest {
+    type Proto = v1beta1::MsgSend;
+    type Err = NftError;
+}
+
+impl TryFrom<v1beta1::MsgSend> for SendRequest {
+    type Error = NftError;
+
+    fn try_from(msg: v1beta1::MsgSend) -> Result<Self, Self::Error> {
+        Ok(Self {
+            class_id: msg.class_id,
+            id: msg.id,
+            sender: msg.sender.parse()?,
+            receiver: msg.receiver.parse()?,
+        })
+    }
+}
+
+impl TryFrom<SendRequest> for v1beta1::MsgSend {
+    type Error = NftError;
+
+    fn try_from(req: SendRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            class_id: req.class_id,
+            id: req.id,
+            sender: req.sender.into(),
+            receiver: req.receiver.into(),
+        })
+    }
+}
@@ -0,0 +1,151 @@
+//! NFT protos (cosmos-sdk 0.46+), which are not part of the sdk protos we currently depend on.
+
+use cosmrs::proto::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
+use cosmrs::proto::traits::TypeUrl;
+use cosmrs::Any;
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Class {
+    #[prost(string, tag = "1")]
+    pub id: String,
+    #[prost(string, tag = "2")]
+    pub name: String,
+    #[prost(string, tag = "3")]
+    pub symbol: String,
+    #[prost(string, tag = "4")]
+    pub description: String,
+    #[prost(string, tag = "5")]
+    pub uri: String,
+    #[prost(string, tag = "6")]
+    pub uri_hash: String,
+    #[prost(message, optional, tag = "7")]
+    pub data: Option<Any>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Nft {
+    #[prost(string, tag = "1")]
+    pub class_id: String,
+    #[prost(string, tag = "2")]
+    pub id: String,
+    #[prost(string, tag = "3")]
+    pub uri: String,
+    #[prost(string, tag = "4")]
+    pub uri_hash: String,
+    #[prost(message, optional, tag = "10")]
+    pub data: Option<Any>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgSend {
+    #[prost(string, tag = "1")]
+    pub class_id: String,
+    #[prost(string, tag = "2")]
+    pub id: String,
+    #[prost(string, tag = "3")]
+    pub sender: String,
+    #[prost(string, tag = "4")]
+    pub receiver: String,
+}
+
+impl TypeUrl for MsgSend {
+    const TYPE_URL: &'static str = "/cosmos.nft.v1beta1.MsgSend";
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryBalanceRequest {
+    #[prost(string, tag = "1")]
+    pub class_id: String,
+    #[prost(string, tag = "2")]
+    pub owner: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryBalanceResponse {
+    #[prost(uint64, tag = "1")]
+    pub amount: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryOwnerRequest {
+    #[prost(string, tag = "1")]
+    pub class_id: String,
+    #[prost(string, tag = "2")]
+    pub id: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryOwnerResponse {
+    #[prost(string, tag = "1")]
+    pub owner: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QuerySupplyRequest {
+    #[prost(string, tag = "1")]
+    pub class_id: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QuerySupplyResponse {
+    #[prost(uint64, tag = "1")]
+    pub amount: u64,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryNftsRequest {
+    #[prost(string, tag = "1")]
+    pub class_id: String,
+    #[prost(string, tag = "2")]
+    pub owner: String,
+    #[prost(message, optional, tag = "3")]
+    pub pagination: Option<PageRequest>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryNftsResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub nfts: Vec<Nft>,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: Option<PageResponse>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryNftRequest {
+    #[prost(string, tag = "1")]
+    pub class_id: String,
+    #[prost(string, tag = "2")]
+    pub id: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryNftResponse {
+    #[prost(message, optional, tag = "1")]
+    pub nft: Option<Nft>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryClassRequest {
+    #[prost(string, tag = "1")]
+    pub class_id: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryClassResponse {
+    #[prost(message, optional, tag = "1")]
+    pub class: Option<Class>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryClassesRequest {
+    #[prost(message, optional, tag = "1")]
+    pub pagination: Option<PageRequest>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryClassesResponse {
+    #[prost(message, repeated, tag = "1")]
+    pub classes: Vec<Class>,
+    #[prost(message, optional, tag = "2")]
+    pub pagination: Option<PageResponse>,
+}
@@ -0,0 +1,221 @@
+use cosmrs::proto::cosmos::slashing::v1beta1::{
+    QueryParamsRequest, QueryParamsResponse, QuerySigningInfoRequest, QuerySigningInfoResponse,
+    QuerySigningInfosRequest, QuerySigningInfosResponse,
+};
+
+use crate::{
+    chain::request::PaginationRequest,
+    clients::client::{CosmTome, CosmosClient},
+    modules::auth::model::Address,
+};
+
+use super::{
+    error::SlashingError,
+    model::{ParamsResponse, SigningInfo, SigningInfoResponse, SigningInfosResponse},
+};
+
+impl<T: CosmosClient> CosmTome<T> {
+    /// Query the signing info of a validator from its consensus address, ie. `junovalcons1...`
+    pub async fn slashing_query_signing_info(
+        &self,
+        cons_address: Address,
+    ) -> Result<SigningInfoResponse, SlashingError> {
+        let req = QuerySigningInfoRequest {
+            cons_address: cons_address.into(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QuerySigningInfoResponse>(req, "/cosmos.slashing.v1beta1.Query/SigningInfo")
+            .await?;
+
+        Ok(SigningInfoResponse {
+            signing_info: res.val_signing_info.map(TryInto::try_into).transpose()?,
+        })
+    }
+
+    pub async fn slashing_query_signing_infos(
+        &self,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<SigningInfosResponse, SlashingError> {
+        let req = QuerySigningInfosRequest {
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QuerySigningInfosResponse>(
+                req,
+                "/cosmos.slashing.v1beta1.Query/SigningInfos",
+            )
+            .await?;
+
+        Ok(SigningInfosResponse {
+            signing_infos: res
+                .info
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<SigningInfo>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    pub async fn slashing_query_params(&self) -> Result<ParamsResponse, SlashingError> {
+        let req = QueryParamsRequest {};
+
+        let res = self
+            .client
+            .query::<_, QueryParamsResponse>(req, "/cosmos.slashing.v1beta1.Query/Params")
+            .await?;
+
+        Ok(ParamsResponse {
+            params: res.params.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use std::time::Duration;
+
+    use cosmrs::proto::cosmos::{
+        base::query::v1beta1::PageResponse,
+        slashing::v1beta1::{
+            Params, QueryParamsRequest, QueryParamsResponse, QuerySigningInfosRequest,
+            QuerySigningInfosResponse, ValidatorSigningInfo,
+        },
+    };
+
+    use crate::{
+        chain::request::{OffsetParams, PageID, PaginationRequest},
+        clients::client::{CosmTome, MockCosmosClient},
+        modules::slashing::error::SlashingError,
+        test_utils::test_cfg,
+    };
+
+    const VALCONS: &str = "junovalcons10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k3sx48s";
+
+    #[tokio::test]
+    async fn test_slashing_query_params() {
+        let cfg = test_cfg();
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QueryParamsRequest, QueryParamsResponse>()
+            .times(1)
+            .returning(move |_, path| {
+                assert_eq!(path, "/cosmos.slashing.v1beta1.Query/Params");
+
+                Ok(QueryParamsResponse {
+                    params: Some(Params {
+                        signed_blocks_window: 10000,
+                        min_signed_per_window: b"50000000000000000".to_vec(),
+                        downtime_jail_duration: Some(prost_types::Duration {
+                            seconds: 600,
+                            nanos: 0,
+                        }),
+                        slash_fraction_double_sign: b"50000000000000000".to_vec(),
+                        slash_fraction_downtime: b"100000000000000".to_vec(),
+                    }),
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let params = cosm_tome
+            .slashing_query_params()
+            .await
+            .unwrap()
+            .params
+            .unwrap();
+
+        assert_eq!(params.signed_blocks_window, 10000);
+        assert_eq!(params.min_signed_per_window, "0.05".parse().unwrap());
+        assert_eq!(params.slash_fraction_downtime, "0.0001".parse().unwrap());
+        assert_eq!(
+            params.downtime_jail_duration,
+            Some(Duration::from_secs(600))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_slashing_query_signing_infos_pagination() {
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QuerySigningInfosRequest, QuerySigningInfosResponse>()
+            .times(1)
+            .withf(|req, _| {
+                let page = req.pagination.clone().unwrap();
+                page.key.is_empty() && page.offset == 100 && page.count_total && page.limit == 1
+            })
+            .returning(|_, _| {
+                Ok(QuerySigningInfosResponse {
+                    info: vec![ValidatorSigningInfo {
+                        address: VALCONS.to_string(),
+                        start_height: 1,
+                        index_offset: 2,
+                        jailed_until: None,
+                        tombstoned: true,
+                        missed_blocks_counter: 3,
+                    }],
+                    pagination: Some(PageResponse {
+                        next_key: vec![],
+                        total: 101,
+                    }),
+                })
+            });
+
+        let cosm_tome = CosmTome::new(test_cfg(), mock_client);
+
+        let res = cosm_tome
+            .slashing_query_signing_infos(Some(PaginationRequest {
+                page: PageID::Offset(OffsetParams {
+                    offset: 100,
+                    count_total: true,
+                }),
+                limit: 1,
+                reverse: false,
+            }))
+            .await
+            .unwrap();
+
+        assert_eq!(res.signing_infos[0].address.to_string(), VALCONS);
+        assert!(res.signing_infos[0].tombstoned);
+        assert_eq!(res.signing_infos[0].jailed_until, None);
+
+        // the last page has no next key
+        let next = res.next.unwrap();
+        assert!(next.next_key.is_empty());
+        assert_eq!(next.total, 101);
+    }
+
+    #[tokio::test]
+    async fn test_slashing_query_signing_infos_invalid_address() {
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QuerySigningInfosRequest, QuerySigningInfosResponse>()
+            .times(1)
+            .returning(|_, _| {
+                Ok(QuerySigningInfosResponse {
+                    info: vec![ValidatorSigningInfo {
+                        address: "junovalcons1invalid".to_string(),
+                        ..Default::default()
+                    }],
+                    pagination: None,
+                })
+            });
+
+        let cosm_tome = CosmTome::new(test_cfg(), mock_client);
+
+        let err = cosm_tome
+            .slashing_query_signing_infos(None)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, SlashingError::AccountError(_)));
+    }
+}
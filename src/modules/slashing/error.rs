@@ -0,0 +1,12 @@
+use thiserror::Error;
+
+use crate::{chain::error::ChainError, modules::auth::error::AccountError};
+
+#[derive(Error, Debug)]
+pub enum SlashingError {
+    #[error(transparent)]
+    AccountError(#[from] AccountError),
+
+    #[error(transparent)]
+    ChainError(#[from] ChainError),
+}
@@ -0,0 +1,100 @@
+use std::time::Duration;
+
+use cosmrs::proto::cosmos::slashing::v1beta1::{
+    Params as ProtoParams, ValidatorSigningInfo as ProtoValidatorSigningInfo,
+};
+use cosmrs::tendermint::Time;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::chain::coin::Dec;
+use crate::chain::error::ChainError;
+use crate::chain::request::PaginationResponse;
+use crate::chain::time::{duration_from_proto, time_from_proto};
+use crate::modules::auth::model::Address;
+
+use super::error::SlashingError;
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct SigningInfoResponse {
+    pub signing_info: Option<SigningInfo>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct SigningInfosResponse {
+    pub signing_infos: Vec<SigningInfo>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+/// Liveness of a validator, as tracked by the slashing module
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct SigningInfo {
+    /// Consensus address of the validator, ie. `junovalcons1...`
+    pub address: Address,
+
+    /// Height at which the validator was first a candidate or was unjailed
+    pub start_height: i64,
+
+    /// Index of the current block in the signed blocks window
+    pub index_offset: i64,
+
+    /// Time until which the validator is jailed, the unix epoch if it was never jailed
+    #[schemars(with = "Option<String>")]
+    pub jailed_until: Option<Time>,
+
+    /// Whether the validator was tombstoned for double signing, in which case it cannot be unjailed
+    pub tombstoned: bool,
+
+    /// Number of blocks missed in the current signed blocks window
+    pub missed_blocks_counter: i64,
+}
+
+impl TryFrom<ProtoValidatorSigningInfo> for SigningInfo {
+    type Error = SlashingError;
+
+    fn try_from(info: ProtoValidatorSigningInfo) -> Result<Self, Self::Error> {
+        Ok(Self {
+            address: info.address.parse()?,
+            start_height: info.start_height,
+            index_offset: info.index_offset,
+            jailed_until: info.jailed_until.map(time_from_proto).transpose()?,
+            tombstoned: info.tombstoned,
+            missed_blocks_counter: info.missed_blocks_counter,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct ParamsResponse {
+    pub params: Option<Params>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct Params {
+    pub signed_blocks_window: i64,
+
+    /// Fraction of the signed blocks window a validator must sign to avoid being jailed
+    pub min_signed_per_window: Dec,
+
+    pub downtime_jail_duration: Option<Duration>,
+    pub slash_fraction_double_sign: Dec,
+    pub slash_fraction_downtime: Dec,
+}
+
+impl TryFrom<ProtoParams> for Params {
+    type Error = ChainError;
+
+    fn try_from(p: ProtoParams) -> Result<Self, Self::Error> {
+        Ok(Self {
+            signed_blocks_window: p.signed_blocks_window,
+            min_signed_per_window: Dec::from_atomics_bytes(p.min_signed_per_window)?,
+            downtime_jail_duration: p
+                .downtime_jail_duration
+                .map(duration_from_proto)
+                .transpose()?,
+            slash_fraction_double_sign: Dec::from_atomics_bytes(p.slash_fraction_double_sign)?,
+            slash_fraction_downtime: Dec::from_atomics_bytes(p.slash_fraction_downtime)?,
+        })
+    }
+}
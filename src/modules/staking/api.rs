@@ -0,0 +1,724 @@
+use cosmrs::proto::cosmos::staking::v1beta1::{
+    QueryDelegationRequest, QueryDelegationResponse, QueryDelegatorDelegationsRequest,
+    QueryDelegatorDelegationsResponse, QueryDelegatorUnbondingDelegationsRequest,
+    QueryDelegatorUnbondingDelegationsResponse, QueryDelegatorValidatorsRequest,
+    QueryDelegatorValidatorsResponse, QueryParamsRequest, QueryParamsResponse, QueryPoolRequest,
+    QueryPoolResponse, QueryRedelegationsRequest, QueryRedelegationsResponse,
+    QueryUnbondingDelegationRequest, QueryUnbondingDelegationResponse,
+    QueryValidatorDelegationsRequest, QueryValidatorDelegationsResponse, QueryValidatorRequest,
+    QueryValidatorResponse, QueryValidatorUnbondingDelegationsRequest,
+    QueryValidatorUnbondingDelegationsResponse, QueryValidatorsRequest, QueryValidatorsResponse,
+};
+
+use crate::{
+    chain::request::{PaginationRequest, TxOptions},
+    clients::client::{CosmTome, CosmosClient},
+    modules::auth::model::Address,
+    signing_key::key::SigningKey,
+};
+
+use super::{
+    error::StakingError,
+    model::{
+        BondStatus, CancelUnbondingRequest, DelegateRequest, DelegationResponse,
+        DelegationsResponse, ParamsResponse, PoolResponse, RedelegateRequest,
+        RedelegationsResponse, StakingTxResponse, UnbondingDelegationResponse,
+        UnbondingDelegationsResponse, UndelegateRequest, ValidatorResponse, ValidatorsResponse,
+    },
+};
+
+impl<T: CosmosClient> CosmTome<T> {
+    /// Delegate `amount` of the staking denom from `delegator` to `validator`
+    pub async fn staking_delegate(
+        &self,
+        req: DelegateRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<StakingTxResponse, StakingError> {
+        let res = self.tx_send(vec![req], key, tx_options).await?;
+
+        Ok(StakingTxResponse { res })
+    }
+
+    /// Begin unbonding `amount` of staked tokens from `validator`
+    pub async fn staking_undelegate(
+        &self,
+        req: UndelegateRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<StakingTxResponse, StakingError> {
+        let res = self.tx_send(vec![req], key, tx_options).await?;
+
+        Ok(StakingTxResponse { res })
+    }
+
+    /// Move `amount` of staked tokens from `validator_src` to `validator_dst` without unbonding
+    pub async fn staking_redelegate(
+        &self,
+        req: RedelegateRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<StakingTxResponse, StakingError> {
+        let res = self.tx_send(vec![req], key, tx_options).await?;
+
+        Ok(StakingTxResponse { res })
+    }
+
+    /// Cancel an in progress unbonding, delegating the tokens back to the validator.
+    /// Requires cosmos-sdk 0.46+ on chain.
+    pub async fn staking_cancel_unbonding(
+        &self,
+        req: CancelUnbondingRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<StakingTxResponse, StakingError> {
+        let res = self.tx_send(vec![req], key, tx_options).await?;
+
+        Ok(StakingTxResponse { res })
+    }
+
+    /// Query all validators, optionally filtered by `status`
+    pub async fn staking_query_validators(
+        &self,
+        status: Option<BondStatus>,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<ValidatorsResponse, StakingError> {
+        let req = QueryValidatorsRequest {
+            status: status
+                .map(|s| s.as_str_name().to_string())
+                .unwrap_or_default(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryValidatorsResponse>(req, "/cosmos.staking.v1beta1.Query/Validators")
+            .await?;
+
+        Ok(ValidatorsResponse {
+            validators: res
+                .validators
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query a single validator by its operator address
+    pub async fn staking_query_validator(
+        &self,
+        validator: Address,
+    ) -> Result<ValidatorResponse, StakingError> {
+        let req = QueryValidatorRequest {
+            validator_addr: validator.into(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryValidatorResponse>(req, "/cosmos.staking.v1beta1.Query/Validator")
+            .await?;
+
+        Ok(ValidatorResponse {
+            validator: res.validator.map(TryInto::try_into).transpose()?,
+        })
+    }
+
+    /// Query the delegation from `delegator` to `validator`
+    pub async fn staking_query_delegation(
+        &self,
+        delegator: Address,
+        validator: Address,
+    ) -> Result<DelegationResponse, StakingError> {
+        let req = QueryDelegationRequest {
+            delegator_addr: delegator.into(),
+            validator_addr: validator.into(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryDelegationResponse>(req, "/cosmos.staking.v1beta1.Query/Delegation")
+            .await?;
+
+        Ok(DelegationResponse {
+            delegation: res.delegation_response.map(TryInto::try_into).transpose()?,
+        })
+    }
+
+    /// Query all delegations made by `delegator`
+    pub async fn staking_query_delegations(
+        &self,
+        delegator: Address,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<DelegationsResponse, StakingError> {
+        let req = QueryDelegatorDelegationsRequest {
+            delegator_addr: delegator.into(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryDelegatorDelegationsResponse>(
+                req,
+                "/cosmos.staking.v1beta1.Query/DelegatorDelegations",
+            )
+            .await?;
+
+        Ok(DelegationsResponse {
+            delegations: res
+                .delegation_responses
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query all delegations made to `validator`
+    pub async fn staking_query_validator_delegations(
+        &self,
+        validator: Address,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<DelegationsResponse, StakingError> {
+        let req = QueryValidatorDelegationsRequest {
+            validator_addr: validator.into(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryValidatorDelegationsResponse>(
+                req,
+                "/cosmos.staking.v1beta1.Query/ValidatorDelegations",
+            )
+            .await?;
+
+        Ok(DelegationsResponse {
+            delegations: res
+                .delegation_responses
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query the unbonding delegation from `delegator` to `validator`
+    pub async fn staking_query_unbonding_delegation(
+        &self,
+        delegator: Address,
+        validator: Address,
+    ) -> Result<UnbondingDelegationResponse, StakingError> {
+        let req = QueryUnbondingDelegationRequest {
+            delegator_addr: delegator.into(),
+            validator_addr: validator.into(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryUnbondingDelegationResponse>(
+                req,
+                "/cosmos.staking.v1beta1.Query/UnbondingDelegation",
+            )
+            .await?;
+
+        Ok(UnbondingDelegationResponse {
+            unbond: res.unbond.map(TryInto::try_into).transpose()?,
+        })
+    }
+
+    /// Query all unbonding delegations of `delegator`
+    pub async fn staking_query_unbonding_delegations(
+        &self,
+        delegator: Address,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<UnbondingDelegationsResponse, StakingError> {
+        let req = QueryDelegatorUnbondingDelegationsRequest {
+            delegator_addr: delegator.into(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryDelegatorUnbondingDelegationsResponse>(
+                req,
+                "/cosmos.staking.v1beta1.Query/DelegatorUnbondingDelegations",
+            )
+            .await?;
+
+        Ok(UnbondingDelegationsResponse {
+            unbonds: res
+                .unbonding_responses
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query all unbonding delegations from `validator`
+    pub async fn staking_query_validator_unbonding_delegations(
+        &self,
+        validator: Address,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<UnbondingDelegationsResponse, StakingError> {
+        let req = QueryValidatorUnbondingDelegationsRequest {
+            validator_addr: validator.into(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryValidatorUnbondingDelegationsResponse>(
+                req,
+                "/cosmos.staking.v1beta1.Query/ValidatorUnbondingDelegations",
+            )
+            .await?;
+
+        Ok(UnbondingDelegationsResponse {
+            unbonds: res
+                .unbonding_responses
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query redelegations of `delegator`, optionally filtered by source and destination validator
+    pub async fn staking_query_redelegations(
+        &self,
+        delegator: Address,
+        validator_src: Option<Address>,
+        validator_dst: Option<Address>,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<RedelegationsResponse, StakingError> {
+        let req = QueryRedelegationsRequest {
+            delegator_addr: delegator.into(),
+            src_validator_addr: validator_src.map(Into::into).unwrap_or_default(),
+            dst_validator_addr: validator_dst.map(Into::into).unwrap_or_default(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryRedelegationsResponse>(
+                req,
+                "/cosmos.staking.v1beta1.Query/Redelegations",
+            )
+            .await?;
+
+        Ok(RedelegationsResponse {
+            redelegations: res
+                .redelegation_responses
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query all validators that `delegator` is delegated to
+    pub async fn staking_query_delegator_validators(
+        &self,
+        delegator: Address,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<ValidatorsResponse, StakingError> {
+        let req = QueryDelegatorValidatorsRequest {
+            delegator_addr: delegator.into(),
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryDelegatorValidatorsResponse>(
+                req,
+                "/cosmos.staking.v1beta1.Query/DelegatorValidators",
+            )
+            .await?;
+
+        Ok(ValidatorsResponse {
+            validators: res
+                .validators
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query the amount of bonded and not bonded tokens
+    pub async fn staking_query_pool(&self) -> Result<PoolResponse, StakingError> {
+        let req = QueryPoolRequest {};
+
+        let res = self
+            .client
+            .query::<_, QueryPoolResponse>(req, "/cosmos.staking.v1beta1.Query/Pool")
+            .await?;
+
+        Ok(PoolResponse {
+            pool: res.pool.map(TryInto::try_into).transpose()?,
+        })
+    }
+
+    /// Query staking module cosmos sdk params
+    pub async fn staking_query_params(&self) -> Result<ParamsResponse, StakingError> {
+        let req = QueryParamsRequest {};
+
+        let res = self
+            .client
+            .query::<_, QueryParamsResponse>(req, "/cosmos.staking.v1beta1.Query/Params")
+            .await?;
+
+        Ok(ParamsResponse {
+            params: res.params.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use cosmrs::proto::cosmos::{
+        auth::v1beta1::{BaseAccount, QueryAccountRequest, QueryAccountResponse},
+        base::query::v1beta1::PageResponse,
+        base::v1beta1::Coin as ProtoCoin,
+        staking::v1beta1::{
+            Delegation as ProtoDelegation, DelegationResponse as ProtoDelegationResponse,
+            MsgDelegate, QueryDelegatorDelegationsRequest, QueryDelegatorDelegationsResponse,
+            QueryValidatorsRequest, QueryValidatorsResponse, Validator as ProtoValidator,
+        },
+    };
+    use cosmrs::proto::traits::{MessageExt, TypeUrl};
+
+    use crate::{
+        chain::{
+            coin::Coin,
+            error::ChainError,
+            fee::GasInfo,
+            request::{PageID, PaginationRequest, PaginationResponse, TxOptions},
+            response::{ChainResponse, ChainTxResponse, Code},
+        },
+        clients::client::{CosmTome, MockCosmosClient},
+        modules::{
+            auth::model::BASE_ACCOUNT_TYPE_URL,
+            staking::{
+                error::StakingError,
+                model::{BondStatus, DelegateRequest, Delegation},
+            },
+            tx::error::TxError,
+        },
+        signing_key::key::SigningKey,
+        test_utils::test_cfg,
+    };
+
+    #[tokio::test]
+    async fn test_staking_delegate() {
+        let cfg = test_cfg();
+        let tx_options = TxOptions::default();
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QueryAccountRequest, QueryAccountResponse>()
+            .times(1)
+            .returning(move |_, _| {
+                Ok(QueryAccountResponse {
+                    account: Some(cosmrs::proto::Any {
+                        type_url: BASE_ACCOUNT_TYPE_URL.to_string(),
+                        value: BaseAccount {
+                            address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                            pub_key: None,
+                            account_number: 1337,
+                            sequence: 1,
+                        }
+                        .to_bytes()
+                        .unwrap(),
+                    }),
+                })
+            });
+
+        mock_client.expect_simulate_tx().times(1).returning(|_| {
+            Ok(GasInfo {
+                gas_wanted: 200u16.into(),
+                gas_used: 100u16.into(),
+            })
+        });
+
+        mock_client
+            .expect_broadcast_tx_block()
+            .times(1)
+            .withf(|tx| {
+                let tx = cosmrs::Tx::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+                tx.body.messages.len() == 1 && tx.body.messages[0].type_url == MsgDelegate::TYPE_URL
+            })
+            .returning(|_| {
+                Ok(ChainTxResponse {
+                    res: ChainResponse {
+                        code: Code::Ok,
+                        codespace: String::new(),
+                        data: None,
+                        log: "log log log".to_string(),
+                    },
+                    events: vec![],
+                    gas_wanted: 200,
+                    gas_used: 100,
+                    tx_hash: "TX_HASH_0".to_string(),
+                    height: 1337,
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg.clone(), mock_client);
+
+        let req = DelegateRequest {
+            delegator: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                .parse()
+                .unwrap(),
+            validator: "junovaloper10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k9r4ft3"
+                .parse()
+                .unwrap(),
+            amount: Coin {
+                denom: cfg.denom.parse().unwrap(),
+                amount: 10,
+            },
+        };
+
+        let res = cosm_tome
+            .staking_delegate(req, &key, &tx_options)
+            .await
+            .unwrap();
+
+        assert_eq!(res.res.tx_hash, "TX_HASH_0");
+    }
+
+    #[tokio::test]
+    async fn test_staking_query_delegations() {
+        let cfg = test_cfg();
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QueryDelegatorDelegationsRequest, QueryDelegatorDelegationsResponse>()
+            .times(1)
+            .returning(move |req, path| {
+                assert_eq!(path, "/cosmos.staking.v1beta1.Query/DelegatorDelegations");
+
+                Ok(QueryDelegatorDelegationsResponse {
+                    delegation_responses: vec![ProtoDelegationResponse {
+                        delegation: Some(ProtoDelegation {
+                            delegator_address: req.delegator_addr,
+                            validator_address: "junovaloper10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k9r4ft3"
+                                .to_string(),
+                            shares: "10000000000000000000".to_string(),
+                        }),
+                        balance: Some(ProtoCoin {
+                            denom: "ujuno".to_string(),
+                            amount: "10".to_string(),
+                        }),
+                    }],
+                    pagination: None,
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let res = cosm_tome
+            .staking_query_delegations(
+                "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                    .parse()
+                    .unwrap(),
+                None,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            res.delegations,
+            vec![Delegation {
+                delegator: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                    .parse()
+                    .unwrap(),
+                validator: "junovaloper10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k9r4ft3"
+                    .parse()
+                    .unwrap(),
+                shares: "10".parse().unwrap(),
+                balance: Some(Coin {
+                    denom: "ujuno".parse().unwrap(),
+                    amount: 10
+                }),
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_staking_delegate_empty_amount() {
+        let cfg = test_cfg();
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QueryAccountRequest, QueryAccountResponse>()
+            .times(1)
+            .returning(move |_, _| {
+                Ok(QueryAccountResponse {
+                    account: Some(cosmrs::proto::Any {
+                        type_url: BASE_ACCOUNT_TYPE_URL.to_string(),
+                        value: BaseAccount {
+                            address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                            pub_key: None,
+                            account_number: 1337,
+                            sequence: 1,
+                        }
+                        .to_bytes()
+                        .unwrap(),
+                    }),
+                })
+            });
+
+        // the msg is rejected before simulating or broadcasting the tx
+        mock_client.expect_simulate_tx().times(0);
+        mock_client.expect_broadcast_tx_block().times(0);
+
+        let cosm_tome = CosmTome::new(cfg.clone(), mock_client);
+
+        let req = DelegateRequest {
+            delegator: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                .parse()
+                .unwrap(),
+            validator: "junovaloper10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k9r4ft3"
+                .parse()
+                .unwrap(),
+            amount: Coin {
+                denom: cfg.denom.parse().unwrap(),
+                amount: 0,
+            },
+        };
+
+        let err = cosm_tome
+            .staking_delegate(req, &key, &TxOptions::default())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            StakingError::TxError(TxError::ChainError(ChainError::ProtoEncoding { message }))
+                if message == StakingError::EmptyAmount.to_string()
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_staking_query_validators_pagination() {
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QueryValidatorsRequest, QueryValidatorsResponse>()
+            .times(1)
+            .withf(|req, path| {
+                let page = req.pagination.clone().unwrap();
+
+                path == "/cosmos.staking.v1beta1.Query/Validators"
+                    && req.status == "BOND_STATUS_BONDED"
+                    && page.key == [1]
+                    && page.limit == 1
+                    && page.reverse
+            })
+            .returning(|_, _| {
+                Ok(QueryValidatorsResponse {
+                    validators: vec![ProtoValidator {
+                        operator_address: "junovaloper10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k9r4ft3"
+                            .to_string(),
+                        status: 3,
+                        tokens: "1000".to_string(),
+                        delegator_shares: "1000000000000000000000".to_string(),
+                        min_self_delegation: "1".to_string(),
+                        ..Default::default()
+                    }],
+                    pagination: Some(PageResponse {
+                        next_key: vec![2],
+                        total: 0,
+                    }),
+                })
+            });
+
+        let cosm_tome = CosmTome::new(test_cfg(), mock_client);
+
+        let res = cosm_tome
+            .staking_query_validators(
+                Some(BondStatus::Bonded),
+                Some(PaginationRequest {
+                    page: PageID::Key(vec![1]),
+                    limit: 1,
+                    reverse: true,
+                }),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res.validators.len(), 1);
+        assert_eq!(res.validators[0].status, BondStatus::Bonded);
+        assert_eq!(res.validators[0].tokens, 1000);
+        assert_eq!(
+            res.next,
+            Some(PaginationResponse {
+                next_key: vec![2],
+                total: 0,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_staking_query_validators_decode_failure() {
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QueryValidatorsRequest, QueryValidatorsResponse>()
+            .times(2)
+            .returning(|req, _| {
+                // an unknown bond status on the first page, a malformed token amount on the second
+                let (status, tokens) = match req.pagination {
+                    None => (42, "1000"),
+                    Some(_) => (3, "1000.5"),
+                };
+
+                Ok(QueryValidatorsResponse {
+                    validators: vec![ProtoValidator {
+                        operator_address: "junovaloper10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k9r4ft3"
+                            .to_string(),
+                        status,
+                        tokens: tokens.to_string(),
+                        delegator_shares: "1000000000000000000000".to_string(),
+                        min_self_delegation: "1".to_string(),
+                        ..Default::default()
+                    }],
+                    pagination: None,
+                })
+            });
+
+        let cosm_tome = CosmTome::new(test_cfg(), mock_client);
+
+        let err = cosm_tome
+            .staking_query_validators(None, None)
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, StakingError::BondStatus { i: 42 }));
+
+        let err = cosm_tome
+            .staking_query_validators(
+                None,
+                Some(PaginationRequest {
+                    page: PageID::Key(vec![1]),
+                    limit: 1,
+                    reverse: false,
+                }),
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            StakingError::ChainError(ChainError::ProtoDecoding { .. })
+        ));
+    }
+}
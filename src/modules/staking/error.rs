@@ -0,0 +1,24 @@
+use thiserror::Error;
+
+use crate::{
+    chain::error::ChainError,
+    modules::{auth::error::AccountError, tx::error::TxError},
+};
+
+#[derive(Error, Debug)]
+pub enum StakingError {
+    #[error("Cannot stake 0 amount of a token")]
+    EmptyAmount,
+
+    #[error("unsupported validator BondStatus: {i:?}")]
+    BondStatus { i: i32 },
+
+    #[error(transparent)]
+    TxError(#[from] TxError),
+
+    #[error(transparent)]
+    AccountError(#[from] AccountError),
+
+    #[error(transparent)]
+    ChainError(#[from] ChainError),
+}
@@ -0,0 +1,622 @@
+use std::time::Duration;
+
+use cosmrs::proto::cosmos::base::v1beta1::Coin as ProtoCoin;
+use cosmrs::proto::cosmos::staking::v1beta1::{
+    BondStatus as ProtoBondStatus, Commission as ProtoCommission,
+    CommissionRates as ProtoCommissionRates, DelegationResponse as ProtoDelegationResponse,
+    Description as ProtoDescription, MsgBeginRedelegate, MsgDelegate, MsgUndelegate,
+    Params as ProtoParams, Pool as ProtoPool, Redelegation as ProtoRedelegation,
+    RedelegationEntryResponse as ProtoRedelegationEntryResponse,
+    RedelegationResponse as ProtoRedelegationResponse,
+    UnbondingDelegation as ProtoUnbondingDelegation,
+    UnbondingDelegationEntry as ProtoUnbondingDelegationEntry, Validator as ProtoValidator,
+};
+use cosmrs::proto::traits::TypeUrl;
+use cosmrs::{crypto::PublicKey, tendermint::Time};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::chain::coin::{parse_int, Coin, Dec, Denom};
+use crate::chain::error::ChainError;
+use crate::chain::msg::Msg;
+use crate::chain::request::PaginationResponse;
+use crate::chain::response::ChainTxResponse;
+use crate::chain::time::{duration_from_proto, duration_to_proto, time_from_proto};
+use crate::modules::auth::model::Address;
+
+use super::error::StakingError;
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct StakingTxResponse {
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct ValidatorResponse {
+    pub validator: Option<Validator>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct ValidatorsResponse {
+    pub validators: Vec<Validator>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct DelegationResponse {
+    pub delegation: Option<Delegation>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct DelegationsResponse {
+    pub delegations: Vec<Delegation>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct UnbondingDelegationResponse {
+    pub unbond: Option<UnbondingDelegation>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct UnbondingDelegationsResponse {
+    pub unbonds: Vec<UnbondingDelegation>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct RedelegationsResponse {
+    pub redelegations: Vec<Redelegation>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct PoolResponse {
+    pub pool: Option<Pool>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct ParamsResponse {
+    pub params: Option<Params>,
+}
+
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub enum BondStatus {
+    Unspecified,
+    Unbonded,
+    Unbonding,
+    Bonded,
+}
+
+impl BondStatus {
+    /// String representation used by the `Validators` query filter
+    pub fn as_str_name(&self) -> &'static str {
+        ProtoBondStatus::from(*self).as_str_name()
+    }
+}
+
+impl TryFrom<i32> for BondStatus {
+    type Error = StakingError;
+
+    fn try_from(i: i32) -> Result<Self, Self::Error> {
+        match ProtoBondStatus::from_i32(i) {
+            Some(ProtoBondStatus::Unspecified) => Ok(BondStatus::Unspecified),
+            Some(ProtoBondStatus::Unbonded) => Ok(BondStatus::Unbonded),
+            Some(ProtoBondStatus::Unbonding) => Ok(BondStatus::Unbonding),
+            Some(ProtoBondStatus::Bonded) => Ok(BondStatus::Bonded),
+            None => Err(StakingError::BondStatus { i }),
+        }
+    }
+}
+
+impl From<BondStatus> for ProtoBondStatus {
+    fn from(status: BondStatus) -> Self {
+        match status {
+            BondStatus::Unspecified => ProtoBondStatus::Unspecified,
+            BondStatus::Unbonded => ProtoBondStatus::Unbonded,
+            BondStatus::Unbonding => ProtoBondStatus::Unbonding,
+            BondStatus::Bonded => ProtoBondStatus::Bonded,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct Validator {
+    /// Bech32 validator operator address (ie. `junovaloper1...`)
+    pub operator_address: Address,
+
+    #[schemars(with = "Option<serde_json::Value>")]
+    pub consensus_pubkey: Option<PublicKey>,
+
+    pub jailed: bool,
+
+    pub status: BondStatus,
+
+    pub tokens: u128,
+
+    pub delegator_shares: Dec,
+
+    pub description: Option<Description>,
+
+    pub unbonding_height: i64,
+
+    #[schemars(with = "Option<String>")]
+    pub unbonding_time: Option<Time>,
+
+    pub commission: Option<Commission>,
+
+    pub min_self_delegation: u128,
+}
+
+impl TryFrom<ProtoValidator> for Validator {
+    type Error = StakingError;
+
+    fn try_from(v: ProtoValidator) -> Result<Self, Self::Error> {
+        Ok(Self {
+            operator_address: v.operator_address.parse()?,
+            consensus_pubkey: v
+                .consensus_pubkey
+                .map(PublicKey::try_from)
+                .transpose()
+                .map_err(ChainError::crypto)?,
+            jailed: v.jailed,
+            status: v.status.try_into()?,
+            tokens: parse_int(&v.tokens)?,
+            delegator_shares: Dec::from_atomics(&v.delegator_shares)?,
+            description: v.description.map(Into::into),
+            unbonding_height: v.unbonding_height,
+            unbonding_time: v.unbonding_time.map(time_from_proto).transpose()?,
+            commission: v.commission.map(TryInto::try_into).transpose()?,
+            min_self_delegation: parse_int(&v.min_self_delegation)?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct Description {
+    pub moniker: String,
+    pub identity: String,
+    pub website: String,
+    pub security_contact: String,
+    pub details: String,
+}
+
+impl From<ProtoDescription> for Description {
+    fn from(d: ProtoDescription) -> Self {
+        Self {
+            moniker: d.moniker,
+            identity: d.identity,
+            website: d.website,
+            security_contact: d.security_contact,
+            details: d.details,
+        }
+    }
+}
+
+impl From<Description> for ProtoDescription {
+    fn from(d: Description) -> Self {
+        Self {
+            moniker: d.moniker,
+            identity: d.identity,
+            website: d.website,
+            security_contact: d.security_contact,
+            details: d.details,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct Commission {
+    pub rates: Option<CommissionRates>,
+
+    #[schemars(with = "Option<String>")]
+    pub update_time: Option<Time>,
+}
+
+impl TryFrom<ProtoCommission> for Commission {
+    type Error = ChainError;
+
+    fn try_from(c: ProtoCommission) -> Result<Self, Self::Error> {
+        Ok(Self {
+            rates: c.commission_rates.map(TryInto::try_into).transpose()?,
+            update_time: c.update_time.map(time_from_proto).transpose()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct CommissionRates {
+    pub rate: Dec,
+    pub max_rate: Dec,
+    pub max_change_rate: Dec,
+}
+
+impl TryFrom<ProtoCommissionRates> for CommissionRates {
+    type Error = ChainError;
+
+    fn try_from(c: ProtoCommissionRates) -> Result<Self, Self::Error> {
+        Ok(Self {
+            rate: Dec::from_atomics(&c.rate)?,
+            max_rate: Dec::from_atomics(&c.max_rate)?,
+            max_change_rate: Dec::from_atomics(&c.max_change_rate)?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct Delegation {
+    pub delegator: Address,
+
+    pub validator: Address,
+
+    pub shares: Dec,
+
+    pub balance: Option<Coin>,
+}
+
+impl TryFrom<ProtoDelegationResponse> for Delegation {
+    type Error = StakingError;
+
+    fn try_from(res: ProtoDelegationResponse) -> Result<Self, Self::Error> {
+        let delegation = res.delegation.unwrap_or_default();
+
+        Ok(Self {
+            delegator: delegation.delegator_address.parse()?,
+            validator: delegation.validator_address.parse()?,
+            shares: Dec::from_atomics(&delegation.shares)?,
+            balance: res.balance.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct UnbondingDelegation {
+    pub delegator: Address,
+
+    pub validator: Address,
+
+    pub entries: Vec<UnbondingDelegationEntry>,
+}
+
+impl TryFrom<ProtoUnbondingDelegation> for UnbondingDelegation {
+    type Error = StakingError;
+
+    fn try_from(u: ProtoUnbondingDelegation) -> Result<Self, Self::Error> {
+        Ok(Self {
+            delegator: u.delegator_address.parse()?,
+            validator: u.validator_address.parse()?,
+            entries: u
+                .entries
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct UnbondingDelegationEntry {
+    /// Height at which the unbonding took place, needed to cancel it
+    pub creation_height: i64,
+
+    #[schemars(with = "Option<String>")]
+    pub completion_time: Option<Time>,
+
+    pub initial_balance: u128,
+
+    pub balance: u128,
+}
+
+impl TryFrom<ProtoUnbondingDelegationEntry> for UnbondingDelegationEntry {
+    type Error = ChainError;
+
+    fn try_from(e: ProtoUnbondingDelegationEntry) -> Result<Self, Self::Error> {
+        Ok(Self {
+            creation_height: e.creation_height,
+            completion_time: e.completion_time.map(time_from_proto).transpose()?,
+            initial_balance: parse_int(&e.initial_balance)?,
+            balance: parse_int(&e.balance)?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct Redelegation {
+    pub delegator: Address,
+
+    pub validator_src: Address,
+
+    pub validator_dst: Address,
+
+    pub entries: Vec<RedelegationEntry>,
+}
+
+impl TryFrom<ProtoRedelegationResponse> for Redelegation {
+    type Error = StakingError;
+
+    fn try_from(res: ProtoRedelegationResponse) -> Result<Self, Self::Error> {
+        let redelegation: ProtoRedelegation = res.redelegation.unwrap_or_default();
+
+        Ok(Self {
+            delegator: redelegation.delegator_address.parse()?,
+            validator_src: redelegation.validator_src_address.parse()?,
+            validator_dst: redelegation.validator_dst_address.parse()?,
+            entries: res
+                .entries
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct RedelegationEntry {
+    pub creation_height: i64,
+
+    #[schemars(with = "Option<String>")]
+    pub completion_time: Option<Time>,
+
+    pub initial_balance: u128,
+
+    pub shares_dst: Dec,
+
+    pub balance: u128,
+}
+
+impl TryFrom<ProtoRedelegationEntryResponse> for RedelegationEntry {
+    type Error = ChainError;
+
+    fn try_from(res: ProtoRedelegationEntryResponse) -> Result<Self, Self::Error> {
+        let entry = res.redelegation_entry.unwrap_or_default();
+
+        Ok(Self {
+            creation_height: entry.creation_height,
+            completion_time: entry.completion_time.map(time_from_proto).transpose()?,
+            initial_balance: parse_int(&entry.initial_balance)?,
+            shares_dst: Dec::from_atomics(&entry.shares_dst)?,
+            balance: parse_int(&res.balance)?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct Pool {
+    pub not_bonded_tokens: u128,
+    pub bonded_tokens: u128,
+}
+
+impl TryFrom<ProtoPool> for Pool {
+    type Error = ChainError;
+
+    fn try_from(p: ProtoPool) -> Result<Self, Self::Error> {
+        Ok(Self {
+            not_bonded_tokens: parse_int(&p.not_bonded_tokens)?,
+            bonded_tokens: parse_int(&p.bonded_tokens)?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct Params {
+    pub unbonding_time: Option<Duration>,
+    pub max_validators: u32,
+    pub max_entries: u32,
+    pub historical_entries: u32,
+    pub bond_denom: Denom,
+}
+
+impl TryFrom<ProtoParams> for Params {
+    type Error = ChainError;
+
+    fn try_from(p: ProtoParams) -> Result<Self, Self::Error> {
+        Ok(Self {
+            unbonding_time: p.unbonding_time.map(duration_from_proto).transpose()?,
+            max_validators: p.max_validators,
+            max_entries: p.max_entries,
+            historical_entries: p.historical_entries,
+            bond_denom: p.bond_denom.parse()?,
+        })
+    }
+}
+
+impl From<Params> for ProtoParams {
+    fn from(p: Params) -> Self {
+        Self {
+            unbonding_time: p.unbonding_time.map(duration_to_proto),
+            max_validators: p.max_validators,
+            max_entries: p.max_entries,
+            historical_entries: p.historical_entries,
+            bond_denom: p.bond_denom.into(),
+        }
+    }
+}
+
+fn staked_amount(amount: Coin) -> Result<ProtoCoin, StakingError> {
+    if amount.amount == 0 {
+        return Err(StakingError::EmptyAmount);
+    }
+
+    Ok(amount.into())
+}
+
+fn required_amount(amount: Option<ProtoCoin>) -> Result<Coin, StakingError> {
+    Ok(amount.ok_or(StakingError::EmptyAmount)?.try_into()?)
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct DelegateRequest {
+    pub delegator: Address,
+    pub validator: Address,
+    pub amount: Coin,
+}
+
+impl Msg for DelegateRequest {
+    type Proto = MsgDelegate;
+    type Err = StakingError;
+}
+
+impl TryFrom<MsgDelegate> for DelegateRequest {
+    type Error = StakingError;
+
+    fn try_from(msg: MsgDelegate) -> Result<Self, Self::Error> {
+        Ok(Self {
+            delegator: msg.delegator_address.parse()?,
+            validator: msg.validator_address.parse()?,
+            amount: required_amount(msg.amount)?,
+        })
+    }
+}
+
+impl TryFrom<DelegateRequest> for MsgDelegate {
+    type Error = StakingError;
+
+    fn try_from(req: DelegateRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            delegator_address: req.delegator.into(),
+            validator_address: req.validator.into(),
+            amount: Some(staked_amount(req.amount)?),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct UndelegateRequest {
+    pub delegator: Address,
+    pub validator: Address,
+    pub amount: Coin,
+}
+
+impl Msg for UndelegateRequest {
+    type Proto = MsgUndelegate;
+    type Err = StakingError;
+}
+
+impl TryFrom<MsgUndelegate> for UndelegateRequest {
+    type Error = StakingError;
+
+    fn try_from(msg: MsgUndelegate) -> Result<Self, Self::Error> {
+        Ok(Self {
+            delegator: msg.delegator_address.parse()?,
+            validator: msg.validator_address.parse()?,
+            amount: required_amount(msg.amount)?,
+        })
+    }
+}
+
+impl TryFrom<UndelegateRequest> for MsgUndelegate {
+    type Error = StakingError;
+
+    fn try_from(req: UndelegateRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            delegator_address: req.delegator.into(),
+            validator_address: req.validator.into(),
+            amount: Some(staked_amount(req.amount)?),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct RedelegateRequest {
+    pub delegator: Address,
+    pub validator_src: Address,
+    pub validator_dst: Address,
+    pub amount: Coin,
+}
+
+impl Msg for RedelegateRequest {
+    type Proto = MsgBeginRedelegate;
+    type Err = StakingError;
+}
+
+impl TryFrom<MsgBeginRedelegate> for RedelegateRequest {
+    type Error = StakingError;
+
+    fn try_from(msg: MsgBeginRedelegate) -> Result<Self, Self::Error> {
+        Ok(Self {
+            delegator: msg.delegator_address.parse()?,
+            validator_src: msg.validator_src_address.parse()?,
+            validator_dst: msg.validator_dst_address.parse()?,
+            amount: required_amount(msg.amount)?,
+        })
+    }
+}
+
+impl TryFrom<RedelegateRequest> for MsgBeginRedelegate {
+    type Error = StakingError;
+
+    fn try_from(req: RedelegateRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            delegator_address: req.delegator.into(),
+            validator_src_address: req.validator_src.into(),
+            validator_dst_address: req.validator_dst.into(),
+            amount: Some(staked_amount(req.amount)?),
+        })
+    }
+}
+
+/// `MsgCancelUnbondingDelegation` was added in cosmos-sdk 0.46,
+/// so it is not part of the sdk protos we currently depend on.
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgCancelUnbondingDelegation {
+    #[prost(string, tag = "1")]
+    pub delegator_address: String,
+    #[prost(string, tag = "2")]
+    pub validator_address: String,
+    #[prost(message, optional, tag = "3")]
+    pub amount: Option<ProtoCoin>,
+    #[prost(int64, tag = "4")]
+    pub creation_height: i64,
+}
+
+impl TypeUrl for MsgCancelUnbondingDelegation {
+    const TYPE_URL: &'static str = "/cosmos.staking.v1beta1.MsgCancelUnbondingDelegation";
+}
+
+/// Cancels an unbonding delegation, re-delegating `amount` back to `validator`.
+///
+/// Since: cosmos-sdk 0.46
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct CancelUnbondingRequest {
+    pub delegator: Address,
+    pub validator: Address,
+    pub amount: Coin,
+
+    /// `creation_height` of the `UnbondingDelegationEntry` being canceled
+    pub creation_height: i64,
+}
+
+impl Msg for CancelUnbondingRequest {
+    type Proto = MsgCancelUnbondingDelegation;
+    type Err = StakingError;
+}
+
+impl TryFrom<MsgCancelUnbondingDelegation> for CancelUnbondingRequest {
+    type Error = StakingError;
+
+    fn try_from(msg: MsgCancelUnbondingDelegation) -> Result<Self, Self::Error> {
+        Ok(Self {
+            delegator: msg.delegator_address.parse()?,
+            validator: msg.validator_address.parse()?,
+            amount: required_amount(msg.amount)?,
+            creation_height: msg.creation_height,
+        })
+    }
+}
+
+impl TryFrom<CancelUnbondingRequest> for MsgCancelUnbondingDelegation {
+    type Error = StakingError;
+
+    fn try_from(req: CancelUnbondingRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            delegator_address: req.delegator.into(),
+            validator_address: req.validator.into(),
+            amount: Some(staked_amount(req.amount)?),
+            creation_height: req.creation_height,
+        })
+    }
+}
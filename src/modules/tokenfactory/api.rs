@@ -0,0 +1,389 @@
+use crate::{
+    chain::{coin::Denom, request::TxOptions},
+    clients::client::{CosmTome, CosmosClient},
+    signing_key::key::SigningKey,
+};
+
+use super::{
+    error::TokenFactoryError,
+    model::{
+        split_factory_denom, BurnRequest, ChangeAdminRequest, CreateDenomRequest,
+        CreateDenomResponse, DenomAuthorityResponse, MintRequest, ParamsResponse,
+        SetDenomMetadataRequest, TokenFactoryTxResponse, INJECTIVE_PACKAGE,
+    },
+    v1beta1::{
+        InjectiveQueryDenomAuthorityMetadataRequest, QueryDenomAuthorityMetadataRequest,
+        QueryDenomAuthorityMetadataResponse, QueryParamsRequest, QueryParamsResponse,
+    },
+};
+
+/// Every token factory call takes the proto `package` of the chain's fork of the module,
+/// ie. `OSMOSIS_PACKAGE` for Osmosis, Juno and Neutron.
+impl<T: CosmosClient> CosmTome<T> {
+    pub async fn tokenfactory_create_denom(
+        &self,
+        package: &str,
+        req: CreateDenomRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<CreateDenomResponse, TokenFactoryError> {
+        let denom = format!("factory/{}/{}", req.sender, req.subdenom).parse()?;
+
+        let res = self
+            .tx_send_any(vec![req.into_any(package)], key, tx_options)
+            .await?;
+
+        Ok(CreateDenomResponse { denom, res })
+    }
+
+    pub async fn tokenfactory_mint(
+        &self,
+        package: &str,
+        req: MintRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<TokenFactoryTxResponse, TokenFactoryError> {
+        let res = self
+            .tx_send_any(vec![req.into_any(package)], key, tx_options)
+            .await?;
+
+        Ok(TokenFactoryTxResponse { res })
+    }
+
+    pub async fn tokenfactory_burn(
+        &self,
+        package: &str,
+        req: BurnRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<TokenFactoryTxResponse, TokenFactoryError> {
+        let res = self
+            .tx_send_any(vec![req.into_any(package)], key, tx_options)
+            .await?;
+
+        Ok(TokenFactoryTxResponse { res })
+    }
+
+    pub async fn tokenfactory_change_admin(
+        &self,
+        package: &str,
+        req: ChangeAdminRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<TokenFactoryTxResponse, TokenFactoryError> {
+        let res = self
+            .tx_send_any(vec![req.into_any(package)], key, tx_options)
+            .await?;
+
+        Ok(TokenFactoryTxResponse { res })
+    }
+
+    pub async fn tokenfactory_set_denom_metadata(
+        &self,
+        package: &str,
+        req: SetDenomMetadataRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<TokenFactoryTxResponse, TokenFactoryError> {
+        let res = self
+            .tx_send_any(vec![req.into_any(package)], key, tx_options)
+            .await?;
+
+        Ok(TokenFactoryTxResponse { res })
+    }
+
+    /// Query the admin of a token factory `denom`.
+    /// Fails with `TokenFactoryError::NotFactoryDenom` on Injective, if `denom` is not a `factory/{creator}/{subdenom}` denom.
+    pub async fn tokenfactory_query_denom_authority(
+        &self,
+        package: &str,
+        denom: &Denom,
+    ) -> Result<DenomAuthorityResponse, TokenFactoryError> {
+        let path = format!("/{package}.Query/DenomAuthorityMetadata");
+
+        let res = if package == INJECTIVE_PACKAGE {
+            let (creator, sub_denom) = split_factory_denom(denom)?;
+            let req = InjectiveQueryDenomAuthorityMetadataRequest { creator, sub_denom };
+
+            self.client
+                .query::<_, QueryDenomAuthorityMetadataResponse>(req, &path)
+                .await?
+        } else {
+            let req = QueryDenomAuthorityMetadataRequest {
+                denom: denom.to_string(),
+            };
+
+            self.client
+                .query::<_, QueryDenomAuthorityMetadataResponse>(req, &path)
+                .await?
+        };
+
+        Ok(DenomAuthorityResponse {
+            admin: res
+                .authority_metadata
+                .map(|m| m.admin)
+                .filter(|a| !a.is_empty())
+                .map(|a| a.parse())
+                .transpose()?,
+        })
+    }
+
+    pub async fn tokenfactory_query_params(
+        &self,
+        package: &str,
+    ) -> Result<ParamsResponse, TokenFactoryError> {
+        let req = QueryParamsRequest {};
+
+        let res = self
+            .client
+            .query::<_, QueryParamsResponse>(req, &format!("/{package}.Query/Params"))
+            .await?;
+
+        Ok(ParamsResponse {
+            params: res.params.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use cosmrs::proto::cosmos::auth::v1beta1::{
+        BaseAccount, QueryAccountRequest, QueryAccountResponse,
+    };
+    use cosmrs::proto::traits::{Message, MessageExt};
+
+    use crate::{
+        chain::{
+            coin::Coin,
+            fee::GasInfo,
+            request::TxOptions,
+            response::{ChainResponse, ChainTxResponse, Code},
+        },
+        clients::client::{CosmTome, MockCosmosClient},
+        config::cfg::ChainConfig,
+        modules::{
+            auth::model::{Address, BASE_ACCOUNT_TYPE_URL},
+            tokenfactory::{
+                error::TokenFactoryError,
+                model::{MintRequest, INJECTIVE_PACKAGE, KUJIRA_PACKAGE, OSMOSIS_PACKAGE},
+                v1beta1::{
+                    DenomAuthorityMetadata, InjectiveQueryDenomAuthorityMetadataRequest, MsgMint,
+                    QueryDenomAuthorityMetadataRequest, QueryDenomAuthorityMetadataResponse,
+                },
+            },
+            tx::model::RawTx,
+        },
+        signing_key::key::SigningKey,
+        test_utils::test_cfg,
+    };
+
+    #[tokio::test]
+    async fn test_tokenfactory_mint() {
+        let cfg = test_cfg();
+        let tx_options = TxOptions::default();
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
+        let sender = key.to_addr(&cfg.prefix).await.unwrap();
+        let denom = format!("factory/{sender}/tome");
+
+        let recipient: Address = "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+            .parse()
+            .unwrap();
+
+        let mut mock_client = MockCosmosClient::new();
+
+        let account_addr = sender.to_string();
+        mock_client
+            .expect_query::<QueryAccountRequest, QueryAccountResponse>()
+            .times(1)
+            .returning(move |_, _| {
+                Ok(QueryAccountResponse {
+                    account: Some(cosmrs::proto::Any {
+                        type_url: BASE_ACCOUNT_TYPE_URL.to_string(),
+                        value: BaseAccount {
+                            address: account_addr.clone(),
+                            pub_key: None,
+                            account_number: 1337,
+                            sequence: 1,
+                        }
+                        .to_bytes()
+                        .unwrap(),
+                    }),
+                })
+            });
+
+        mock_client.expect_simulate_tx().times(1).returning(|_| {
+            Ok(GasInfo {
+                gas_wanted: 200u16.into(),
+                gas_used: 100u16.into(),
+            })
+        });
+
+        let expected_sender = sender.to_string();
+        let expected_denom = denom.clone();
+        mock_client
+            .expect_broadcast_tx_block()
+            .times(1)
+            .withf(move |tx: &RawTx| {
+                let tx = cosmrs::Tx::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+                let msg = MsgMint::decode(tx.body.messages[0].value.as_slice()).unwrap();
+                let amount = msg.amount.unwrap();
+
+                tx.body.messages[0].type_url == "/injective.tokenfactory.v1beta1.MsgMint"
+                    && msg.sender == expected_sender
+                    && msg.mint_to_address == "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                    && amount.denom == expected_denom
+                    && amount.amount == "1000"
+            })
+            .returning(|_| {
+                Ok(ChainTxResponse {
+                    res: ChainResponse {
+                        code: Code::Ok,
+                        codespace: String::new(),
+                        data: None,
+                        log: "log log log".to_string(),
+                    },
+                    events: vec![],
+                    gas_wanted: 200,
+                    gas_used: 100,
+                    tx_hash: "TX_HASH_0".to_string(),
+                    height: 1337,
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let req = MintRequest {
+            sender,
+            amount: Coin {
+                denom: denom.parse().unwrap(),
+                amount: 1000,
+            },
+            mint_to: Some(recipient),
+        };
+
+        let res = cosm_tome
+            .tokenfactory_mint(INJECTIVE_PACKAGE, req, &key, &tx_options)
+            .await
+            .unwrap();
+
+        assert_eq!(res.res.tx_hash, "TX_HASH_0");
+    }
+
+    fn authority_response(admin: &str) -> QueryDenomAuthorityMetadataResponse {
+        QueryDenomAuthorityMetadataResponse {
+            authority_metadata: Some(DenomAuthorityMetadata {
+                admin: admin.to_string(),
+            }),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tokenfactory_query_denom_authority_osmosis() {
+        let cfg = ChainConfig {
+            denom: "uosmo".to_string(),
+            prefix: "osmo".to_string(),
+            ..test_cfg()
+        };
+        let admin = "osmo1cyyzpxplxdzkeea7kwsydadg87357qnahakaks";
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QueryDenomAuthorityMetadataRequest, QueryDenomAuthorityMetadataResponse>()
+            .times(1)
+            .withf(move |req, path| {
+                req.denom == format!("factory/{admin}/tome")
+                    && path == "/osmosis.tokenfactory.v1beta1.Query/DenomAuthorityMetadata"
+            })
+            .returning(move |_, _| Ok(authority_response(admin)));
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let res = cosm_tome
+            .tokenfactory_query_denom_authority(
+                OSMOSIS_PACKAGE,
+                &format!("factory/{admin}/tome").parse().unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res.admin, Some(admin.parse().unwrap()));
+    }
+
+    #[tokio::test]
+    async fn test_tokenfactory_query_denom_authority_injective() {
+        let cfg = ChainConfig {
+            denom: "inj".to_string(),
+            prefix: "inj".to_string(),
+            derivation_path: "m/44'/60'/0'/0/0".to_string(),
+            ..test_cfg()
+        };
+        let creator = "inj1cml96vmptgw99syqrrz8az79xer2pcgp0a885r";
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<InjectiveQueryDenomAuthorityMetadataRequest, QueryDenomAuthorityMetadataResponse>()
+            .times(1)
+            .withf(move |req, path| {
+                req.creator == creator
+                    && req.sub_denom == "tome"
+                    && path == "/injective.tokenfactory.v1beta1.Query/DenomAuthorityMetadata"
+            })
+            // a renounced admin is returned as an empty string
+            .returning(|_, _| Ok(authority_response("")));
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let res = cosm_tome
+            .tokenfactory_query_denom_authority(
+                INJECTIVE_PACKAGE,
+                &format!("factory/{creator}/tome").parse().unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res.admin, None);
+
+        let err = cosm_tome
+            .tokenfactory_query_denom_authority(INJECTIVE_PACKAGE, &"inj".parse().unwrap())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, TokenFactoryError::NotFactoryDenom { denom } if denom == "inj"));
+    }
+
+    #[tokio::test]
+    async fn test_tokenfactory_query_denom_authority_kujira() {
+        let cfg = ChainConfig {
+            denom: "ukuji".to_string(),
+            prefix: "kujira".to_string(),
+            ..test_cfg()
+        };
+        let admin = "kujira1cyyzpxplxdzkeea7kwsydadg87357qnaww84dg";
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QueryDenomAuthorityMetadataRequest, QueryDenomAuthorityMetadataResponse>()
+            .times(1)
+            .withf(move |req, path| {
+                req.denom == format!("factory/{admin}/tome")
+                    && path == "/kujira.denom.Query/DenomAuthorityMetadata"
+            })
+            .returning(move |_, _| Ok(authority_response(admin)));
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let res = cosm_tome
+            .tokenfactory_query_denom_authority(
+                KUJIRA_PACKAGE,
+                &format!("factory/{admin}/tome").parse().unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res.admin, Some(admin.parse().unwrap()));
+    }
+}
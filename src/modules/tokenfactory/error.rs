@@ -0,0 +1,21 @@
+use thiserror::Error;
+
+use crate::{
+    chain::error::ChainError,
+    modules::{auth::error::AccountError, tx::error::TxError},
+};
+
+#[derive(Error, Debug)]
+pub enum TokenFactoryError {
+    #[error("{denom} is not a token factory denom (factory/{{creator}}/{{subdenom}})")]
+    NotFactoryDenom { denom: String },
+
+    #[error(transparent)]
+    TxError(#[from] TxError),
+
+    #[error(transparent)]
+    AccountError(#[from] AccountError),
+
+    #[error(transparent)]
+    ChainError(#[from] ChainError),
+}
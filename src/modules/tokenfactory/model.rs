@@ -0,0 +1,310 @@
+use cosmrs::Any;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::chain::coin::{Coin, Denom};
+use crate::chain::error::ChainError;
+use crate::chain::msg::encode_any;
+use crate::chain::response::ChainTxResponse;
+use crate::modules::auth::model::Address;
+use crate::modules::bank::model::DenomMetadata;
+
+use super::error::TokenFactoryError;
+use super::v1beta1;
+
+/// Proto package of the osmosis token factory, also used by Juno and Neutron
+pub const OSMOSIS_PACKAGE: &str = "osmosis.tokenfactory.v1beta1";
+
+/// Injective's fork queries the denom authority by creator and subdenom instead of by denom
+pub const INJECTIVE_PACKAGE: &str = "injective.tokenfactory.v1beta1";
+
+/// Kujira's fork does not support setting denom metadata, nor burning from another address
+pub const KUJIRA_PACKAGE: &str = "kujira.denom";
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct TokenFactoryTxResponse {
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct CreateDenomResponse {
+    /// Full denom of the created token, ie. `factory/{sender}/{subdenom}`
+    pub denom: Denom,
+
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct DenomAuthorityResponse {
+    /// `None` if the admin was renounced
+    pub admin: Option<Address>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct ParamsResponse {
+    pub params: Option<Params>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct Params {
+    /// Fee charged to the sender of `MsgCreateDenom`
+    pub denom_creation_fee: Vec<Coin>,
+}
+
+impl TryFrom<v1beta1::Params> for Params {
+    type Error = ChainError;
+
+    fn try_from(params: v1beta1::Params) -> Result<Self, Self::Error> {
+        Ok(Self {
+            denom_creation_fee: params
+                .denom_creation_fee
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+        })
+    }
+}
+
+fn type_url(package: &str, msg: &str) -> String {
+    format!("/{package}.{msg}")
+}
+
+/// Splits a `factory/{creator}/{subdenom}` denom into its creator and subdenom
+pub(crate) fn split_factory_denom(denom: &Denom) -> Result<(String, String), TokenFactoryError> {
+    let denom = denom.to_string();
+
+    match denom.splitn(3, '/').collect::<Vec<_>>()[..] {
+        ["factory", creator, subdenom] if !creator.is_empty() && !subdenom.is_empty() => {
+            Ok((creator.to_string(), subdenom.to_string()))
+        }
+        _ => Err(TokenFactoryError::NotFactoryDenom { denom }),
+    }
+}
+
+/// Creates the denom `factory/{sender}/{subdenom}`, administered by `sender`
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct CreateDenomRequest {
+    pub sender: Address,
+    pub subdenom: String,
+}
+
+impl CreateDenomRequest {
+    /// Encodes the msg of the token factory fork under `package`, ie. to send it along other msgs with `tx_send_any()`.
+    ///
+    /// The token factory requests don't implement `Msg`, since their type url depends on the fork
+    /// they are sent to, which is only known at runtime.
+    pub fn into_any(self, package: &str) -> Any {
+        encode_any(
+            &type_url(package, "MsgCreateDenom"),
+            &v1beta1::MsgCreateDenom {
+                sender: self.sender.into(),
+                subdenom: self.subdenom,
+            },
+        )
+    }
+}
+
+/// Mints `amount` of a denom administered by `sender`, to `mint_to` or to `sender` if `None`.
+/// Older versions of the module do not support `mint_to`.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct MintRequest {
+    pub sender: Address,
+    pub amount: Coin,
+    pub mint_to: Option<Address>,
+}
+
+impl MintRequest {
+    /// Encodes the msg of the token factory fork under `package`, see `CreateDenomRequest::into_any()`
+    pub fn into_any(self, package: &str) -> Any {
+        encode_any(
+            &type_url(package, "MsgMint"),
+            &v1beta1::MsgMint {
+                sender: self.sender.into(),
+                amount: Some(self.amount.into()),
+                mint_to_address: self.mint_to.map(Into::into).unwrap_or_default(),
+            },
+        )
+    }
+}
+
+/// Burns `amount` of a denom administered by `sender`, from `burn_from` or from `sender` if `None`.
+/// Older versions of the module do not support `burn_from`.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct BurnRequest {
+    pub sender: Address,
+    pub amount: Coin,
+    pub burn_from: Option<Address>,
+}
+
+impl BurnRequest {
+    /// Encodes the msg of the token factory fork under `package`, see `CreateDenomRequest::into_any()`
+    pub fn into_any(self, package: &str) -> Any {
+        encode_any(
+            &type_url(package, "MsgBurn"),
+            &v1beta1::MsgBurn {
+                sender: self.sender.into(),
+                amount: Some(self.amount.into()),
+                burn_from_address: self.burn_from.map(Into::into).unwrap_or_default(),
+            },
+        )
+    }
+}
+
+/// Transfers the admin of `denom` from `sender` to `new_admin`
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct ChangeAdminRequest {
+    pub sender: Address,
+    pub denom: Denom,
+    pub new_admin: Address,
+}
+
+impl ChangeAdminRequest {
+    /// Encodes the msg of the token factory fork under `package`, see `CreateDenomRequest::into_any()`
+    pub fn into_any(self, package: &str) -> Any {
+        encode_any(
+            &type_url(package, "MsgChangeAdmin"),
+            &v1beta1::MsgChangeAdmin {
+                sender: self.sender.into(),
+                denom: self.denom.to_string(),
+                new_admin: self.new_admin.into(),
+            },
+        )
+    }
+}
+
+/// Sets the bank metadata of the denom `metadata.base`, administered by `sender`
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct SetDenomMetadataRequest {
+    pub sender: Address,
+    pub metadata: DenomMetadata,
+}
+
+impl SetDenomMetadataRequest {
+    /// Encodes the msg of the token factory fork under `package`, see `CreateDenomRequest::into_any()`
+    pub fn into_any(self, package: &str) -> Any {
+        encode_any(
+            &type_url(package, "MsgSetDenomMetadata"),
+            &v1beta1::MsgSetDenomMetadata {
+                sender: self.sender.into(),
+                metadata: Some(self.metadata.into()),
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmrs::proto::traits::Message;
+
+    use crate::{
+        chain::coin::Coin,
+        modules::{auth::model::Address, tokenfactory::v1beta1},
+    };
+
+    use super::{
+        split_factory_denom, BurnRequest, ChangeAdminRequest, CreateDenomRequest, MintRequest,
+        INJECTIVE_PACKAGE, KUJIRA_PACKAGE, OSMOSIS_PACKAGE,
+    };
+
+    const SENDER: &str = "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg";
+    const DENOM: &str = "factory/juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg/tome";
+
+    fn sender() -> Address {
+        SENDER.parse().unwrap()
+    }
+
+    fn tome(amount: u128) -> Coin {
+        Coin {
+            denom: DENOM.parse().unwrap(),
+            amount,
+        }
+    }
+
+    #[test]
+    fn test_osmosis_package() {
+        let any = CreateDenomRequest {
+            sender: sender(),
+            subdenom: "tome".to_string(),
+        }
+        .into_any(OSMOSIS_PACKAGE);
+        assert_eq!(any.type_url, "/osmosis.tokenfactory.v1beta1.MsgCreateDenom");
+
+        let msg = v1beta1::MsgCreateDenom::decode(any.value.as_slice()).unwrap();
+        assert_eq!(msg.sender, SENDER);
+        assert_eq!(msg.subdenom, "tome");
+
+        let any = BurnRequest {
+            sender: sender(),
+            amount: tome(10),
+            burn_from: Some(sender()),
+        }
+        .into_any(OSMOSIS_PACKAGE);
+        assert_eq!(any.type_url, "/osmosis.tokenfactory.v1beta1.MsgBurn");
+
+        let msg = v1beta1::MsgBurn::decode(any.value.as_slice()).unwrap();
+        assert_eq!(msg.burn_from_address, SENDER);
+        assert_eq!(msg.amount.unwrap().amount, "10");
+    }
+
+    #[test]
+    fn test_injective_package() {
+        let any = MintRequest {
+            sender: sender(),
+            amount: tome(1000),
+            mint_to: None,
+        }
+        .into_any(INJECTIVE_PACKAGE);
+        assert_eq!(any.type_url, "/injective.tokenfactory.v1beta1.MsgMint");
+
+        let msg = v1beta1::MsgMint::decode(any.value.as_slice()).unwrap();
+        assert_eq!(msg.sender, SENDER);
+        assert_eq!(msg.mint_to_address, "");
+
+        let any = ChangeAdminRequest {
+            sender: sender(),
+            denom: DENOM.parse().unwrap(),
+            new_admin: sender(),
+        }
+        .into_any(INJECTIVE_PACKAGE);
+        assert_eq!(
+            any.type_url,
+            "/injective.tokenfactory.v1beta1.MsgChangeAdmin"
+        );
+    }
+
+    #[test]
+    fn test_kujira_package() {
+        // kujira names the subdenom `nonce` and the mint recipient `recipient`, at the same tags
+        let any = CreateDenomRequest {
+            sender: sender(),
+            subdenom: "tome".to_string(),
+        }
+        .into_any(KUJIRA_PACKAGE);
+        assert_eq!(any.type_url, "/kujira.denom.MsgCreateDenom");
+
+        let any = MintRequest {
+            sender: sender(),
+            amount: tome(1000),
+            mint_to: Some(sender()),
+        }
+        .into_any(KUJIRA_PACKAGE);
+        assert_eq!(any.type_url, "/kujira.denom.MsgMint");
+
+        let msg = v1beta1::MsgMint::decode(any.value.as_slice()).unwrap();
+        assert_eq!(msg.mint_to_address, SENDER);
+    }
+
+    #[test]
+    fn test_split_factory_denom() {
+        let (creator, subdenom) = split_factory_denom(&DENOM.parse().unwrap()).unwrap();
+        assert_eq!(creator, SENDER);
+        assert_eq!(subdenom, "tome");
+
+        let (_, subdenom) =
+            split_factory_denom(&format!("factory/{SENDER}/a/b").parse().unwrap()).unwrap();
+        assert_eq!(subdenom, "a/b");
+
+        split_factory_denom(&"ujuno".parse().unwrap()).unwrap_err();
+        split_factory_denom(&format!("factory/{SENDER}/").parse().unwrap()).unwrap_err();
+    }
+}
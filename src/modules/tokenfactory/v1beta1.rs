@@ -0,0 +1,95 @@
+//! Token factory protos, which are not part of the sdk protos we currently depend on.
+//!
+//! Chains fork the module under their own proto package, so these types carry no type urls.
+//! They only contain the fields shared by the osmosis layout and its forks.
+
+use cosmrs::proto::cosmos::bank::v1beta1::Metadata;
+use cosmrs::proto::cosmos::base::v1beta1::Coin;
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgCreateDenom {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(string, tag = "2")]
+    pub subdenom: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgMint {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(message, optional, tag = "2")]
+    pub amount: Option<Coin>,
+    #[prost(string, tag = "3")]
+    pub mint_to_address: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgBurn {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(message, optional, tag = "2")]
+    pub amount: Option<Coin>,
+    #[prost(string, tag = "3")]
+    pub burn_from_address: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgChangeAdmin {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(string, tag = "2")]
+    pub denom: String,
+    #[prost(string, tag = "3")]
+    pub new_admin: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgSetDenomMetadata {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(message, optional, tag = "2")]
+    pub metadata: Option<Metadata>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct DenomAuthorityMetadata {
+    #[prost(string, tag = "1")]
+    pub admin: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct Params {
+    #[prost(message, repeated, tag = "1")]
+    pub denom_creation_fee: Vec<Coin>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryDenomAuthorityMetadataRequest {
+    #[prost(string, tag = "1")]
+    pub denom: String,
+}
+
+/// Injective keys the denom authority query on the parts of `factory/{creator}/{sub_denom}`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct InjectiveQueryDenomAuthorityMetadataRequest {
+    #[prost(string, tag = "1")]
+    pub creator: String,
+    #[prost(string, tag = "2")]
+    pub sub_denom: String,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryDenomAuthorityMetadataResponse {
+    #[prost(message, optional, tag = "1")]
+    pub authority_metadata: Option<DenomAuthorityMetadata>,
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryParamsRequest {}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct QueryParamsResponse {
+    #[prost(message, optional, tag = "1")]
+    pub params: Option<Params>,
+}
@@ -1,4 +1,9 @@
-use cosmrs::proto::cosmos::tx::v1beta1::TxRaw;
+use cosmrs::proto::cosmos::tx::v1beta1::{
+    GetBlockWithTxsRequest, GetBlockWithTxsResponse, GetTxRequest, GetTxResponse,
+    GetTxsEventRequest, GetTxsEventResponse, OrderBy as ProtoOrderBy, TxRaw,
+};
+use std::future::Future;
+
 use cosmrs::tx::Body;
 use cosmrs::tx::SignerInfo;
 use serde::Serialize;
@@ -6,8 +11,9 @@ use serde::Serialize;
 use crate::chain::coin::{Coin, Denom};
 use crate::chain::error::ChainError;
 use crate::chain::msg::Msg;
+use crate::chain::request::PaginationRequest;
 use crate::chain::response::AsyncChainTxResponse;
-use crate::modules::auth::model::{Account, Address};
+use crate::modules::auth::model::{Account, AccountKind, Address, ETH_SECP256K1_PUBKEY_TYPE_URL};
 use crate::{
     chain::{fee::Fee, request::TxOptions, response::ChainTxResponse, Any},
     clients::client::{CosmTome, CosmosClient},
@@ -15,14 +21,19 @@ use crate::{
 };
 
 use super::error::TxError;
-use super::model::{BroadcastMode, RawTx};
+use super::model::{
+    BlockWithTxsResponse, BroadcastMode, OrderBy, RawTx, SignerData, TxResponse, TxsResponse,
+    UnsignedTx,
+};
+use super::sequence::SequenceLease;
 
-// TODO: Query endpoints
-// * tx_query_get_tx()
-// * tx_query_get_txs_event()
-// * tx_query_get_block_with_txs()
+/// Number of times a tx is re-signed and re-broadcasted after failing with an account sequence mismatch
+const SEQUENCE_MISMATCH_RETRIES: usize = 3;
 
 impl<T: CosmosClient> CosmTome<T> {
+    /// Sign `msgs` in a single tx without broadcasting it.
+    /// The account sequence is queried from the chain for every tx, and is not taken from nor handed out
+    /// by the cached sequences of `tx_send()`, since the tx might never reach the chain.
     pub async fn tx_sign(
         &self,
         msgs: Vec<impl Msg + Serialize>,
@@ -30,26 +41,236 @@ impl<T: CosmosClient> CosmTome<T> {
         key: &SigningKey,
         tx_options: &TxOptions,
     ) -> Result<RawTx, TxError> {
-        let sender_addr = if let Some(sender_addr) = sender_addr {
-            sender_addr
+        let sender_addr = self.sender_addr(sender_addr, key).await?;
+        let account = self.query_signing_account(sender_addr).await?;
+
+        self.sign_msgs_with_account(msgs, account, key, tx_options)
+            .await
+    }
+
+    /// Sign a tx containing already encoded messages.
+    /// Use `Msg::into_any()` to combine different message types in the same tx.
+    pub async fn tx_sign_any(
+        &self,
+        msgs: Vec<Any>,
+        sender_addr: Option<Address>,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<RawTx, TxError> {
+        let sender_addr = self.sender_addr(sender_addr, key).await?;
+        let account = self.query_signing_account(sender_addr).await?;
+
+        self.sign_with_account(msgs, account, key, tx_options).await
+    }
+
+    /// Sign and broadcast `msgs` in a single tx, waiting for it to be commited in the next block.
+    pub async fn tx_send(
+        &self,
+        msgs: Vec<impl Msg + Serialize>,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<ChainTxResponse, TxError> {
+        let sender_addr = key.to_addr(&self.cfg.prefix).await?;
+
+        self.send_with_retry(&sender_addr, |account| {
+            self.sign_msgs_with_account(msgs.clone(), account, key, tx_options)
+        })
+        .await
+    }
+
+    /// Atomically execute all `msgs` in a single tx, waiting for it to be commited in the next block.
+    pub async fn tx_send_any(
+        &self,
+        msgs: Vec<Any>,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<ChainTxResponse, TxError> {
+        let sender_addr = key.to_addr(&self.cfg.prefix).await?;
+
+        self.send_with_retry(&sender_addr, |account| {
+            self.sign_with_account(msgs.clone(), account, key, tx_options)
+        })
+        .await
+    }
+
+    /// Build an unsigned tx that can be exported and signed offline with `SigningKey::sign_offline()`.
+    /// The chain is only queried to simulate the fee, if `tx_options.fee` is not set.
+    pub async fn tx_generate_only(
+        &self,
+        msgs: Vec<Any>,
+        sender_addr: Address,
+        tx_options: &TxOptions,
+    ) -> Result<UnsignedTx, TxError> {
+        let fee = if let Some(fee) = &tx_options.fee {
+            fee.clone()
         } else {
-            key.to_addr(&self.cfg.prefix).await?
+            let account = self.auth_query_account(sender_addr).await?.account;
+            self.tx_simulate_with_fee_payer(
+                msgs.clone(),
+                &account,
+                None,
+                tx_options.fee_granter.clone(),
+            )
+            .await?
+        };
+
+        Ok(UnsignedTx {
+            msgs,
+            memo: tx_options.memo.clone(),
+            timeout_height: tx_options.timeout_height.unwrap_or_default(),
+            fee,
+        })
+    }
+
+    /// Query the account number and current sequence needed to sign a tx offline for `address`
+    pub async fn tx_signer_data(&self, address: Address) -> Result<SignerData, TxError> {
+        let account = self.query_signing_account(address).await?;
+
+        Ok(SignerData::new(self.cfg.chain_id.clone(), &account))
+    }
+
+    // Broadcasts the tx returned by `sign` for the next cached sequence of `sender_addr`, re-signing it
+    // with the sequence expected by the chain if it was rejected for using the wrong one.
+    async fn send_with_retry<F, Fut>(
+        &self,
+        sender_addr: &Address,
+        sign: F,
+    ) -> Result<ChainTxResponse, TxError>
+    where
+        F: Fn(Account) -> Fut,
+        Fut: Future<Output = Result<RawTx, TxError>>,
+    {
+        let mut retries = 0;
+
+        loop {
+            let (tx_raw, lease) = self.sign_next(sender_addr, &sign).await?;
+
+            let e = match self.tx_broadcast_block(&tx_raw).await {
+                Ok(res) => return Ok(res),
+                Err(e) => e,
+            };
+
+            // the mempool can already hold txs with later sequences than the committed account state,
+            // so prefer the sequence reported by the chain over resyncing it with an account query
+            match e.expected_sequence() {
+                Some(sequence) => self.sequences.resync(sender_addr, lease, sequence).await,
+                None if e.is_sequence_mismatch() => {
+                    self.sequences.invalidate(sender_addr, lease).await
+                }
+                // the tx is still in the mempool or was committed, so its sequence is used either way
+                None if matches!(
+                    e,
+                    TxError::ChainError(ChainError::TxTimeout { .. } | ChainError::TxFailed { .. })
+                ) => {}
+                // rejected by `CheckTx` or by the broadcast itself
+                None => self.sequences.release(sender_addr, lease).await,
+            }
+
+            if !e.is_sequence_mismatch() || retries >= SEQUENCE_MISMATCH_RETRIES {
+                return Err(e);
+            }
+
+            retries += 1;
+        }
+    }
+
+    // Signs a tx with the next cached sequence of `sender_addr`, only handing it out if signing succeeds.
+    // A queried account is only cached once a tx is signed with it.
+    // The account slot stays locked while signing, so concurrent txs of the same address are
+    // simulated one after the other, but are still broadcasted concurrently.
+    async fn sign_next<F, Fut>(
+        &self,
+        sender_addr: &Address,
+        sign: &F,
+    ) -> Result<(RawTx, SequenceLease), TxError>
+    where
+        F: Fn(Account) -> Fut,
+        Fut: Future<Output = Result<RawTx, TxError>>,
+    {
+        let entry = self.sequences.entry(sender_addr).await;
+        let mut slot = entry.lock().await;
+
+        let account = match slot.account.clone() {
+            Some(account) => account,
+            None => self.query_signing_account(sender_addr.clone()).await?,
         };
 
+        let tx_raw = sign(account.clone()).await?;
+
+        let lease = SequenceLease {
+            sequence: account.sequence,
+            epoch: slot.epoch,
+        };
+        slot.account = Some(Account {
+            sequence: account.sequence + 1,
+            ..account
+        });
+
+        Ok((tx_raw, lease))
+    }
+
+    async fn sender_addr(
+        &self,
+        sender_addr: Option<Address>,
+        key: &SigningKey,
+    ) -> Result<Address, TxError> {
+        if let Some(sender_addr) = sender_addr {
+            Ok(sender_addr)
+        } else {
+            Ok(key.to_addr(&self.cfg.prefix).await?)
+        }
+    }
+
+    // Queries the account of `address`, rejecting Ethermint accounts since `SigningKey` can only
+    // produce cosmos secp256k1 signatures, even if the account has no public key on chain yet.
+    async fn query_signing_account(&self, address: Address) -> Result<Account, TxError> {
+        let res = self.auth_query_account(address).await?;
+
+        if let AccountKind::Eth { .. } = res.kind {
+            return Err(ChainError::UnsupportedPubKey {
+                type_url: ETH_SECP256K1_PUBKEY_TYPE_URL.to_string(),
+            }
+            .into());
+        }
+
+        Ok(res.account)
+    }
+
+    async fn sign_msgs_with_account(
+        &self,
+        msgs: Vec<impl Msg + Serialize>,
+        account: Account,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<RawTx, TxError> {
+        let msgs = msgs
+            .into_iter()
+            .map(|m| m.into_any())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| ChainError::ProtoEncoding {
+                message: e.to_string(),
+            })?;
+
+        self.sign_with_account(msgs, account, key, tx_options).await
+    }
+
+    async fn sign_with_account(
+        &self,
+        msgs: Vec<Any>,
+        account: Account,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<RawTx, TxError> {
         let timeout_height = tx_options.timeout_height.unwrap_or_default();
-        let account = self.auth_query_account(sender_addr).await?.account;
+
+        let (payer, granter) = match &tx_options.fee {
+            Some(fee) => (fee.payer.clone(), fee.granter.clone()),
+            None => (None, tx_options.fee_granter.clone()),
+        };
 
         // even if the user is supplying their own `Fee`, we will simulate the tx to ensure its valid
         let sim_fee = self
-            .tx_simulate(
-                msgs.iter()
-                    .map(|m| m.to_any())
-                    .collect::<Result<Vec<_>, _>>()
-                    .map_err(|e| ChainError::ProtoEncoding {
-                        message: e.to_string(),
-                    })?,
-                &account,
-            )
+            .tx_simulate_with_fee_payer(msgs.clone(), &account, payer, granter)
             .await?;
 
         let fee = if let Some(fee) = &tx_options.fee {
@@ -59,7 +280,7 @@ impl<T: CosmosClient> CosmTome<T> {
         };
 
         let raw = key
-            .sign(
+            .sign_any(
                 msgs,
                 timeout_height,
                 &tx_options.memo,
@@ -74,6 +295,22 @@ impl<T: CosmosClient> CosmTome<T> {
     // Sends tx with an empty public_key / signature, like they do in the cosmos-sdk:
     // https://github.com/cosmos/cosmos-sdk/blob/main/client/tx/tx.go#L133
     pub async fn tx_simulate<I>(&self, msgs: I, account: &Account) -> Result<Fee, TxError>
+    where
+        I: IntoIterator<Item = Any>,
+    {
+        self.tx_simulate_with_fee_payer(msgs, account, None, None)
+            .await
+    }
+
+    /// Same as `tx_simulate()`, for a fee paid by `payer` and/or a feegrant allowance of `granter`.
+    /// The chain checks the allowance during simulation, and the returned `Fee` keeps both addresses.
+    pub async fn tx_simulate_with_fee_payer<I>(
+        &self,
+        msgs: I,
+        account: &Account,
+        payer: Option<Address>,
+        granter: Option<Address>,
+    ) -> Result<Fee, TxError>
     where
         I: IntoIterator<Item = Any>,
     {
@@ -87,8 +324,8 @@ impl<T: CosmosClient> CosmTome<T> {
                 amount: 0u128,
             },
             0u64,
-            None,
-            None,
+            payer.clone(),
+            granter.clone(),
         );
 
         let auth_info =
@@ -109,7 +346,7 @@ impl<T: CosmosClient> CosmTome<T> {
             amount: ((gas_limit * self.cfg.gas_price).ceil() as u64).into(),
         };
 
-        let fee = Fee::new(amount, gas_limit as u64, None, None);
+        let fee = Fee::new(amount, gas_limit as u64, payer, granter);
 
         Ok(fee)
     }
@@ -127,4 +364,961 @@ impl<T: CosmosClient> CosmTome<T> {
     pub async fn tx_broadcast_block(&self, tx: &RawTx) -> Result<ChainTxResponse, TxError> {
         Ok(self.client.broadcast_tx_block(tx).await?)
     }
+
+    /// Query a committed tx by its hash
+    pub async fn tx_query_get_tx(&self, tx_hash: String) -> Result<TxResponse, TxError> {
+        let req = GetTxRequest { hash: tx_hash };
+
+        let res = self
+            .client
+            .query::<_, GetTxResponse>(req, "/cosmos.tx.v1beta1.Service/GetTx")
+            .await?;
+
+        Ok(TxResponse {
+            tx: res.tx.ok_or(TxError::MissingTx)?.try_into()?,
+            res: res.tx_response.ok_or(TxError::MissingTx)?.try_into()?,
+        })
+    }
+
+    /// Query all txs matching the given `events`, ie. `["message.sender='juno1...'"]`
+    pub async fn tx_query_get_txs_event(
+        &self,
+        events: Vec<String>,
+        pagination: Option<PaginationRequest>,
+        order_by: OrderBy,
+    ) -> Result<TxsResponse, TxError> {
+        let req = GetTxsEventRequest {
+            events,
+            pagination: pagination.map(Into::into),
+            order_by: ProtoOrderBy::from(order_by).into(),
+        };
+
+        let res = self
+            .client
+            .query::<_, GetTxsEventResponse>(req, "/cosmos.tx.v1beta1.Service/GetTxsEvent")
+            .await?;
+
+        if res.txs.len() != res.tx_responses.len() {
+            return Err(TxError::TxResponsesMismatch {
+                txs: res.txs.len(),
+                responses: res.tx_responses.len(),
+            });
+        }
+
+        let txs = res
+            .txs
+            .into_iter()
+            .zip(res.tx_responses)
+            .map(|(tx, res)| {
+                Ok(TxResponse {
+                    tx: tx.try_into()?,
+                    res: res.try_into()?,
+                })
+            })
+            .collect::<Result<Vec<_>, ChainError>>()?;
+
+        Ok(TxsResponse {
+            txs,
+            next: res.pagination.map(Into::into),
+        })
+    }
+
+    /// Query a block at `height` along with its decoded txs
+    pub async fn tx_query_get_block_with_txs(
+        &self,
+        height: u64,
+        pagination: Option<PaginationRequest>,
+    ) -> Result<BlockWithTxsResponse, TxError> {
+        let req = GetBlockWithTxsRequest {
+            height: height as i64,
+            pagination: pagination.map(Into::into),
+        };
+
+        let res = self
+            .client
+            .query::<_, GetBlockWithTxsResponse>(req, "/cosmos.tx.v1beta1.Service/GetBlockWithTxs")
+            .await?;
+
+        Ok(BlockWithTxsResponse {
+            id: res
+                .block_id
+                .ok_or(TxError::MissingBlockId)?
+                .try_into()
+                .map_err(ChainError::Tendermint)?,
+            block: res
+                .block
+                .ok_or(TxError::MissingBlock)?
+                .try_into()
+                .map_err(ChainError::Tendermint)?,
+            txs: res
+                .txs
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, _>>()?,
+            next: res.pagination.map(Into::into),
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use cosmrs::proto::{
+        cosmos::auth::v1beta1::{BaseAccount, QueryAccountRequest, QueryAccountResponse},
+        cosmos::tx::v1beta1::{AuthInfo as ProtoAuthInfo, TxRaw},
+        traits::{Message, MessageExt},
+    };
+
+    use crate::{
+        chain::{
+            coin::Coin,
+            error::ChainError,
+            fee::GasInfo,
+            msg::Msg,
+            request::TxOptions,
+            response::{ChainResponse, ChainTxResponse, Code},
+        },
+        clients::client::{CosmTome, MockCosmosClient},
+        config::cfg::ChainConfig,
+        modules::{
+            auth::model::BASE_ACCOUNT_TYPE_URL,
+            bank::model::SendRequest,
+            cosmwasm::model::ExecRequest,
+            tx::{error::TxError, model::RawTx},
+        },
+        signing_key::key::SigningKey,
+        test_utils::test_cfg,
+    };
+
+    #[tokio::test]
+    async fn test_tx_send_any_mixed_msgs() {
+        let cfg = test_cfg();
+        let tx_options = TxOptions::default();
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QueryAccountRequest, QueryAccountResponse>()
+            .times(1)
+            .returning(move |_, _| {
+                Ok(QueryAccountResponse {
+                    account: Some(cosmrs::proto::Any {
+                        type_url: BASE_ACCOUNT_TYPE_URL.to_string(),
+                        value: BaseAccount {
+                            address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                            pub_key: None,
+                            account_number: 1337,
+                            sequence: 1,
+                        }
+                        .to_bytes()
+                        .unwrap(),
+                    }),
+                })
+            });
+
+        mock_client.expect_simulate_tx().times(1).returning(|_| {
+            Ok(GasInfo {
+                gas_wanted: 200u16.into(),
+                gas_used: 100u16.into(),
+            })
+        });
+
+        mock_client
+            .expect_broadcast_tx_block()
+            .times(1)
+            .withf(|tx: &RawTx| {
+                let tx = cosmrs::Tx::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+                tx.body.messages.len() == 2
+                    && tx.body.messages[0].type_url == "/cosmos.bank.v1beta1.MsgSend"
+                    && tx.body.messages[1].type_url == "/cosmwasm.wasm.v1.MsgExecuteContract"
+            })
+            .returning(|_| {
+                Ok(ChainTxResponse {
+                    res: ChainResponse {
+                        code: Code::Ok,
+                        codespace: String::new(),
+                        data: None,
+                        log: "log log log".to_string(),
+                    },
+                    events: vec![],
+                    gas_wanted: 200,
+                    gas_used: 100,
+                    tx_hash: "TX_HASH_0".to_string(),
+                    height: 1337,
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg.clone(), mock_client);
+
+        let send = SendRequest {
+            from: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                .parse()
+                .unwrap(),
+            to: "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                .parse()
+                .unwrap(),
+            amounts: vec![Coin {
+                denom: cfg.denom.parse().unwrap(),
+                amount: 10,
+            }],
+        };
+
+        let exec = ExecRequest {
+            address: "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                .parse()
+                .unwrap(),
+            msg: "do_something",
+            funds: vec![],
+        }
+        .to_proto(
+            "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                .parse()
+                .unwrap(),
+        )
+        .unwrap();
+
+        let res = cosm_tome
+            .tx_send_any(
+                vec![send.into_any().unwrap(), exec.into_any().unwrap()],
+                &key,
+                &tx_options,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(res.tx_hash, "TX_HASH_0");
+    }
+
+    #[tokio::test]
+    async fn test_tx_send_sequence_mismatch_retry() {
+        let cfg = test_cfg();
+        let tx_options = TxOptions::default();
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
+
+        let mut mock_client = MockCosmosClient::new();
+
+        // the account is only queried once, the sequence mismatch is resynced from the error log
+        mock_client
+            .expect_query::<QueryAccountRequest, QueryAccountResponse>()
+            .times(1)
+            .returning(move |_, _| {
+                Ok(QueryAccountResponse {
+                    account: Some(cosmrs::proto::Any {
+                        type_url: BASE_ACCOUNT_TYPE_URL.to_string(),
+                        value: BaseAccount {
+                            address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                            pub_key: None,
+                            account_number: 1337,
+                            sequence: 1,
+                        }
+                        .to_bytes()
+                        .unwrap(),
+                    }),
+                })
+            });
+
+        mock_client.expect_simulate_tx().times(2).returning(|_| {
+            Ok(GasInfo {
+                gas_wanted: 200u16.into(),
+                gas_used: 100u16.into(),
+            })
+        });
+
+        let mut broadcasts = 0;
+        mock_client
+            .expect_broadcast_tx_block()
+            .times(2)
+            .returning(move |tx| {
+                broadcasts += 1;
+
+                let auth_info =
+                    ProtoAuthInfo::decode(&TxRaw::from(tx.clone()).auth_info_bytes[..]).unwrap();
+                let sequence = auth_info.signer_infos[0].sequence;
+
+                if broadcasts == 1 {
+                    assert_eq!(sequence, 1);
+
+                    return Err(ChainError::CosmosSdk {
+                        res: ChainResponse {
+                            code: Code::Err(32),
+                            codespace: "sdk".to_string(),
+                            data: None,
+                            log: "account sequence mismatch, expected 5, got 1: incorrect account sequence"
+                                .to_string(),
+                        },
+                    });
+                }
+
+                // txs still in the mempool are not part of the committed account state,
+                // so the retry has to use the sequence the chain reported
+                assert_eq!(sequence, 5);
+
+                Ok(ChainTxResponse {
+                    tx_hash: "TX_HASH_0".to_string(),
+                    ..Default::default()
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg.clone(), mock_client);
+
+        let send = SendRequest {
+            from: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                .parse()
+                .unwrap(),
+            to: "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                .parse()
+                .unwrap(),
+            amounts: vec![Coin {
+                denom: cfg.denom.parse().unwrap(),
+                amount: 10,
+            }],
+        };
+
+        let res = cosm_tome
+            .tx_send(vec![send], &key, &tx_options)
+            .await
+            .unwrap();
+
+        assert_eq!(res.tx_hash, "TX_HASH_0");
+
+        // the next tx continues after the resynced sequence
+        assert_eq!(cached_sequence(&cosm_tome, &key).await, Some(6));
+    }
+
+    fn mock_base_account(mock_client: &mut MockCosmosClient, sequence: u64, times: usize) {
+        mock_client
+            .expect_query::<QueryAccountRequest, QueryAccountResponse>()
+            .times(times)
+            .returning(move |_, _| {
+                Ok(QueryAccountResponse {
+                    account: Some(cosmrs::proto::Any {
+                        type_url: BASE_ACCOUNT_TYPE_URL.to_string(),
+                        value: BaseAccount {
+                            address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                            pub_key: None,
+                            account_number: 1337,
+                            sequence,
+                        }
+                        .to_bytes()
+                        .unwrap(),
+                    }),
+                })
+            });
+    }
+
+    async fn cached_sequence(
+        cosm_tome: &CosmTome<MockCosmosClient>,
+        key: &SigningKey,
+    ) -> Option<u64> {
+        let address = key.to_addr(&cosm_tome.cfg.prefix).await.unwrap();
+        let entry = cosm_tome.sequences.entry(&address).await;
+        let slot = entry.lock().await;
+
+        slot.account.as_ref().map(|a| a.sequence)
+    }
+
+    fn tx_sequence(tx: &RawTx) -> u64 {
+        let auth_info =
+            ProtoAuthInfo::decode(&TxRaw::from(tx.clone()).auth_info_bytes[..]).unwrap();
+        auth_info.signer_infos[0].sequence
+    }
+
+    fn test_send(cfg: &ChainConfig) -> SendRequest {
+        SendRequest {
+            from: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                .parse()
+                .unwrap(),
+            to: "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                .parse()
+                .unwrap(),
+            amounts: vec![Coin {
+                denom: cfg.denom.parse().unwrap(),
+                amount: 10,
+            }],
+        }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_tx_send_concurrent_same_key() {
+        let cfg = test_cfg();
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
+
+        let mut mock_client = MockCosmosClient::new();
+
+        // the account is committed with sequence 1, but the mempool already holds a tx using it
+        mock_base_account(&mut mock_client, 1, 1);
+
+        mock_client.expect_simulate_tx().returning(|_| {
+            Ok(GasInfo {
+                gas_wanted: 200u16.into(),
+                gas_used: 100u16.into(),
+            })
+        });
+
+        // accepts txs in sequence order, like the ante handler
+        let chain_sequence = Arc::new(Mutex::new(2u64));
+        let accepted = Arc::new(Mutex::new(vec![]));
+        {
+            let chain_sequence = chain_sequence.clone();
+            let accepted = accepted.clone();
+
+            mock_client.expect_broadcast_tx_block().returning(move |tx| {
+                let sequence = tx_sequence(tx);
+                let mut expected = chain_sequence.lock().unwrap();
+
+                if sequence != *expected {
+                    return Err(ChainError::CosmosSdk {
+                        res: ChainResponse {
+                            code: Code::Err(32),
+                            codespace: "sdk".to_string(),
+                            data: None,
+                            log: format!("account sequence mismatch, expected {expected}, got {sequence}: incorrect account sequence"),
+                        },
+                    });
+                }
+
+                *expected += 1;
+                accepted.lock().unwrap().push(sequence);
+
+                Ok(ChainTxResponse {
+                    tx_hash: format!("TX_HASH_{sequence}"),
+                    ..Default::default()
+                })
+            });
+        }
+
+        let cosm_tome = Arc::new(CosmTome::new(cfg.clone(), mock_client));
+
+        let tasks = (0..2)
+            .map(|_| {
+                let cosm_tome = cosm_tome.clone();
+                let key = key.clone();
+                let send = test_send(&cfg);
+
+                tokio::spawn(async move {
+                    cosm_tome
+                        .tx_send(vec![send], &key, &TxOptions::default())
+                        .await
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for task in tasks {
+            task.await.unwrap().unwrap();
+        }
+
+        // every tx was committed with its own sequence
+        let mut accepted = accepted.lock().unwrap().clone();
+        accepted.sort_unstable();
+        assert_eq!(accepted, vec![2, 3]);
+
+        assert_eq!(cached_sequence(&cosm_tome, &key).await, Some(4));
+    }
+
+    #[tokio::test]
+    async fn test_tx_send_failed_simulation_keeps_sequence() {
+        let cfg = test_cfg();
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
+
+        let mut mock_client = MockCosmosClient::new();
+
+        // the account is only cached once a tx is signed with it
+        mock_base_account(&mut mock_client, 1, 2);
+
+        let mut simulations = 0;
+        mock_client
+            .expect_simulate_tx()
+            .times(2)
+            .returning(move |_| {
+                simulations += 1;
+
+                if simulations == 1 {
+                    return Err(ChainError::Simulation);
+                }
+
+                Ok(GasInfo {
+                    gas_wanted: 200u16.into(),
+                    gas_used: 100u16.into(),
+                })
+            });
+
+        mock_client
+            .expect_broadcast_tx_block()
+            .times(1)
+            .returning(|tx| {
+                // the failed tx did not use up sequence 1
+                assert_eq!(tx_sequence(tx), 1);
+
+                Ok(ChainTxResponse {
+                    tx_hash: "TX_HASH_0".to_string(),
+                    ..Default::default()
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg.clone(), mock_client);
+
+        let err = cosm_tome
+            .tx_send(vec![test_send(&cfg)], &key, &TxOptions::default())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, TxError::ChainError(ChainError::Simulation)));
+
+        cosm_tome
+            .tx_send(vec![test_send(&cfg)], &key, &TxOptions::default())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_tx_sign_queries_sequence() {
+        let cfg = test_cfg();
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
+
+        let mut mock_client = MockCosmosClient::new();
+
+        // the chain advances the sequence once the first signed tx is broadcasted
+        let mut queries = 0;
+        mock_client
+            .expect_query::<QueryAccountRequest, QueryAccountResponse>()
+            .times(2)
+            .returning(move |_, _| {
+                queries += 1;
+
+                Ok(QueryAccountResponse {
+                    account: Some(cosmrs::proto::Any {
+                        type_url: BASE_ACCOUNT_TYPE_URL.to_string(),
+                        value: BaseAccount {
+                            address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                            pub_key: None,
+                            account_number: 1337,
+                            sequence: queries,
+                        }
+                        .to_bytes()
+                        .unwrap(),
+                    }),
+                })
+            });
+
+        mock_client.expect_simulate_tx().times(2).returning(|_| {
+            Ok(GasInfo {
+                gas_wanted: 200u16.into(),
+                gas_used: 100u16.into(),
+            })
+        });
+
+        mock_client
+            .expect_broadcast_tx_block()
+            .times(1)
+            .returning(|_| {
+                Ok(ChainTxResponse {
+                    tx_hash: "TX_HASH_0".to_string(),
+                    ..Default::default()
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg.clone(), mock_client);
+
+        let tx = cosm_tome
+            .tx_sign(vec![test_send(&cfg)], None, &key, &TxOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(tx_sequence(&tx), 1);
+
+        cosm_tome.tx_broadcast_block(&tx).await.unwrap();
+
+        let tx = cosm_tome
+            .tx_sign(vec![test_send(&cfg)], None, &key, &TxOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(tx_sequence(&tx), 2);
+
+        // offline signing does not touch the sequences cached by `tx_send()`
+        assert_eq!(cached_sequence(&cosm_tome, &key).await, None);
+    }
+
+    #[tokio::test]
+    async fn test_tx_send_committed_failure_keeps_sequence() {
+        let cfg = test_cfg();
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_base_account(&mut mock_client, 1, 1);
+
+        mock_client.expect_simulate_tx().times(2).returning(|_| {
+            Ok(GasInfo {
+                gas_wanted: 200u16.into(),
+                gas_used: 100u16.into(),
+            })
+        });
+
+        let mut broadcasts = 0;
+        mock_client
+            .expect_broadcast_tx_block()
+            .times(2)
+            .returning(move |tx| {
+                broadcasts += 1;
+
+                if broadcasts == 1 {
+                    assert_eq!(tx_sequence(tx), 1);
+
+                    // committed, so sequence 1 is used up even if the tx failed
+                    return Err(ChainError::TxFailed {
+                        tx_hash: "TX_HASH_0".to_string(),
+                        height: 1337,
+                        res: ChainResponse {
+                            code: Code::Err(5),
+                            codespace: "sdk".to_string(),
+                            data: None,
+                            log: "insufficient funds".to_string(),
+                        },
+                    });
+                }
+
+                assert_eq!(tx_sequence(tx), 2);
+
+                Ok(ChainTxResponse {
+                    tx_hash: "TX_HASH_1".to_string(),
+                    ..Default::default()
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg.clone(), mock_client);
+
+        let err = cosm_tome
+            .tx_send(vec![test_send(&cfg)], &key, &TxOptions::default())
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            TxError::ChainError(ChainError::TxFailed { .. })
+        ));
+
+        cosm_tome
+            .tx_send(vec![test_send(&cfg)], &key, &TxOptions::default())
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_tx_send_fee_granter() {
+        let cfg = test_cfg();
+        let granter = "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea";
+        let tx_options = TxOptions {
+            fee_granter: Some(granter.parse().unwrap()),
+            ..Default::default()
+        };
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QueryAccountRequest, QueryAccountResponse>()
+            .times(1)
+            .returning(move |_, _| {
+                Ok(QueryAccountResponse {
+                    account: Some(cosmrs::proto::Any {
+                        type_url: BASE_ACCOUNT_TYPE_URL.to_string(),
+                        value: BaseAccount {
+                            address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg".to_string(),
+                            pub_key: None,
+                            account_number: 1337,
+                            sequence: 1,
+                        }
+                        .to_bytes()
+                        .unwrap(),
+                    }),
+                })
+            });
+
+        let fee_granter = |tx: &RawTx| {
+            let tx = cosmrs::Tx::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+            tx.auth_info.fee.granter.map(|g| g.to_string())
+        };
+
+        // the allowance is checked by the chain during simulation
+        mock_client
+            .expect_simulate_tx()
+            .times(1)
+            .withf(move |tx: &RawTx| fee_granter(tx).as_deref() == Some(granter))
+            .returning(|_| {
+                Ok(GasInfo {
+                    gas_wanted: 200u16.into(),
+                    gas_used: 100u16.into(),
+                })
+            });
+
+        mock_client
+            .expect_broadcast_tx_block()
+            .times(1)
+            .withf(move |tx: &RawTx| fee_granter(tx).as_deref() == Some(granter))
+            .returning(|_| {
+                Ok(ChainTxResponse {
+                    tx_hash: "TX_HASH_0".to_string(),
+                    ..Default::default()
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg.clone(), mock_client);
+
+        let send = SendRequest {
+            from: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                .parse()
+                .unwrap(),
+            to: "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                .parse()
+                .unwrap(),
+            amounts: vec![Coin {
+                denom: cfg.denom.parse().unwrap(),
+                amount: 10,
+            }],
+        };
+
+        let res = cosm_tome
+            .tx_send(vec![send], &key, &tx_options)
+            .await
+            .unwrap();
+
+        assert_eq!(res.tx_hash, "TX_HASH_0");
+    }
+
+    fn test_tx() -> cosmrs::proto::cosmos::tx::v1beta1::Tx {
+        use cosmrs::proto::cosmos::{
+            base::v1beta1::Coin as ProtoCoin,
+            tx::v1beta1::{AuthInfo, Fee, Tx, TxBody},
+        };
+
+        Tx {
+            body: Some(TxBody {
+                messages: vec![cosmrs::proto::Any {
+                    type_url: "/cosmos.bank.v1beta1.MsgSend".to_string(),
+                    value: vec![],
+                }],
+                memo: "tome".to_string(),
+                ..Default::default()
+            }),
+            auth_info: Some(AuthInfo {
+                signer_infos: vec![],
+                fee: Some(Fee {
+                    amount: vec![ProtoCoin {
+                        denom: "ujuno".to_string(),
+                        amount: "20".to_string(),
+                    }],
+                    gas_limit: 200,
+                    payer: String::new(),
+                    granter: String::new(),
+                }),
+            }),
+            signatures: vec![vec![1; 64]],
+        }
+    }
+
+    fn test_tx_response(tx_hash: &str) -> cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse {
+        cosmrs::proto::cosmos::base::abci::v1beta1::TxResponse {
+            height: 10,
+            txhash: tx_hash.to_string(),
+            gas_wanted: 200,
+            gas_used: 100,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_tx_query_get_tx() {
+        use cosmrs::proto::cosmos::tx::v1beta1::{GetTxRequest, GetTxResponse};
+
+        let cfg = test_cfg();
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<GetTxRequest, GetTxResponse>()
+            .times(2)
+            .returning(|req, path| {
+                assert_eq!(path, "/cosmos.tx.v1beta1.Service/GetTx");
+
+                Ok(GetTxResponse {
+                    tx: (req.hash == "TX_HASH_0").then(test_tx),
+                    tx_response: Some(test_tx_response(&req.hash)),
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let res = cosm_tome
+            .tx_query_get_tx("TX_HASH_0".to_string())
+            .await
+            .unwrap();
+
+        assert_eq!(res.res.tx_hash, "TX_HASH_0");
+        assert_eq!(res.res.height, 10);
+        assert_eq!(res.tx.memo, "tome");
+        assert_eq!(res.tx.messages[0].type_url, "/cosmos.bank.v1beta1.MsgSend");
+        assert_eq!(res.tx.fee.amount[0].amount, 20);
+        assert_eq!(res.tx.fee.gas_limit.value(), 200);
+
+        let err = cosm_tome
+            .tx_query_get_tx("TX_HASH_1".to_string())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, TxError::MissingTx));
+    }
+
+    #[tokio::test]
+    async fn test_tx_query_get_txs_event() {
+        use cosmrs::proto::cosmos::{
+            base::query::v1beta1::PageResponse,
+            tx::v1beta1::{GetTxsEventRequest, GetTxsEventResponse},
+        };
+
+        use crate::chain::request::{PageID, PaginationRequest, PaginationResponse};
+        use crate::modules::tx::model::OrderBy;
+
+        let cfg = test_cfg();
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<GetTxsEventRequest, GetTxsEventResponse>()
+            .times(2)
+            .returning(|req, path| {
+                assert_eq!(path, "/cosmos.tx.v1beta1.Service/GetTxsEvent");
+                assert_eq!(req.order_by, 2);
+
+                // the second page is missing a tx response
+                let last_page = req.pagination.is_some();
+                let tx_responses = if last_page {
+                    vec![test_tx_response("TX_HASH_2")]
+                } else {
+                    vec![test_tx_response("TX_HASH_0"), test_tx_response("TX_HASH_1")]
+                };
+
+                Ok(GetTxsEventResponse {
+                    txs: vec![test_tx(), test_tx()],
+                    tx_responses,
+                    pagination: Some(PageResponse {
+                        next_key: b"next".to_vec(),
+                        total: 4,
+                    }),
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let events =
+            vec!["message.sender='juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg'".to_string()];
+
+        let res = cosm_tome
+            .tx_query_get_txs_event(events.clone(), None, OrderBy::Desc)
+            .await
+            .unwrap();
+
+        assert_eq!(res.txs.len(), 2);
+        assert_eq!(res.txs[1].res.tx_hash, "TX_HASH_1");
+        assert_eq!(
+            res.next,
+            Some(PaginationResponse {
+                next_key: b"next".to_vec(),
+                total: 4,
+            })
+        );
+
+        let err = cosm_tome
+            .tx_query_get_txs_event(
+                events,
+                Some(PaginationRequest {
+                    page: PageID::Key(res.next.unwrap().next_key),
+                    limit: 2,
+                    reverse: false,
+                }),
+                OrderBy::Desc,
+            )
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            TxError::TxResponsesMismatch {
+                txs: 2,
+                responses: 1
+            }
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_tx_query_get_block_with_txs() {
+        use cosmrs::proto::{
+            cosmos::tx::v1beta1::{GetBlockWithTxsRequest, GetBlockWithTxsResponse},
+            tendermint::{
+                types::{Block, BlockId, Data, EvidenceList, Header, PartSetHeader},
+                version::Consensus,
+            },
+        };
+
+        let cfg = test_cfg();
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<GetBlockWithTxsRequest, GetBlockWithTxsResponse>()
+            .times(2)
+            .returning(|req, path| {
+                assert_eq!(path, "/cosmos.tx.v1beta1.Service/GetBlockWithTxs");
+
+                let block = Block {
+                    header: Some(Header {
+                        version: Some(Consensus { block: 11, app: 0 }),
+                        chain_id: "test-1".to_string(),
+                        height: 1,
+                        time: Some(cosmrs::proto::tendermint::google::protobuf::Timestamp {
+                            seconds: 1_700_000_000,
+                            nanos: 0,
+                        }),
+                        validators_hash: vec![1; 32],
+                        next_validators_hash: vec![1; 32],
+                        consensus_hash: vec![2; 32],
+                        proposer_address: vec![3; 20],
+                        ..Default::default()
+                    }),
+                    data: Some(Data::default()),
+                    evidence: Some(EvidenceList::default()),
+                    last_commit: None,
+                };
+
+                Ok(GetBlockWithTxsResponse {
+                    txs: vec![test_tx()],
+                    block_id: Some(BlockId {
+                        hash: vec![4; 32],
+                        part_set_header: Some(PartSetHeader {
+                            total: 1,
+                            hash: vec![5; 32],
+                        }),
+                    }),
+                    // an unknown height returns no block
+                    block: (req.height == 1).then_some(block),
+                    pagination: None,
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let res = cosm_tome
+            .tx_query_get_block_with_txs(1, None)
+            .await
+            .unwrap();
+
+        assert_eq!(res.block.header.height.value(), 1);
+        assert_eq!(res.block.header.chain_id.as_str(), "test-1");
+        assert_eq!(res.txs.len(), 1);
+        assert_eq!(res.txs[0].memo, "tome");
+        assert_eq!(res.next, None);
+
+        let err = cosm_tome
+            .tx_query_get_block_with_txs(2, None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, TxError::MissingBlock));
+    }
 }
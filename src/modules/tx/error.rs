@@ -1,15 +1,77 @@
 use thiserror::Error;
 
-use crate::{chain::error::ChainError, modules::auth::error::AccountError};
+use crate::{
+    chain::error::{ChainError, SdkError},
+    modules::auth::error::AccountError,
+};
 
 #[derive(Error, Debug)]
 pub enum TxError {
     #[error("unsupported BroadcastMode: {i:?}")]
     BroadcastMode { i: i32 },
 
+    #[error("unsupported OrderBy: {i:?}")]
+    OrderBy { i: i32 },
+
+    #[error("tx missing from chain response")]
+    MissingTx,
+
+    #[error("block missing from chain response")]
+    MissingBlock,
+
+    #[error("blockId missing from chain response")]
+    MissingBlockId,
+
+    #[error("chain returned {txs} txs but {responses} tx responses")]
+    TxResponsesMismatch { txs: usize, responses: usize },
+
     #[error(transparent)]
     AccountError(#[from] AccountError),
 
     #[error(transparent)]
     ChainError(#[from] ChainError),
 }
+
+impl TxError {
+    /// Returns true if the chain rejected the tx for using the wrong account sequence (sdk error code 32)
+    pub fn is_sequence_mismatch(&self) -> bool {
+        match self {
+            TxError::ChainError(ChainError::CosmosSdk { res }) => {
+                // not every client reports the codespace, so fall back to checking the log
+                res.sdk_error() == Some(SdkError::SequenceMismatch)
+                    || res.log.contains("account sequence mismatch")
+            }
+            TxError::ChainError(ChainError::CosmosRest { message, .. }) => {
+                message.contains("account sequence mismatch")
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the account sequence the chain expected, parsed from the log of a sequence mismatch error
+    /// (`account sequence mismatch, expected 5, got 3: incorrect account sequence`)
+    pub fn expected_sequence(&self) -> Option<u64> {
+        if !self.is_sequence_mismatch() {
+            return None;
+        }
+
+        let log = match self {
+            TxError::ChainError(ChainError::CosmosSdk { res }) => &res.log,
+            TxError::ChainError(ChainError::CosmosRest { message, .. }) => message,
+            _ => return None,
+        };
+
+        let (_, rest) = log.split_once("expected ")?;
+        let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+
+        digits.parse().ok()
+    }
+
+    /// Classifies a chain error by its codespace and code, see `ChainError::sdk_error()`
+    pub fn sdk_error(&self) -> Option<SdkError> {
+        match self {
+            TxError::ChainError(e) => e.sdk_error(),
+            _ => None,
+        }
+    }
+}
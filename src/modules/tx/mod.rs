@@ -1,3 +1,5 @@
 pub mod api;
 pub mod error;
 pub mod model;
+
+pub(crate) mod sequence;
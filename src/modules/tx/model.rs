@@ -1,13 +1,23 @@
+use cosmrs::crypto::PublicKey;
 use cosmrs::proto::traits::MessageExt;
 use cosmrs::proto::{
-    cosmos::tx::v1beta1::{BroadcastMode as ProtoBroadcastMode, TxRaw},
+    cosmos::tx::v1beta1::{
+        BroadcastMode as ProtoBroadcastMode, OrderBy as ProtoOrderBy, Tx as ProtoTx, TxRaw,
+    },
     traits::Message,
 };
-use cosmrs::tx::Raw;
+use cosmrs::tendermint::{block, Block};
+use cosmrs::tx::{Raw, SignerPublicKey};
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
 
 use crate::chain::error::ChainError;
+use crate::chain::fee::Fee;
+use crate::chain::request::PaginationResponse;
+use crate::chain::response::ChainTxResponse;
+use crate::chain::Any;
+use crate::modules::auth::model::Account;
 
 use super::error::TxError;
 
@@ -83,6 +93,21 @@ impl RawTx {
     pub fn to_bytes(&self) -> Result<Vec<u8>, ChainError> {
         self.0.to_bytes().map_err(ChainError::prost_proto_encoding)
     }
+
+    /// Compute the hash the chain will index this transaction under,
+    /// as an uppercase hex encoded SHA-256 digest of the serialized transaction.
+    pub fn hash(&self) -> Result<String, ChainError> {
+        let digest = Sha256::digest(self.to_bytes()?);
+
+        Ok(digest.iter().map(|b| format!("{b:02X}")).collect())
+    }
+
+    /// Decode the body messages, memo, timeout height, fee, signer infos and signatures of this transaction.
+    pub fn decode(&self) -> Result<Tx, ChainError> {
+        cosmrs::Tx::from_bytes(&self.to_bytes()?)
+            .map_err(ChainError::proto_decoding)?
+            .try_into()
+    }
 }
 
 impl From<RawTx> for TxRaw {
@@ -108,3 +133,285 @@ impl From<Raw> for RawTx {
         RawTx(tx.into())
     }
 }
+
+/// Decoded cosmos transaction
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct Tx {
+    /// Messages executed by this transaction, in order
+    #[serde(with = "any_msgs")]
+    #[schemars(with = "Vec<any_msgs::JsonAny>")]
+    pub messages: Vec<Any>,
+
+    pub memo: String,
+
+    /// The block height after which this transaction will not be processed by the chain
+    pub timeout_height: u64,
+
+    pub fee: Fee,
+
+    pub signer_infos: Vec<SignerInfo>,
+
+    pub signatures: Vec<Vec<u8>>,
+}
+
+impl TryFrom<cosmrs::Tx> for Tx {
+    type Error = ChainError;
+
+    fn try_from(tx: cosmrs::Tx) -> Result<Self, Self::Error> {
+        Ok(Self {
+            messages: tx.body.messages,
+            memo: tx.body.memo,
+            timeout_height: tx.body.timeout_height.value(),
+            fee: tx.auth_info.fee.try_into()?,
+            signer_infos: tx
+                .auth_info
+                .signer_infos
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            signatures: tx.signatures,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct SignerInfo {
+    /// `None` if the tx does not include the public key of this signer, or for multisig signers
+    #[schemars(with = "Option<serde_json::Value>")]
+    pub public_key: Option<PublicKey>,
+
+    pub sequence: u64,
+}
+
+impl From<cosmrs::tx::SignerInfo> for SignerInfo {
+    fn from(info: cosmrs::tx::SignerInfo) -> Self {
+        Self {
+            public_key: match info.public_key {
+                Some(SignerPublicKey::Single(key)) => Some(key),
+                _ => None,
+            },
+            sequence: info.sequence,
+        }
+    }
+}
+
+impl TryFrom<ProtoTx> for Tx {
+    type Error = ChainError;
+
+    fn try_from(tx: ProtoTx) -> Result<Self, Self::Error> {
+        cosmrs::Tx::try_from(tx)
+            .map_err(ChainError::proto_decoding)?
+            .try_into()
+    }
+}
+
+/// Sort order of txs returned by `tx_query_get_txs_event()`
+#[derive(
+    Copy, Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, PartialOrd, Ord, Hash,
+)]
+#[repr(i32)]
+pub enum OrderBy {
+    /// ORDER_BY_UNSPECIFIED specifies an unknown sorting order. OrderBy defaults to ASC in this case.
+    Unspecified = 0,
+    /// ORDER_BY_ASC defines ascending order
+    Asc = 1,
+    /// ORDER_BY_DESC defines descending order
+    Desc = 2,
+}
+
+impl AsRef<str> for OrderBy {
+    fn as_ref(&self) -> &str {
+        match self {
+            OrderBy::Unspecified => "ORDER_BY_UNSPECIFIED",
+            OrderBy::Asc => "ORDER_BY_ASC",
+            OrderBy::Desc => "ORDER_BY_DESC",
+        }
+    }
+}
+
+impl TryFrom<i32> for OrderBy {
+    type Error = TxError;
+
+    fn try_from(v: i32) -> Result<Self, Self::Error> {
+        match v {
+            x if x == OrderBy::Unspecified as i32 => Ok(OrderBy::Unspecified),
+            x if x == OrderBy::Asc as i32 => Ok(OrderBy::Asc),
+            x if x == OrderBy::Desc as i32 => Ok(OrderBy::Desc),
+            _ => Err(TxError::OrderBy { i: v }),
+        }
+    }
+}
+
+impl From<OrderBy> for ProtoOrderBy {
+    fn from(order: OrderBy) -> Self {
+        match order {
+            OrderBy::Unspecified => ProtoOrderBy::Unspecified,
+            OrderBy::Asc => ProtoOrderBy::Asc,
+            OrderBy::Desc => ProtoOrderBy::Desc,
+        }
+    }
+}
+
+impl From<ProtoOrderBy> for OrderBy {
+    fn from(order: ProtoOrderBy) -> Self {
+        match order {
+            ProtoOrderBy::Unspecified => OrderBy::Unspecified,
+            ProtoOrderBy::Asc => OrderBy::Asc,
+            ProtoOrderBy::Desc => OrderBy::Desc,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct TxResponse {
+    pub tx: Tx,
+    pub res: ChainTxResponse,
+}
+
+impl AsRef<ChainTxResponse> for TxResponse {
+    fn as_ref(&self) -> &ChainTxResponse {
+        &self.res
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct TxsResponse {
+    pub txs: Vec<TxResponse>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, PartialEq)]
+pub struct BlockWithTxsResponse {
+    #[schemars(with = "serde_json::Value")]
+    pub id: block::Id,
+
+    #[schemars(with = "serde_json::Value")]
+    pub block: Block,
+
+    pub txs: Vec<Tx>,
+
+    pub next: Option<PaginationResponse>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct SignerData {
+    /// example: "cosmoshub-4"
+    pub chain_id: String,
+
+    pub account_number: u64,
+
+    pub sequence: u64,
+}
+
+impl SignerData {
+    pub fn new(chain_id: impl Into<String>, account: &Account) -> Self {
+        Self {
+            chain_id: chain_id.into(),
+            account_number: account.account_number,
+            sequence: account.sequence,
+        }
+    }
+}
+
+/// Unsigned tx, that can be exported as json and signed on a different (offline) machine
+/// with `SigningKey::sign_offline()`
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct UnsignedTx {
+    /// Messages executed by this transaction, in order
+    #[serde(with = "any_msgs")]
+    pub msgs: Vec<Any>,
+
+    pub memo: String,
+
+    /// The block height after which this transaction will not be processed by the chain
+    pub timeout_height: u64,
+
+    pub fee: Fee,
+}
+
+// Serializes proto `Any` messages as `{ "type_url": "...", "value": "<base64>" }`
+pub(crate) mod any_msgs {
+    use base64::{engine::general_purpose::STANDARD as BASE64, Engine};
+    use schemars::JsonSchema;
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    use crate::chain::Any;
+
+    #[derive(Serialize, Deserialize, JsonSchema)]
+    pub(crate) struct JsonAny {
+        type_url: String,
+        value: String,
+    }
+
+    impl JsonAny {
+        pub(super) fn new(msg: &Any) -> Self {
+            Self {
+                type_url: msg.type_url.clone(),
+                value: BASE64.encode(&msg.value),
+            }
+        }
+
+        pub(super) fn decode<E: Error>(self) -> Result<Any, E> {
+            Ok(Any {
+                type_url: self.type_url,
+                value: BASE64.decode(self.value).map_err(E::custom)?,
+            })
+        }
+    }
+
+    pub fn serialize<S: Serializer>(msgs: &[Any], s: S) -> Result<S::Ok, S::Error> {
+        msgs.iter()
+            .map(JsonAny::new)
+            .collect::<Vec<_>>()
+            .serialize(s)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Vec<Any>, D::Error> {
+        Vec::<JsonAny>::deserialize(d)?
+            .into_iter()
+            .map(JsonAny::decode)
+            .collect()
+    }
+}
+
+// Same as `any_msgs`, for a single optional message
+pub(crate) mod any_msg {
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    use super::any_msgs::JsonAny;
+    use crate::chain::Any;
+
+    pub fn serialize<S: Serializer>(msg: &Option<Any>, s: S) -> Result<S::Ok, S::Error> {
+        msg.as_ref().map(JsonAny::new).serialize(s)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Option<Any>, D::Error> {
+        Option::<JsonAny>::deserialize(d)?
+            .map(JsonAny::decode)
+            .transpose()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmrs::proto::cosmos::tx::v1beta1::TxRaw;
+
+    use super::RawTx;
+
+    #[test]
+    fn test_raw_tx_hash() {
+        let tx: RawTx = TxRaw {
+            body_bytes: vec![1, 2, 3],
+            auth_info_bytes: vec![4, 5, 6],
+            signatures: vec![vec![7, 8, 9]],
+        }
+        .into();
+
+        // sha256 of the proto encoded `TxRaw` bytes: 0a0301020312030405061a03070809
+        assert_eq!(
+            tx.hash().unwrap(),
+            "7F355117EF04758E2F2954F69E7EFE4444C66B579E3B8D2AE2E8ADEF83499CB5"
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tokio::sync::Mutex;
+
+use crate::modules::auth::model::{Account, Address};
+
+/// Cached signing account for a single address
+#[derive(Clone, Debug, Default)]
+pub(crate) struct AccountSlot {
+    /// `None` until it is synced from the chain
+    pub(crate) account: Option<Account>,
+
+    /// Bumped every time the cached sequence is moved back or dropped,
+    /// so that failing txs signed before can tell that their sequence is already being reused
+    pub(crate) epoch: u64,
+}
+
+/// Sequence handed out to a single tx, along with the epoch of the slot it was handed out from
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub(crate) struct SequenceLease {
+    pub(crate) sequence: u64,
+    pub(crate) epoch: u64,
+}
+
+/// Caches the signing account of every address used by a `CosmTome` instance,
+/// so that account sequences can be handed out locally to concurrent txs
+/// instead of re-querying the chain (and reusing the same sequence) for every tx.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct SequenceManager {
+    accounts: Arc<Mutex<HashMap<String, Arc<Mutex<AccountSlot>>>>>,
+}
+
+impl SequenceManager {
+    /// Returns the cached account slot for `address`.
+    /// Holding the slot's lock serializes sequence assignment for that address only.
+    pub(crate) async fn entry(&self, address: &Address) -> Arc<Mutex<AccountSlot>> {
+        self.accounts
+            .lock()
+            .await
+            .entry(address.to_string())
+            .or_default()
+            .clone()
+    }
+
+    /// Gives back the sequence of a tx that was not accepted by the chain.
+    /// Does nothing if a later sequence was already handed out, in which case those txs
+    /// will be rejected with the sequence the chain expects and resync it.
+    pub(crate) async fn release(&self, address: &Address, lease: SequenceLease) {
+        let entry = self.entry(address).await;
+        let mut slot = entry.lock().await;
+        let epoch = slot.epoch;
+
+        if let Some(account) = slot.account.as_mut() {
+            if epoch == lease.epoch && account.sequence == lease.sequence + 1 {
+                account.sequence = lease.sequence;
+            }
+        }
+    }
+
+    /// Sets the next sequence handed out for `address` to `expected`, as reported by the chain
+    /// when rejecting the tx signed with `lease`.
+    ///
+    /// Moving ahead is always safe. Moving back is only done once for all the txs signed before,
+    /// since every one of them is rejected with the same expected sequence, which would otherwise
+    /// be handed out again to each of them.
+    /// Does nothing if the account is not cached, since it will be resynced by the next tx anyway.
+    pub(crate) async fn resync(&self, address: &Address, lease: SequenceLease, expected: u64) {
+        let entry = self.entry(address).await;
+        let mut slot = entry.lock().await;
+        let epoch = slot.epoch;
+
+        if let Some(account) = slot.account.as_mut() {
+            if expected > account.sequence {
+                account.sequence = expected;
+            } else if expected < account.sequence && epoch == lease.epoch {
+                account.sequence = expected;
+                slot.epoch += 1;
+            }
+        }
+    }
+
+    /// Drops the cached account, forcing the next tx to resync it from the chain.
+    /// Only done once for all the txs signed before, see `resync()`.
+    pub(crate) async fn invalidate(&self, address: &Address, lease: SequenceLease) {
+        let entry = self.entry(address).await;
+        let mut slot = entry.lock().await;
+
+        if slot.epoch == lease.epoch {
+            slot.account = None;
+            slot.epoch += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::modules::auth::model::{Account, Address};
+
+    use super::{SequenceLease, SequenceManager};
+
+    async fn cache(sequences: &SequenceManager, address: &Address, sequence: u64) {
+        sequences.entry(address).await.lock().await.account = Some(Account {
+            address: address.clone(),
+            pubkey: None,
+            account_number: 1337,
+            sequence,
+        });
+    }
+
+    async fn cached_sequence(sequences: &SequenceManager, address: &Address) -> Option<u64> {
+        let entry = sequences.entry(address).await;
+        let slot = entry.lock().await;
+        slot.account.as_ref().map(|a| a.sequence)
+    }
+
+    #[tokio::test]
+    async fn test_resync() {
+        let sequences = SequenceManager::default();
+        let address: Address = "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+            .parse()
+            .unwrap();
+
+        // sequences 1 and 2 were handed out, both txs are rejected because the chain expects 0
+        cache(&sequences, &address, 3).await;
+        let first = SequenceLease {
+            sequence: 1,
+            epoch: 0,
+        };
+        let second = SequenceLease {
+            sequence: 2,
+            epoch: 0,
+        };
+
+        sequences.resync(&address, first, 0).await;
+        assert_eq!(cached_sequence(&sequences, &address).await, Some(0));
+
+        // sequence 0 was handed out again to the first tx, and must not be reused by the second one
+        cache(&sequences, &address, 1).await;
+        sequences.resync(&address, second, 0).await;
+        assert_eq!(cached_sequence(&sequences, &address).await, Some(1));
+
+        // moving ahead is always applied
+        sequences.resync(&address, second, 7).await;
+        assert_eq!(cached_sequence(&sequences, &address).await, Some(7));
+    }
+
+    #[tokio::test]
+    async fn test_release() {
+        let sequences = SequenceManager::default();
+        let address: Address = "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+            .parse()
+            .unwrap();
+
+        cache(&sequences, &address, 3).await;
+
+        // a later sequence was already handed out
+        sequences
+            .release(
+                &address,
+                SequenceLease {
+                    sequence: 1,
+                    epoch: 0,
+                },
+            )
+            .await;
+        assert_eq!(cached_sequence(&sequences, &address).await, Some(3));
+
+        sequences
+            .release(
+                &address,
+                SequenceLease {
+                    sequence: 2,
+                    epoch: 0,
+                },
+            )
+            .await;
+        assert_eq!(cached_sequence(&sequences, &address).await, Some(2));
+    }
+}
@@ -0,0 +1,122 @@
+use cosmrs::proto::cosmos::upgrade::v1beta1::{
+    QueryAppliedPlanRequest, QueryAppliedPlanResponse, QueryCurrentPlanRequest,
+    QueryCurrentPlanResponse, QueryModuleVersionsRequest, QueryModuleVersionsResponse,
+};
+
+use crate::clients::client::{CosmTome, CosmosClient};
+
+use super::{
+    error::UpgradeError,
+    model::{
+        AppliedPlanResponse, AuthorityResponse, CurrentPlanResponse, ModuleVersionsResponse,
+        QueryAuthorityRequest, QueryAuthorityResponse,
+    },
+};
+
+impl<T: CosmosClient> CosmTome<T> {
+    pub async fn upgrade_query_current_plan(&self) -> Result<CurrentPlanResponse, UpgradeError> {
+        let req = QueryCurrentPlanRequest {};
+
+        let res = self
+            .client
+            .query::<_, QueryCurrentPlanResponse>(req, "/cosmos.upgrade.v1beta1.Query/CurrentPlan")
+            .await?;
+
+        Ok(CurrentPlanResponse {
+            plan: res.plan.map(Into::into),
+        })
+    }
+
+    /// Query the height at which the upgrade named `name` was applied
+    pub async fn upgrade_query_applied_plan(
+        &self,
+        name: &str,
+    ) -> Result<AppliedPlanResponse, UpgradeError> {
+        let req = QueryAppliedPlanRequest {
+            name: name.to_string(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryAppliedPlanResponse>(req, "/cosmos.upgrade.v1beta1.Query/AppliedPlan")
+            .await?;
+
+        Ok(AppliedPlanResponse {
+            height: Some(res.height).filter(|h| *h != 0),
+        })
+    }
+
+    /// Query the consensus version of `module_name`, or of every module if `None`
+    pub async fn upgrade_query_module_versions(
+        &self,
+        module_name: Option<&str>,
+    ) -> Result<ModuleVersionsResponse, UpgradeError> {
+        let req = QueryModuleVersionsRequest {
+            module_name: module_name.unwrap_or_default().to_string(),
+        };
+
+        let res = self
+            .client
+            .query::<_, QueryModuleVersionsResponse>(
+                req,
+                "/cosmos.upgrade.v1beta1.Query/ModuleVersions",
+            )
+            .await?;
+
+        Ok(ModuleVersionsResponse {
+            module_versions: res.module_versions.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    /// Requires cosmos-sdk 0.46+ on the chain
+    pub async fn upgrade_query_authority(&self) -> Result<AuthorityResponse, UpgradeError> {
+        let req = QueryAuthorityRequest {};
+
+        let res = self
+            .client
+            .query::<_, QueryAuthorityResponse>(req, "/cosmos.upgrade.v1beta1.Query/Authority")
+            .await?;
+
+        Ok(AuthorityResponse {
+            address: res.address.parse()?,
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use cosmrs::proto::cosmos::upgrade::v1beta1::{
+        QueryAppliedPlanRequest, QueryAppliedPlanResponse,
+    };
+
+    use crate::{
+        clients::client::{CosmTome, MockCosmosClient},
+        test_utils::test_cfg,
+    };
+
+    #[tokio::test]
+    async fn test_upgrade_query_applied_plan() {
+        let cfg = test_cfg();
+
+        let mut mock_client = MockCosmosClient::new();
+
+        mock_client
+            .expect_query::<QueryAppliedPlanRequest, QueryAppliedPlanResponse>()
+            .times(2)
+            .returning(move |req, path| {
+                assert_eq!(path, "/cosmos.upgrade.v1beta1.Query/AppliedPlan");
+
+                let height = if req.name == "v12" { 4136530 } else { 0 };
+                Ok(QueryAppliedPlanResponse { height })
+            });
+
+        let cosm_tome = CosmTome::new(cfg, mock_client);
+
+        let res = cosm_tome.upgrade_query_applied_plan("v12").await.unwrap();
+        assert_eq!(res.height, Some(4136530));
+
+        let res = cosm_tome.upgrade_query_applied_plan("v13").await.unwrap();
+        assert_eq!(res.height, None);
+    }
+}
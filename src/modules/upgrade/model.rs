@@ -0,0 +1,78 @@
+use cosmrs::proto::cosmos::upgrade::v1beta1::{
+    ModuleVersion as ProtoModuleVersion, Plan as ProtoPlan,
+};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::modules::auth::model::Address;
+
+/// Cosmos sdk 0.46+ `QueryAuthorityRequest`, which is not part of the sdk protos we currently depend on.
+#[derive(Clone, PartialEq, prost::Message)]
+pub(crate) struct QueryAuthorityRequest {}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub(crate) struct QueryAuthorityResponse {
+    #[prost(string, tag = "1")]
+    pub address: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct CurrentPlanResponse {
+    /// Upgrade scheduled on chain, if any
+    pub plan: Option<Plan>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct AppliedPlanResponse {
+    /// Height at which the upgrade was applied, `None` if it never was
+    pub height: Option<i64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct ModuleVersionsResponse {
+    pub module_versions: Vec<ModuleVersion>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct AuthorityResponse {
+    /// Account allowed to schedule and cancel upgrades, usually the gov module account
+    pub address: Address,
+}
+
+/// Software upgrade scheduled at a given height.
+/// The deprecated `time` and `upgraded_client_state` fields are ignored.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct Plan {
+    /// Name of the upgrade, matching the upgrade handler of the new binary
+    pub name: String,
+
+    pub height: i64,
+
+    /// Any application specific upgrade info, ie. binaries to download
+    pub info: String,
+}
+
+impl From<ProtoPlan> for Plan {
+    fn from(plan: ProtoPlan) -> Self {
+        Self {
+            name: plan.name,
+            height: plan.height,
+            info: plan.info,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq, Hash)]
+pub struct ModuleVersion {
+    pub name: String,
+    pub version: u64,
+}
+
+impl From<ProtoModuleVersion> for ModuleVersion {
+    fn from(version: ProtoModuleVersion) -> Self {
+        Self {
+            name: version.name,
+            version: version.version,
+        }
+    }
+}
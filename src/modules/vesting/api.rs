@@ -0,0 +1,229 @@
+use crate::{
+    chain::request::TxOptions,
+    clients::client::{CosmTome, CosmosClient},
+    modules::auth::model::Address,
+    signing_key::key::SigningKey,
+};
+
+use super::{
+    error::VestingError,
+    model::{
+        CreatePeriodicVestingAccountRequest, CreatePermanentLockedAccountRequest,
+        CreateVestingAccountRequest, VestingAccountResponse, VestingTxResponse,
+    },
+};
+
+impl<T: CosmosClient> CosmTome<T> {
+    pub async fn vesting_create_account(
+        &self,
+        req: CreateVestingAccountRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<VestingTxResponse, VestingError> {
+        let res = self.tx_send(vec![req], key, tx_options).await?;
+
+        Ok(VestingTxResponse { res })
+    }
+
+    /// Requires cosmos-sdk 0.46+ on the chain
+    pub async fn vesting_create_permanent_locked_account(
+        &self,
+        req: CreatePermanentLockedAccountRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<VestingTxResponse, VestingError> {
+        let res = self.tx_send(vec![req], key, tx_options).await?;
+
+        Ok(VestingTxResponse { res })
+    }
+
+    /// Requires cosmos-sdk 0.46+ on the chain
+    pub async fn vesting_create_periodic_account(
+        &self,
+        req: CreatePeriodicVestingAccountRequest,
+        key: &SigningKey,
+        tx_options: &TxOptions,
+    ) -> Result<VestingTxResponse, VestingError> {
+        let res = self.tx_send(vec![req], key, tx_options).await?;
+
+        Ok(VestingTxResponse { res })
+    }
+
+    /// Query a vesting account, to compute its vested and spendable coins at a given block time.
+    /// Fails with `VestingError::NotVestingAccount` for any other account type.
+    pub async fn vesting_query_account(
+        &self,
+        address: Address,
+    ) -> Result<VestingAccountResponse, VestingError> {
+        let res = self.auth_query_account(address).await?;
+
+        Ok(VestingAccountResponse {
+            account: res.try_into()?,
+        })
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "mocks")]
+mod tests {
+    use cosmrs::proto::cosmos::{
+        auth::v1beta1::{BaseAccount, QueryAccountRequest, QueryAccountResponse},
+        vesting::v1beta1::{BaseVestingAccount, DelayedVestingAccount},
+    };
+    use cosmrs::proto::traits::{Message, MessageExt};
+    use cosmrs::tendermint::Time;
+
+    use crate::{
+        chain::{
+            coin::Coin,
+            fee::GasInfo,
+            request::TxOptions,
+            response::{ChainResponse, ChainTxResponse, Code},
+        },
+        clients::client::{CosmTome, MockCosmosClient},
+        modules::{
+            auth::model::{VestingPeriod, BASE_ACCOUNT_TYPE_URL, DELAYED_VESTING_ACCOUNT_TYPE_URL},
+            tx::model::RawTx,
+            vesting::{
+                error::VestingError,
+                model::{CreatePeriodicVestingAccountRequest, VestingSchedule},
+                v1beta1::MsgCreatePeriodicVestingAccount,
+            },
+        },
+        signing_key::key::SigningKey,
+        test_utils::test_cfg,
+    };
+
+    #[tokio::test]
+    async fn test_vesting_create_periodic_account() {
+        let cfg = test_cfg();
+        let tx_options = TxOptions::default();
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
+        let from = key.to_addr(&cfg.prefix).await.unwrap();
+
+        let mut mock_client = MockCosmosClient::new();
+
+        let account_addr = from.to_string();
+        mock_client
+            .expect_query::<QueryAccountRequest, QueryAccountResponse>()
+            .times(3)
+            .returning(move |req, _| {
+                let account = BaseAccount {
+                    address: req.address.clone(),
+                    pub_key: None,
+                    account_number: 1337,
+                    sequence: 1,
+                };
+
+                // the signer is a base account, the created account a vesting one
+                let account = if req.address == account_addr {
+                    cosmrs::proto::Any {
+                        type_url: BASE_ACCOUNT_TYPE_URL.to_string(),
+                        value: account.to_bytes().unwrap(),
+                    }
+                } else {
+                    cosmrs::proto::Any {
+                        type_url: DELAYED_VESTING_ACCOUNT_TYPE_URL.to_string(),
+                        value: DelayedVestingAccount {
+                            base_vesting_account: Some(BaseVestingAccount {
+                                base_account: Some(account),
+                                original_vesting: vec![],
+                                delegated_free: vec![],
+                                delegated_vesting: vec![],
+                                end_time: 1300,
+                            }),
+                        }
+                        .to_bytes()
+                        .unwrap(),
+                    }
+                };
+
+                Ok(QueryAccountResponse {
+                    account: Some(account),
+                })
+            });
+
+        mock_client.expect_simulate_tx().times(1).returning(|_| {
+            Ok(GasInfo {
+                gas_wanted: 200u16.into(),
+                gas_used: 100u16.into(),
+            })
+        });
+
+        mock_client
+            .expect_broadcast_tx_block()
+            .times(1)
+            .withf(|tx: &RawTx| {
+                let tx = cosmrs::Tx::from_bytes(&tx.to_bytes().unwrap()).unwrap();
+                let msg =
+                    MsgCreatePeriodicVestingAccount::decode(tx.body.messages[0].value.as_slice())
+                        .unwrap();
+
+                tx.body.messages[0].type_url
+                    == "/cosmos.vesting.v1beta1.MsgCreatePeriodicVestingAccount"
+                    && msg.to_address == "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                    && msg.start_time == 1000
+                    && msg.vesting_periods.len() == 2
+                    && msg.vesting_periods[1].length == 200
+                    && msg.vesting_periods[1].amount[0].amount == "600"
+            })
+            .returning(|_| {
+                Ok(ChainTxResponse {
+                    res: ChainResponse {
+                        code: Code::Ok,
+                        codespace: String::new(),
+                        data: None,
+                        log: "log log log".to_string(),
+                    },
+                    events: vec![],
+                    gas_wanted: 200,
+                    gas_used: 100,
+                    tx_hash: "TX_HASH_0".to_string(),
+                    height: 1337,
+                })
+            });
+
+        let cosm_tome = CosmTome::new(cfg.clone(), mock_client);
+
+        let ujuno = |amount| {
+            vec![Coin {
+                denom: "ujuno".parse().unwrap(),
+                amount,
+            }]
+        };
+
+        let req = CreatePeriodicVestingAccountRequest {
+            from: from.clone(),
+            to: "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                .parse()
+                .unwrap(),
+            start_time: Time::from_unix_timestamp(1000, 0).unwrap(),
+            periods: vec![
+                VestingPeriod {
+                    length: 100,
+                    amount: ujuno(400),
+                },
+                VestingPeriod {
+                    length: 200,
+                    amount: ujuno(600),
+                },
+            ],
+        };
+
+        let res = cosm_tome
+            .vesting_create_periodic_account(req.clone(), &key, &tx_options)
+            .await
+            .unwrap();
+        assert_eq!(res.res.tx_hash, "TX_HASH_0");
+
+        let res = cosm_tome.vesting_query_account(req.to).await.unwrap();
+        assert_eq!(res.account.schedule, VestingSchedule::Delayed);
+        assert_eq!(
+            res.account.vesting.end_time,
+            Time::from_unix_timestamp(1300, 0).unwrap()
+        );
+
+        let err = cosm_tome.vesting_query_account(from).await.unwrap_err();
+        assert!(matches!(err, VestingError::NotVestingAccount { .. }));
+    }
+}
@@ -0,0 +1,472 @@
+use cosmrs::tendermint::Time;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::chain::coin::Coin;
+use crate::chain::error::ChainError;
+use crate::chain::msg::Msg;
+use crate::chain::response::ChainTxResponse;
+use crate::chain::time::{time_from_unix_seconds, unix_seconds};
+use crate::modules::auth::model::{
+    Account, AccountKind, AccountResponse, Address, VestingInfo, VestingPeriod,
+};
+
+use super::error::VestingError;
+use super::v1beta1::{
+    MsgCreatePeriodicVestingAccount, MsgCreatePermanentLockedAccount, MsgCreateVestingAccount,
+};
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct VestingTxResponse {
+    pub res: ChainTxResponse,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct VestingAccountResponse {
+    pub account: VestingAccount,
+}
+
+/// Creates a continuous vesting account at `to`, funded with `amount` from `from`.
+/// Coins vest linearly from the block time of the tx until `end_time`,
+/// or all at once at `end_time` if `delayed` is set.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct CreateVestingAccountRequest {
+    pub from: Address,
+    pub to: Address,
+    pub amount: Vec<Coin>,
+
+    /// Time at which all coins are vested, truncated to seconds
+    pub end_time: Time,
+
+    pub delayed: bool,
+}
+
+impl Msg for CreateVestingAccountRequest {
+    type Proto = MsgCreateVestingAccount;
+    type Err = VestingError;
+}
+
+impl TryFrom<MsgCreateVestingAccount> for CreateVestingAccountRequest {
+    type Error = VestingError;
+
+    fn try_from(msg: MsgCreateVestingAccount) -> Result<Self, Self::Error> {
+        Ok(Self {
+            from: msg.from_address.parse()?,
+            to: msg.to_address.parse()?,
+            amount: coins_from_proto(msg.amount)?,
+            end_time: time_from_unix_seconds(msg.end_time)?,
+            delayed: msg.delayed,
+        })
+    }
+}
+
+impl TryFrom<CreateVestingAccountRequest> for MsgCreateVestingAccount {
+    type Error = VestingError;
+
+    fn try_from(req: CreateVestingAccountRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            from_address: req.from.into(),
+            to_address: req.to.into(),
+            amount: req.amount.into_iter().map(Into::into).collect(),
+            end_time: unix_seconds(req.end_time),
+            delayed: req.delayed,
+        })
+    }
+}
+
+/// Creates an account at `to` whose coins never vest, funded with `amount` from `from`.
+/// The coins can still be delegated and used in governance.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct CreatePermanentLockedAccountRequest {
+    pub from: Address,
+    pub to: Address,
+    pub amount: Vec<Coin>,
+}
+
+impl Msg for CreatePermanentLockedAccountRequest {
+    type Proto = MsgCreatePermanentLockedAccount;
+    type Err = VestingError;
+}
+
+impl TryFrom<MsgCreatePermanentLockedAccount> for CreatePermanentLockedAccountRequest {
+    type Error = VestingError;
+
+    fn try_from(msg: MsgCreatePermanentLockedAccount) -> Result<Self, Self::Error> {
+        Ok(Self {
+            from: msg.from_address.parse()?,
+            to: msg.to_address.parse()?,
+            amount: coins_from_proto(msg.amount)?,
+        })
+    }
+}
+
+impl TryFrom<CreatePermanentLockedAccountRequest> for MsgCreatePermanentLockedAccount {
+    type Error = VestingError;
+
+    fn try_from(req: CreatePermanentLockedAccountRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            from_address: req.from.into(),
+            to_address: req.to.into(),
+            amount: req.amount.into_iter().map(Into::into).collect(),
+        })
+    }
+}
+
+/// Creates a periodic vesting account at `to`, funded with the sum of the `periods` amounts from `from`.
+/// Each period vests its amount once its length has elapsed, starting from the end of the previous one.
+#[derive(Clone, Debug, Serialize, Deserialize, Eq, PartialEq)]
+pub struct CreatePeriodicVestingAccountRequest {
+    pub from: Address,
+    pub to: Address,
+
+    /// Time at which the first period starts, truncated to seconds
+    pub start_time: Time,
+
+    pub periods: Vec<VestingPeriod>,
+}
+
+impl Msg for CreatePeriodicVestingAccountRequest {
+    type Proto = MsgCreatePeriodicVestingAccount;
+    type Err = VestingError;
+}
+
+impl TryFrom<MsgCreatePeriodicVestingAccount> for CreatePeriodicVestingAccountRequest {
+    type Error = VestingError;
+
+    fn try_from(msg: MsgCreatePeriodicVestingAccount) -> Result<Self, Self::Error> {
+        Ok(Self {
+            from: msg.from_address.parse()?,
+            to: msg.to_address.parse()?,
+            start_time: time_from_unix_seconds(msg.start_time)?,
+            periods: msg
+                .vesting_periods
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<_>, ChainError>>()?,
+        })
+    }
+}
+
+impl TryFrom<CreatePeriodicVestingAccountRequest> for MsgCreatePeriodicVestingAccount {
+    type Error = VestingError;
+
+    fn try_from(req: CreatePeriodicVestingAccountRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            from_address: req.from.into(),
+            to_address: req.to.into(),
+            start_time: unix_seconds(req.start_time),
+            vesting_periods: req.periods.into_iter().map(Into::into).collect(),
+        })
+    }
+}
+
+fn coins_from_proto(
+    coins: Vec<cosmrs::proto::cosmos::base::v1beta1::Coin>,
+) -> Result<Vec<Coin>, ChainError> {
+    coins.into_iter().map(TryInto::try_into).collect()
+}
+
+/// How the coins of a vesting account are released over time
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub enum VestingSchedule {
+    Continuous {
+        /// Time at which the coins start vesting
+        #[schemars(with = "String")]
+        start_time: Time,
+    },
+    Delayed,
+    Periodic {
+        /// Time at which the first period starts
+        #[schemars(with = "String")]
+        start_time: Time,
+        periods: Vec<VestingPeriod>,
+    },
+    PermanentLocked,
+}
+
+/// A decoded vesting account, used to compute its vested and spendable coins at a given block time.
+/// The computations follow the cosmos sdk `x/auth/vesting` ones.
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema, Eq, PartialEq)]
+pub struct VestingAccount {
+    pub account: Account,
+    pub vesting: VestingInfo,
+    pub schedule: VestingSchedule,
+}
+
+impl TryFrom<AccountResponse> for VestingAccount {
+    type Error = VestingError;
+
+    fn try_from(res: AccountResponse) -> Result<Self, Self::Error> {
+        let (vesting, schedule) = match res.kind {
+            AccountKind::ContinuousVesting {
+                vesting,
+                start_time,
+            } => (vesting, VestingSchedule::Continuous { start_time }),
+            AccountKind::DelayedVesting { vesting } => (vesting, VestingSchedule::Delayed),
+            AccountKind::PeriodicVesting {
+                vesting,
+                start_time,
+                periods,
+            } => (
+                vesting,
+                VestingSchedule::Periodic {
+                    start_time,
+                    periods,
+                },
+            ),
+            AccountKind::PermanentLocked { vesting } => (vesting, VestingSchedule::PermanentLocked),
+            _ => {
+                return Err(VestingError::NotVestingAccount {
+                    address: res.account.address.to_string(),
+                })
+            }
+        };
+
+        Ok(Self {
+            account: res.account,
+            vesting,
+            schedule,
+        })
+    }
+}
+
+impl VestingAccount {
+    /// Coins vested at `time`
+    pub fn vested_coins(&self, time: Time) -> Vec<Coin> {
+        let time = unix_seconds(time);
+        let original = &self.vesting.original_vesting;
+        let end_time = unix_seconds(self.vesting.end_time);
+
+        match &self.schedule {
+            VestingSchedule::Continuous { start_time } => {
+                let start_time = unix_seconds(*start_time);
+
+                if time <= start_time {
+                    vec![]
+                } else if time >= end_time {
+                    original.clone()
+                } else {
+                    let elapsed = (time - start_time) as u128;
+                    let total = (end_time - start_time) as u128;
+
+                    original
+                        .iter()
+                        .map(|c| Coin {
+                            denom: c.denom.clone(),
+                            amount: mul_ratio(c.amount, elapsed, total),
+                        })
+                        .filter(|c| c.amount > 0)
+                        .collect()
+                }
+            }
+            VestingSchedule::Delayed => {
+                if time >= end_time {
+                    original.clone()
+                } else {
+                    vec![]
+                }
+            }
+            VestingSchedule::Periodic {
+                start_time,
+                periods,
+            } => {
+                let start_time = unix_seconds(*start_time);
+
+                if time <= start_time {
+                    return vec![];
+                }
+                if time >= end_time {
+                    return original.clone();
+                }
+
+                let mut vested = vec![];
+                let mut period_start = start_time;
+                for period in periods {
+                    if time - period_start < period.length {
+                        break;
+                    }
+                    add_coins(&mut vested, &period.amount);
+                    period_start += period.length;
+                }
+
+                vested
+            }
+            VestingSchedule::PermanentLocked => vec![],
+        }
+    }
+
+    /// Coins still vesting at `time`
+    pub fn vesting_coins(&self, time: Time) -> Vec<Coin> {
+        sub_coins(&self.vesting.original_vesting, &self.vested_coins(time))
+    }
+
+    /// Coins that cannot be transferred at `time`, ie. the vesting coins which are not delegated
+    pub fn locked_coins(&self, time: Time) -> Vec<Coin> {
+        sub_coins(&self.vesting_coins(time), &self.vesting.delegated_vesting)
+    }
+
+    /// Coins of `balances` that can be transferred at `time`.
+    /// Like the bank module, nothing is spendable if the locked coins exceed the balance of any denom.
+    pub fn spendable_coins(&self, balances: &[Coin], time: Time) -> Vec<Coin> {
+        let locked = self.locked_coins(time);
+
+        let exceeds_balance = locked.iter().any(|l| {
+            !balances
+                .iter()
+                .any(|b| b.denom == l.denom && b.amount >= l.amount)
+        });
+        if exceeds_balance {
+            return vec![];
+        }
+
+        sub_coins(balances, &locked)
+    }
+}
+
+/// `amount * numerator / denominator` rounded to the nearest integer (half to even, like sdk `Dec::RoundInt`),
+/// without overflowing for large amounts
+fn mul_ratio(amount: u128, numerator: u128, denominator: u128) -> u128 {
+    let whole = amount / denominator * numerator;
+    let rem = amount % denominator * numerator;
+    let (quot, frac) = (rem / denominator, rem % denominator);
+
+    let round_up = match (frac * 2).cmp(&denominator) {
+        std::cmp::Ordering::Greater => true,
+        std::cmp::Ordering::Equal => quot % 2 == 1,
+        std::cmp::Ordering::Less => false,
+    };
+
+    whole + quot + round_up as u128
+}
+
+fn add_coins(coins: &mut Vec<Coin>, other: &[Coin]) {
+    for o in other {
+        match coins.iter_mut().find(|c| c.denom == o.denom) {
+            Some(c) => c.amount += o.amount,
+            None => coins.push(o.clone()),
+        }
+    }
+}
+
+/// Subtracts `other` from `coins` per denom, saturating at zero and dropping empty coins
+fn sub_coins(coins: &[Coin], other: &[Coin]) -> Vec<Coin> {
+    coins
+        .iter()
+        .map(|c| {
+            let sub = other
+                .iter()
+                .find(|o| o.denom == c.denom)
+                .map_or(0, |o| o.amount);
+
+            Coin {
+                denom: c.denom.clone(),
+                amount: c.amount.saturating_sub(sub),
+            }
+        })
+        .filter(|c| c.amount > 0)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use cosmrs::tendermint::Time;
+
+    use crate::{
+        chain::{coin::Coin, msg::Msg},
+        modules::auth::model::{Account, VestingInfo, VestingPeriod},
+    };
+
+    use super::{CreateVestingAccountRequest, VestingAccount, VestingSchedule};
+
+    fn ujuno(amount: u128) -> Vec<Coin> {
+        vec![Coin {
+            denom: "ujuno".parse().unwrap(),
+            amount,
+        }]
+    }
+
+    fn at(secs: i64) -> Time {
+        Time::from_unix_timestamp(secs, 0).unwrap()
+    }
+
+    #[test]
+    fn test_vesting_schedules() {
+        let mut account = VestingAccount {
+            account: Account {
+                address: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                    .parse()
+                    .unwrap(),
+                pubkey: None,
+                account_number: 1337,
+                sequence: 0,
+            },
+            vesting: VestingInfo {
+                original_vesting: ujuno(1000),
+                delegated_free: vec![],
+                delegated_vesting: ujuno(100),
+                end_time: at(1300),
+            },
+            schedule: VestingSchedule::Continuous {
+                start_time: at(1000),
+            },
+        };
+
+        assert_eq!(account.vested_coins(at(1000)), vec![]);
+        assert_eq!(account.vested_coins(at(1100)), ujuno(333));
+        assert_eq!(account.vested_coins(at(1150)), ujuno(500));
+        assert_eq!(account.vesting_coins(at(1100)), ujuno(667));
+        assert_eq!(account.locked_coins(at(1100)), ujuno(567));
+        assert_eq!(account.spendable_coins(&ujuno(900), at(1100)), ujuno(333));
+        assert_eq!(account.spendable_coins(&ujuno(500), at(1100)), vec![]);
+        assert_eq!(account.vested_coins(at(1300)), ujuno(1000));
+
+        account.schedule = VestingSchedule::Periodic {
+            start_time: at(1000),
+            periods: vec![
+                VestingPeriod {
+                    length: 100,
+                    amount: ujuno(400),
+                },
+                VestingPeriod {
+                    length: 200,
+                    amount: ujuno(600),
+                },
+            ],
+        };
+
+        assert_eq!(account.vested_coins(at(1099)), vec![]);
+        assert_eq!(account.vested_coins(at(1100)), ujuno(400));
+        assert_eq!(account.vested_coins(at(1299)), ujuno(400));
+        assert_eq!(account.vested_coins(at(1300)), ujuno(1000));
+
+        account.schedule = VestingSchedule::Delayed;
+        assert_eq!(account.vested_coins(at(1299)), vec![]);
+        assert_eq!(account.vested_coins(at(1300)), ujuno(1000));
+
+        account.schedule = VestingSchedule::PermanentLocked;
+        assert_eq!(account.vested_coins(at(5000)), vec![]);
+        assert_eq!(account.locked_coins(at(5000)), ujuno(900));
+    }
+
+    #[test]
+    fn test_create_vesting_account_any_roundtrip() {
+        let req = CreateVestingAccountRequest {
+            from: "juno10j9gpw9t4jsz47qgnkvl5n3zlm2fz72k67rxsg"
+                .parse()
+                .unwrap(),
+            to: "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                .parse()
+                .unwrap(),
+            amount: ujuno(1000),
+            end_time: at(1300),
+            delayed: true,
+        };
+
+        let any = req.to_any().unwrap();
+        assert_eq!(
+            any.type_url,
+            "/cosmos.vesting.v1beta1.MsgCreateVestingAccount"
+        );
+        assert_eq!(CreateVestingAccountRequest::from_any(&any).unwrap(), req);
+    }
+}
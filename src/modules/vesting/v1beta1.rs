@@ -0,0 +1,54 @@
+//! Vesting msgs implementing `TypeUrl`, which the sdk protos we currently depend on are missing.
+//! `MsgCreatePermanentLockedAccount` and `MsgCreatePeriodicVestingAccount` require cosmos-sdk 0.46+.
+
+use cosmrs::proto::cosmos::base::v1beta1::Coin;
+use cosmrs::proto::cosmos::vesting::v1beta1::Period;
+use cosmrs::proto::traits::TypeUrl;
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgCreateVestingAccount {
+    #[prost(string, tag = "1")]
+    pub from_address: String,
+    #[prost(string, tag = "2")]
+    pub to_address: String,
+    #[prost(message, repeated, tag = "3")]
+    pub amount: Vec<Coin>,
+    #[prost(int64, tag = "4")]
+    pub end_time: i64,
+    #[prost(bool, tag = "5")]
+    pub delayed: bool,
+}
+
+impl TypeUrl for MsgCreateVestingAccount {
+    const TYPE_URL: &'static str = "/cosmos.vesting.v1beta1.MsgCreateVestingAccount";
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgCreatePermanentLockedAccount {
+    #[prost(string, tag = "1")]
+    pub from_address: String,
+    #[prost(string, tag = "2")]
+    pub to_address: String,
+    #[prost(message, repeated, tag = "3")]
+    pub amount: Vec<Coin>,
+}
+
+impl TypeUrl for MsgCreatePermanentLockedAccount {
+    const TYPE_URL: &'static str = "/cosmos.vesting.v1beta1.MsgCreatePermanentLockedAccount";
+}
+
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct MsgCreatePeriodicVestingAccount {
+    #[prost(string, tag = "1")]
+    pub from_address: String,
+    #[prost(string, tag = "2")]
+    pub to_address: String,
+    #[prost(int64, tag = "3")]
+    pub start_time: i64,
+    #[prost(message, repeated, tag = "4")]
+    pub vesting_periods: Vec<Period>,
+}
+
+impl TypeUrl for MsgCreatePeriodicVestingAccount {
+    const TYPE_URL: &'static str = "/cosmos.vesting.v1beta1.MsgCreatePeriodicVestingAccount";
+}
@@ -12,9 +12,10 @@ use serde::{Deserialize, Serialize};
 use crate::chain::error::ChainError;
 use crate::chain::fee::Fee;
 use crate::chain::msg::Msg;
+use crate::chain::Any;
 use crate::config::cfg::ChainConfig;
-use crate::modules::auth::model::{Account, Address};
-use crate::modules::tx::model::RawTx;
+use crate::modules::auth::model::{Account, AccountPubKey, Address};
+use crate::modules::tx::model::{RawTx, SignerData, UnsignedTx};
 
 #[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 pub struct SigningKey {
@@ -58,17 +59,80 @@ impl SigningKey {
         fee: Fee,
         cfg: &ChainConfig,
     ) -> Result<RawTx, ChainError> {
-        let public_key = if account.pubkey.is_none() {
-            Some(self.public_key().await?)
-        } else {
-            account.pubkey
+        let msgs = msgs
+            .into_iter()
+            .map(|m| m.into_any())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| ChainError::ProtoEncoding {
+                message: e.to_string(),
+            })?;
+
+        self.sign_any(msgs, timeout_height, memo, account, fee, cfg)
+            .await
+    }
+
+    /// Signs a tx containing already encoded messages, allowing different message types in the same tx.
+    /// If `account` has no public key on chain yet, it is assumed to be a cosmos secp256k1 account.
+    /// Returns `ChainError::UnsupportedPubKey` for Ethermint accounts.
+    pub async fn sign_any(
+        &self,
+        msgs: Vec<Any>,
+        timeout_height: u64,
+        memo: &str,
+        account: Account,
+        fee: Fee,
+        cfg: &ChainConfig,
+    ) -> Result<RawTx, ChainError> {
+        let public_key = match account.pubkey {
+            Some(pubkey) => pubkey,
+            None => self.public_key().await?.into(),
         };
 
+        let tx = UnsignedTx {
+            msgs,
+            memo: memo.to_string(),
+            timeout_height,
+            fee,
+        };
+
+        let signer = SignerData {
+            chain_id: cfg.chain_id.clone(),
+            account_number: account.account_number,
+            sequence: account.sequence,
+        };
+
+        self.sign_tx(tx, &signer, Some(public_key)).await
+    }
+
+    /// Signs `tx` without any network access, using the account number, sequence and chain id from `signer`.
+    /// The resulting `RawTx` can later be broadcasted from a connected machine.
+    pub async fn sign_offline(
+        &self,
+        tx: UnsignedTx,
+        signer: &SignerData,
+    ) -> Result<RawTx, ChainError> {
+        let public_key = self.public_key().await?;
+
+        self.sign_tx(tx, signer, Some(public_key.into())).await
+    }
+
+    async fn sign_tx(
+        &self,
+        tx: UnsignedTx,
+        signer: &SignerData,
+        public_key: Option<AccountPubKey>,
+    ) -> Result<RawTx, ChainError> {
+        // keys are always signed as cosmos secp256k1 keys, which ethermint would reject
+        if let Some(key @ AccountPubKey::EthSecp256k1(_)) = &public_key {
+            return Err(ChainError::UnsupportedPubKey {
+                type_url: key.type_url().to_string(),
+            });
+        }
+
+        let sign_doc = build_sign_doc(tx, signer, public_key)?;
+
         match &self.key {
             Key::Raw(bytes) => {
-                let sign_doc =
-                    build_sign_doc(msgs, timeout_height, memo, &account, fee, public_key, cfg)?;
-
                 let key = raw_bytes_to_signing_key(bytes)?;
 
                 let raw = sign_doc.sign(&key).map_err(ChainError::crypto)?;
@@ -76,9 +140,6 @@ impl SigningKey {
             }
 
             Key::Mnemonic(phrase) => {
-                let sign_doc =
-                    build_sign_doc(msgs, timeout_height, memo, &account, fee, public_key, cfg)?;
-
                 let key = mnemonic_to_signing_key(phrase, &self.derivation_path)?;
 
                 let raw = sign_doc.sign(&key).map_err(ChainError::crypto)?;
@@ -87,9 +148,6 @@ impl SigningKey {
 
             #[cfg(feature = "os_keyring")]
             Key::Keyring(params) => {
-                let sign_doc =
-                    build_sign_doc(msgs, timeout_height, memo, &account, fee, public_key, cfg)?;
-
                 let entry = Entry::new(&params.service, &params.key_name);
                 let key = mnemonic_to_signing_key(&entry.get_password()?, &self.derivation_path)?;
 
@@ -166,38 +224,196 @@ fn raw_bytes_to_signing_key(bytes: &[u8]) -> Result<secp256k1::SigningKey, Chain
 }
 
 fn build_sign_doc(
-    msgs: Vec<impl Msg>,
-    timeout_height: u64,
-    memo: &str,
-    account: &Account,
-    fee: Fee,
-    public_key: Option<PublicKey>,
-    cfg: &ChainConfig,
+    tx: UnsignedTx,
+    signer: &SignerData,
+    public_key: Option<AccountPubKey>,
 ) -> Result<SignDoc, ChainError> {
-    let timeout: Height = timeout_height.try_into()?;
+    let timeout: Height = tx.timeout_height.try_into()?;
 
-    let tx = Body::new(
-        msgs.into_iter()
-            .map(|m| m.into_any())
-            .collect::<Result<Vec<_>, _>>()
-            .map_err(|e| ChainError::ProtoEncoding {
-                message: e.to_string(),
-            })?,
-        memo,
-        timeout,
-    );
+    let body = Body::new(tx.msgs, tx.memo, timeout);
 
-    // NOTE: if we are making requests in parallel with the same key, we need to serialize `account.sequence` to avoid errors
-    let auth_info =
-        SignerInfo::single_direct(public_key, account.sequence).auth_info(fee.try_into()?);
+    // NOTE: when signing through `CosmTome`, `signer.sequence` is handed out by its sequence manager,
+    // so parallel requests with the same key do not reuse the same sequence
+    let auth_info = SignerInfo::single_direct(
+        public_key.as_ref().map(AccountPubKey::public_key),
+        signer.sequence,
+    )
+    .auth_info(tx.fee.try_into()?);
 
     SignDoc::new(
-        &tx,
+        &body,
         &auth_info,
-        &cfg.chain_id.parse().map_err(|_| ChainError::ChainId {
-            chain_id: cfg.chain_id.to_string(),
+        &signer.chain_id.parse().map_err(|_| ChainError::ChainId {
+            chain_id: signer.chain_id.to_string(),
         })?,
-        account.account_number,
+        signer.account_number,
     )
     .map_err(ChainError::proto_encoding)
 }
+
+#[cfg(test)]
+mod tests {
+    use cosmrs::bip32::secp256k1::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+    use cosmrs::proto::cosmos::tx::v1beta1::{SignDoc as ProtoSignDoc, TxRaw};
+    use cosmrs::proto::traits::Message;
+
+    use crate::chain::{coin::Coin, error::ChainError, fee::Fee, msg::Msg};
+    use crate::modules::auth::model::{Account, AccountPubKey, ETH_SECP256K1_PUBKEY_TYPE_URL};
+    use crate::modules::bank::model::SendRequest;
+    use crate::modules::tx::model::{SignerData, UnsignedTx};
+    use crate::test_utils::test_cfg;
+
+    use super::SigningKey;
+
+    async fn test_send(key: &SigningKey) -> SendRequest {
+        SendRequest {
+            from: key.to_addr("juno").await.unwrap(),
+            to: "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                .parse()
+                .unwrap(),
+            amounts: vec![Coin {
+                denom: "utest".parse().unwrap(),
+                amount: 10,
+            }],
+        }
+    }
+
+    fn test_fee() -> Fee {
+        Fee::new(
+            Coin {
+                denom: "utest".parse().unwrap(),
+                amount: 50,
+            },
+            200u64,
+            None,
+            None,
+        )
+    }
+
+    #[tokio::test]
+    async fn test_sign_signature() {
+        let cfg = test_cfg();
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
+        let public_key = key.public_key().await.unwrap();
+
+        let account = Account {
+            address: key.to_addr(&cfg.prefix).await.unwrap(),
+            pubkey: None,
+            account_number: 1337,
+            sequence: 7,
+        };
+
+        let raw = key
+            .sign(
+                vec![test_send(&key).await],
+                100,
+                "memo",
+                account,
+                test_fee(),
+                &cfg,
+            )
+            .await
+            .unwrap();
+
+        // the signature is checked against the sign doc the chain rebuilds from the broadcasted tx:
+        let tx_raw = TxRaw::decode(raw.to_bytes().unwrap().as_slice()).unwrap();
+        let sign_doc = ProtoSignDoc {
+            body_bytes: tx_raw.body_bytes,
+            auth_info_bytes: tx_raw.auth_info_bytes,
+            chain_id: cfg.chain_id,
+            account_number: 1337,
+        }
+        .encode_to_vec();
+
+        let verifying_key = VerifyingKey::from_sec1_bytes(&public_key.to_bytes()).unwrap();
+        let signature = Signature::try_from(tx_raw.signatures[0].as_slice()).unwrap();
+        verifying_key.verify(&sign_doc, &signature).unwrap();
+
+        let signed = raw.decode().unwrap();
+        assert_eq!(signed.signer_infos[0].sequence, 7);
+        assert_eq!(signed.signer_infos[0].public_key, Some(public_key));
+    }
+
+    #[tokio::test]
+    async fn test_sign_eth_account_unsupported() {
+        let cfg = test_cfg();
+        let key = SigningKey::random_mnemonic("test_key".to_string(), cfg.derivation_path.clone());
+
+        let account = Account {
+            address: key.to_addr(&cfg.prefix).await.unwrap(),
+            pubkey: Some(AccountPubKey::EthSecp256k1(key.public_key().await.unwrap())),
+            account_number: 1337,
+            sequence: 7,
+        };
+
+        let err = key
+            .sign(
+                vec![test_send(&key).await],
+                100,
+                "memo",
+                account,
+                test_fee(),
+                &cfg,
+            )
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            ChainError::UnsupportedPubKey { type_url } if type_url == ETH_SECP256K1_PUBKEY_TYPE_URL
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_sign_offline() {
+        let key =
+            SigningKey::random_mnemonic("test_key".to_string(), "m/44'/118'/0'/0/0".to_string());
+
+        let send = SendRequest {
+            from: key.to_addr("juno").await.unwrap(),
+            to: "juno1v9xynggs6vnrv2x5ufxdj398u2ghc5n9ya57ea"
+                .parse()
+                .unwrap(),
+            amounts: vec![Coin {
+                denom: "utest".parse().unwrap(),
+                amount: 10,
+            }],
+        };
+
+        let tx = UnsignedTx {
+            msgs: vec![send.into_any().unwrap()],
+            memo: "offline".to_string(),
+            timeout_height: 100,
+            fee: Fee::new(
+                Coin {
+                    denom: "utest".parse().unwrap(),
+                    amount: 50,
+                },
+                200u64,
+                None,
+                None,
+            ),
+        };
+
+        // the unsigned tx survives being exported to the offline machine as json:
+        let json = serde_json::to_string(&tx).unwrap();
+        let imported: UnsignedTx = serde_json::from_str(&json).unwrap();
+        assert_eq!(imported, tx);
+
+        let signer = SignerData {
+            chain_id: "test-1".to_string(),
+            account_number: 1337,
+            sequence: 7,
+        };
+
+        let raw = key.sign_offline(imported, &signer).await.unwrap();
+
+        let signed = raw.decode().unwrap();
+        assert_eq!(signed.memo, "offline");
+        assert_eq!(signed.messages, tx.msgs);
+        assert_eq!(signed.timeout_height, 100);
+        assert_eq!(signed.fee, tx.fee);
+        assert_eq!(signed.signer_infos[0].sequence, 7);
+        assert_eq!(signed.signatures.len(), 1);
+    }
+}
@@ -0,0 +1,16 @@
+use crate::config::cfg::ChainConfig;
+
+/// Config of the chain mocked by the module tests, with no endpoints set
+pub(crate) fn test_cfg() -> ChainConfig {
+    ChainConfig {
+        denom: "ujuno".to_string(),
+        prefix: "juno".to_string(),
+        chain_id: "test-1".to_string(),
+        derivation_path: "m/44'/118'/0'/0/0".to_string(),
+        rpc_endpoint: None,
+        grpc_endpoint: None,
+        rest_endpoint: None,
+        gas_price: 0.1,
+        gas_adjustment: 1.5,
+    }
+}